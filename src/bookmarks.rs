@@ -0,0 +1,176 @@
+//! Per-tab bookmarks.
+//!
+//! A bookmark is a labelled word index. Bookmarks live on the reader tab entity, so
+//! they follow the tab when tabs are reordered, and are saved with it in `SavedTab`.
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::tabs::{Content, ReaderTab};
+
+pub struct BookmarksPlugin;
+impl Plugin for BookmarksPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .add_observer(BookmarkAdd::on_trigger)
+            .add_observer(BookmarkRemove::on_trigger)
+            .add_observer(BookmarkRename::on_trigger)
+            ;
+    }
+}
+
+// ============================================================================
+// Components
+// ============================================================================
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Bookmark {
+    pub label: String,
+    pub index: usize,
+}
+
+/// Bookmarks of a reader tab, ordered by word index.
+#[derive(Component, Clone, Default)]
+pub struct Bookmarks(pub Vec<Bookmark>);
+impl Bookmarks {
+    /// Keeps bookmarks that point into `word_count` words, ordered by index.
+    pub fn new(mut bookmarks: Vec<Bookmark>, word_count: usize) -> Self {
+        bookmarks.retain(|bookmark| bookmark.index < word_count);
+        bookmarks.sort_by_key(|bookmark| bookmark.index);
+        Self(bookmarks)
+    }
+
+    /// Moves every bookmark through `remap` (e.g. after the words were rewritten).
+    pub fn remap(&mut self, mut remap: impl FnMut(usize) -> usize) {
+        for bookmark in &mut self.0 {
+            bookmark.index = remap(bookmark.index);
+        }
+        self.0.sort_by_key(|bookmark| bookmark.index);
+    }
+
+    fn insert(&mut self, bookmark: Bookmark) {
+        let position = self.0.partition_point(|existing| existing.index <= bookmark.index);
+        self.0.insert(position, bookmark);
+    }
+}
+
+/// Label used when none is typed: the word's text without surrounding punctuation.
+fn default_label(content: &Content, index: usize) -> String {
+    let Some(word) = content.words.get(index) else { return String::new() };
+    let trimmed = word.text.trim_matches(|c: char| !c.is_alphanumeric());
+    if trimmed.is_empty() { word.text } else { trimmed }.to_string()
+}
+
+// ============================================================================
+// Events
+// ============================================================================
+
+/// Bookmarks the current word of a reader tab. A blank label is replaced by the word's text.
+#[derive(EntityEvent)]
+pub struct BookmarkAdd {
+    pub entity: Entity,
+    pub label: String,
+}
+impl BookmarkAdd {
+    fn on_trigger(
+        trigger: On<BookmarkAdd>,
+        mut reader_tabs: Query<(&Content, &mut Bookmarks), With<ReaderTab>>,
+    ) {
+        let Ok((content, mut bookmarks)) = reader_tabs.get_mut(trigger.entity) else { return };
+        if !content.has_words() { return; }
+
+        let index = content.current_index;
+        let label = match trigger.label.trim() {
+            "" => default_label(content, index),
+            typed => typed.to_string(),
+        };
+        bookmarks.insert(Bookmark { label, index });
+    }
+}
+
+/// Deletes the bookmark at `position` in a tab's `Bookmarks`.
+#[derive(EntityEvent)]
+pub struct BookmarkRemove {
+    pub entity: Entity,
+    pub position: usize,
+}
+impl BookmarkRemove {
+    fn on_trigger(trigger: On<BookmarkRemove>, mut reader_tabs: Query<&mut Bookmarks, With<ReaderTab>>) {
+        let Ok(mut bookmarks) = reader_tabs.get_mut(trigger.entity) else { return };
+        if trigger.position < bookmarks.0.len() {
+            bookmarks.0.remove(trigger.position);
+        }
+    }
+}
+
+/// Relabels the bookmark at `position`. A blank label falls back to the word's text.
+#[derive(EntityEvent)]
+pub struct BookmarkRename {
+    pub entity: Entity,
+    pub position: usize,
+    pub label: String,
+}
+impl BookmarkRename {
+    fn on_trigger(
+        trigger: On<BookmarkRename>,
+        mut reader_tabs: Query<(&Content, &mut Bookmarks), With<ReaderTab>>,
+    ) {
+        let Ok((content, mut bookmarks)) = reader_tabs.get_mut(trigger.entity) else { return };
+        let Some(bookmark) = bookmarks.0.get_mut(trigger.position) else { return };
+        bookmark.label = match trigger.label.trim() {
+            "" => default_label(content, bookmark.index),
+            typed => typed.to_string(),
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::text::Word;
+
+    fn make_test_app() -> (App, Entity) {
+        let mut app = App::new();
+        app
+            .add_plugins(MinimalPlugins)
+            .add_plugins(BookmarksPlugin)
+            ;
+        let words = vec![Word::new("Call"), Word::new("me"), Word::new("Ishmael.")];
+        let entity = app.world_mut().spawn((
+            ReaderTab,
+            Content::new_from_loaded("test-cache".into(), words.try_into().unwrap(), Vec::new(), 2),
+            Bookmarks::default(),
+        )).id();
+        (app, entity)
+    }
+
+    fn bookmarks(app: &App, entity: Entity) -> Vec<(String, usize)> {
+        app.world().entity(entity).get::<Bookmarks>().unwrap().0.iter()
+            .map(|bookmark| (bookmark.label.clone(), bookmark.index))
+            .collect()
+    }
+
+    #[test]
+    fn adding_keeps_index_order_and_labels_blank_bookmarks_with_the_word() {
+        let (mut app, entity) = make_test_app();
+
+        app.world_mut().trigger(BookmarkAdd { entity, label: "  ".into() });
+        app.world_mut().entity_mut(entity).get_mut::<Content>().unwrap().current_index = 0;
+        app.world_mut().trigger(BookmarkAdd { entity, label: "Start".into() });
+
+        assert_eq!(bookmarks(&app, entity), vec![("Start".to_string(), 0), ("Ishmael".to_string(), 2)]);
+    }
+
+    #[test]
+    fn rename_and_remove_address_bookmarks_by_position() {
+        let (mut app, entity) = make_test_app();
+        app.world_mut().trigger(BookmarkAdd { entity, label: "Whale".into() });
+
+        app.world_mut().trigger(BookmarkRename { entity, position: 0, label: "".into() });
+        assert_eq!(bookmarks(&app, entity), vec![("Ishmael".to_string(), 2)]);
+
+        app.world_mut().trigger(BookmarkRemove { entity, position: 5 });
+        app.world_mut().trigger(BookmarkRemove { entity, position: 0 });
+        assert!(bookmarks(&app, entity).is_empty());
+    }
+}
@@ -0,0 +1,142 @@
+//! Optional break reminder.
+//!
+//! Counts playing time since the last break. Once it reaches the configured
+//! interval, playback pauses and a dialog suggests a break. Snoozing allows a
+//! few more minutes; dismissing, or stopping for long enough, starts the count over.
+
+use std::time::Duration;
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::playback::PlaybackCommand;
+use crate::reader::ReadingState;
+
+pub struct BreakReminderPlugin;
+impl Plugin for BreakReminderPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<BreakReminder>()
+            .add_systems(Update, BreakReminder::tick.run_if(in_state(ReadingState::Playing)))
+            .add_systems(OnExit(ReadingState::Playing), BreakReminder::on_stop)
+            .add_systems(OnEnter(ReadingState::Playing), BreakReminder::on_play)
+            ;
+    }
+}
+
+pub const BREAK_INTERVAL_MINS_MIN: u32 = 5;
+pub const BREAK_INTERVAL_MINS_MAX: u32 = 90;
+/// Reading time granted by snoozing the reminder.
+pub const BREAK_SNOOZE: Duration = Duration::from_secs(5 * 60);
+/// A stop at least this long counts as the break.
+const BREAK_MIN_LENGTH: Duration = Duration::from_secs(5 * 60);
+
+/// Break reminder settings and the reading time counted towards the next break.
+/// The settings are serialized to disk as part of `ProgramState`.
+#[derive(Resource, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BreakReminder {
+    pub enabled: bool,
+    pub interval_mins: u32,
+    /// Playing time since the last break.
+    #[serde(skip)]
+    reading_time: Duration,
+    /// App time when playback last stopped.
+    #[serde(skip)]
+    stopped_at: Option<Duration>,
+    /// Whether the reminder is showing.
+    #[serde(skip)]
+    is_due: bool,
+}
+impl Default for BreakReminder {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            interval_mins: 20,
+            reading_time: Duration::ZERO,
+            stopped_at: None,
+            is_due: false,
+        }
+    }
+}
+impl BreakReminder {
+    /// Run condition for the reminder dialog.
+    pub fn is_open(reminder: Res<BreakReminder>) -> bool {
+        reminder.is_due
+    }
+
+    /// Playing time since the last break.
+    pub fn reading_time(&self) -> Duration {
+        self.reading_time
+    }
+
+    fn interval(&self) -> Duration {
+        let minutes = self.interval_mins.clamp(BREAK_INTERVAL_MINS_MIN, BREAK_INTERVAL_MINS_MAX);
+        Duration::from_secs(minutes as u64 * 60)
+    }
+
+    /// Counts playing time. Returns true when the reminder becomes due.
+    fn record(&mut self, delta: Duration) -> bool {
+        if !self.enabled || self.is_due { return false; }
+        self.reading_time += delta;
+        self.is_due = self.reading_time >= self.interval();
+        self.is_due
+    }
+
+    /// Hides the reminder until `BREAK_SNOOZE` more has been read.
+    pub fn snooze(&mut self) {
+        self.is_due = false;
+        self.reading_time = self.interval().saturating_sub(BREAK_SNOOZE);
+    }
+
+    /// Hides the reminder and starts the count over.
+    pub fn dismiss(&mut self) {
+        self.is_due = false;
+        self.reading_time = Duration::ZERO;
+    }
+
+    fn tick(
+        mut commands: Commands,
+        time: Res<Time>,
+        mut reminder: ResMut<BreakReminder>,
+    ) {
+        if reminder.record(time.delta()) {
+            commands.trigger(PlaybackCommand::TogglePlayPause);
+        }
+    }
+
+    fn on_stop(time: Res<Time>, mut reminder: ResMut<BreakReminder>) {
+        reminder.stopped_at = Some(time.elapsed());
+    }
+
+    fn on_play(time: Res<Time>, mut reminder: ResMut<BreakReminder>) {
+        let stopped_at = reminder.stopped_at.take();
+        if stopped_at.is_some_and(|stopped_at| time.elapsed().saturating_sub(stopped_at) >= BREAK_MIN_LENGTH) {
+            reminder.reading_time = Duration::ZERO;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reminder_comes_due_once_and_snooze_delays_it() {
+        let minute = Duration::from_secs(60);
+        let mut reminder = BreakReminder { enabled: true, interval_mins: 20, ..default() };
+
+        assert!(!reminder.record(19 * minute));
+        assert!(reminder.record(minute));
+        assert!(!reminder.record(minute));
+
+        reminder.snooze();
+        assert!(!reminder.record(BREAK_SNOOZE - minute));
+        assert!(reminder.record(minute));
+
+        reminder.dismiss();
+        assert!(!reminder.record(19 * minute));
+
+        let mut disabled = BreakReminder::default();
+        assert!(!disabled.record(100 * minute));
+    }
+}
@@ -0,0 +1,263 @@
+//! Guided WPM calibration.
+//!
+//! Plays a fixed passage in a temporary reader tab at increasing speeds and asks
+//! after each round whether the pace was comfortable. The last comfortable speed
+//! becomes the recommended default WPM.
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::clock::unix_now_secs;
+use crate::playback::PlaybackCommand;
+use crate::reader::{ContentNavigate, ReadingState};
+use crate::tabs::{ActiveTab, Content, ReaderTab, TabClose, TabCreateRequest, TabSelect};
+use crate::text::{Word, WordStore};
+
+pub struct CalibrationPlugin;
+impl Plugin for CalibrationPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<CalibrationWizard>()
+            .init_resource::<CalibrationHistory>()
+            .add_systems(Update, CalibrationWizard::drive.run_if(CalibrationWizard::is_reading))
+            ;
+    }
+}
+
+pub const CALIBRATION_SPEEDS: &[u32] = &[300, 400, 500, 600, 700, 800];
+/// Recommended when even the first speed was too fast.
+const CALIBRATION_FALLBACK_WPM: u32 = 250;
+const CALIBRATION_TAB_NAME: &str = "WPM Calibration";
+/// 150 words: each round takes 30 seconds at 300 WPM and about 11 at 800.
+const CALIBRATION_PASSAGE: &str = "The old lighthouse keeper climbed the spiral stairs every evening, \
+counting each step out of habit rather than need. There were one hundred and twelve of them, \
+worn smooth by decades of boots. At the top he wiped the lens with a cloth, \
+checked the oil, and looked out over the water. The new \
+harbor to the south had its own automatic light, and most captains preferred it. \
+Still, he lit the lamp each night. Somewhere out there, he reasoned, a small boat might lose its \
+way in the fog, and a steady light on a familiar rock could mean the difference between a safe \
+return and a cold night at sea. When the beam began to turn, he sat down in his chair, \
+poured a cup of tea, and opened the book he had been reading. He read slowly, \
+savoring every sentence, because there was no hurry at all.";
+
+/// Current step of the calibration flow.
+#[derive(Clone, PartialEq)]
+pub enum CalibrationStage {
+    Inactive,
+    Intro,
+    /// Playing the passage at `CALIBRATION_SPEEDS[speed_index]`.
+    Reading { speed_index: usize, is_started: bool },
+    /// Asking whether `CALIBRATION_SPEEDS[speed_index]` was comfortable.
+    Prompt { speed_index: usize },
+    Finished { recommended_wpm: u32 },
+}
+
+/// Runtime state of the calibration wizard. The temporary tab is identified
+/// by its unique word cache id.
+#[derive(Resource)]
+pub struct CalibrationWizard {
+    pub stage: CalibrationStage,
+    content_cache_id: Option<String>,
+    comfortable_wpm: Option<u32>,
+}
+impl Default for CalibrationWizard {
+    fn default() -> Self {
+        Self {
+            stage: CalibrationStage::Inactive,
+            content_cache_id: None,
+            comfortable_wpm: None,
+        }
+    }
+}
+impl CalibrationWizard {
+    pub fn is_open(wizard: Res<CalibrationWizard>) -> bool {
+        wizard.stage != CalibrationStage::Inactive
+    }
+
+    fn is_reading(wizard: Res<CalibrationWizard>) -> bool {
+        matches!(wizard.stage, CalibrationStage::Reading { .. })
+    }
+
+    pub fn open(&mut self) {
+        *self = Self { stage: CalibrationStage::Intro, ..default() };
+    }
+
+    /// Creates the temporary calibration tab and starts the first round.
+    pub fn start(&mut self, commands: &mut Commands) {
+        let words = WordStore::try_from(CALIBRATION_PASSAGE.split_whitespace().map(Word::new).collect::<Vec<_>>())
+            .expect("the calibration passage fits in a word store");
+        let content = Content::new_ephemeral(words, Vec::new());
+        self.content_cache_id = Some(content.content_cache_id.clone());
+        commands.trigger(
+            TabCreateRequest::new(CALIBRATION_TAB_NAME.to_string(), content)
+                .with_wpm(CALIBRATION_SPEEDS[0])
+        );
+        self.stage = CalibrationStage::Reading { speed_index: 0, is_started: false };
+    }
+
+    /// Records the answer for the round just read and moves to the next speed or the result.
+    /// Returns the recommended WPM once the calibration is finished.
+    pub fn answer(&mut self, is_comfortable: bool) -> Option<u32> {
+        let CalibrationStage::Prompt { speed_index } = self.stage else { return None };
+        if is_comfortable {
+            self.comfortable_wpm = Some(CALIBRATION_SPEEDS[speed_index]);
+        }
+        let next_index = speed_index + 1;
+        if is_comfortable && next_index < CALIBRATION_SPEEDS.len() {
+            self.stage = CalibrationStage::Reading { speed_index: next_index, is_started: false };
+            return None;
+        }
+        let recommended_wpm = self.comfortable_wpm.unwrap_or(CALIBRATION_FALLBACK_WPM);
+        self.stage = CalibrationStage::Finished { recommended_wpm };
+        Some(recommended_wpm)
+    }
+
+    /// Closes the temporary calibration tab, if it is still open.
+    pub fn close_tab(
+        &mut self,
+        commands: &mut Commands,
+        reader_tabs: &Query<(Entity, &Content), With<ReaderTab>>,
+    ) {
+        if let Some(entity) = self.tab_entity(reader_tabs) {
+            commands.trigger(TabClose { entity });
+        }
+        self.content_cache_id = None;
+    }
+
+    /// Closes the wizard and its temporary tab.
+    pub fn close(
+        &mut self,
+        commands: &mut Commands,
+        reader_tabs: &Query<(Entity, &Content), With<ReaderTab>>,
+    ) {
+        self.close_tab(commands, reader_tabs);
+        *self = Self::default();
+    }
+
+    fn tab_entity(&self, reader_tabs: &Query<(Entity, &Content), With<ReaderTab>>) -> Option<Entity> {
+        let cache_id = self.content_cache_id.as_ref()?;
+        reader_tabs.iter()
+            .find(|(_, content)| &content.content_cache_id == cache_id)
+            .map(|(entity, _)| entity)
+    }
+
+    /// Starts each round once the calibration tab is active, and switches to the
+    /// prompt when the passage has been read to the end.
+    fn drive(
+        mut commands: Commands,
+        mut wizard: ResMut<CalibrationWizard>,
+        current_state: Res<State<ReadingState>>,
+        reader_tabs: Query<(Entity, &Content), With<ReaderTab>>,
+        active_tab: Option<Single<Entity, With<ActiveTab>>>,
+    ) {
+        let CalibrationStage::Reading { speed_index, is_started } = wizard.stage else { return };
+        let Some(entity) = wizard.tab_entity(&reader_tabs) else {
+            // The tab has not spawned yet, or the user closed it.
+            if is_started {
+                *wizard = Self::default();
+            }
+            return;
+        };
+        let is_active = active_tab.is_some_and(|active| *active == entity);
+
+        if !is_started {
+            if !is_active {
+                commands.trigger(TabSelect { entity });
+                return;
+            }
+            commands.trigger(ContentNavigate::Seek(0));
+            commands.trigger(PlaybackCommand::SetWpm(CALIBRATION_SPEEDS[speed_index]));
+            if *current_state.get() != ReadingState::Playing {
+                commands.trigger(PlaybackCommand::TogglePlayPause);
+            }
+            wizard.stage = CalibrationStage::Reading { speed_index, is_started: true };
+            return;
+        }
+
+        let is_finished = reader_tabs.get(entity).is_ok_and(|(_, content)| content.is_at_end());
+        if is_active && is_finished && *current_state.get() == ReadingState::Idle {
+            wizard.stage = CalibrationStage::Prompt { speed_index };
+        }
+    }
+}
+
+/// When the user last completed a calibration. Serialized to disk as part of `ProgramState`.
+#[derive(Resource, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CalibrationHistory {
+    pub last_calibrated_secs: Option<u64>,
+}
+impl CalibrationHistory {
+    pub fn record_now(&mut self) {
+        self.last_calibrated_secs = Some(unix_now_secs());
+    }
+
+    /// Human-readable age of the last calibration, e.g. "2 weeks ago".
+    pub fn describe_age(&self) -> Option<String> {
+        let elapsed_days = unix_now_secs().saturating_sub(self.last_calibrated_secs?) / 86_400;
+        Some(match elapsed_days {
+            0 => "today".to_string(),
+            1 => "yesterday".to_string(),
+            2..=13 => format!("{} days ago", elapsed_days),
+            14..=59 => format!("{} weeks ago", elapsed_days / 7),
+            _ => format!("{} months ago", elapsed_days / 30),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn calibration_passage_is_150_words() {
+        assert_eq!(CALIBRATION_PASSAGE.split_whitespace().count(), 150);
+    }
+
+    fn prompt_at(speed_index: usize) -> CalibrationWizard {
+        CalibrationWizard { stage: CalibrationStage::Prompt { speed_index }, ..default() }
+    }
+
+    #[test]
+    fn answer_moves_to_the_next_speed_while_comfortable() {
+        let mut wizard = prompt_at(0);
+
+        assert_eq!(wizard.answer(true), None);
+        assert!(wizard.stage == CalibrationStage::Reading { speed_index: 1, is_started: false });
+    }
+
+    #[test]
+    fn answer_recommends_the_last_comfortable_speed() {
+        let mut wizard = prompt_at(0);
+        wizard.answer(true);
+        wizard.stage = CalibrationStage::Prompt { speed_index: 1 };
+        wizard.answer(true);
+        wizard.stage = CalibrationStage::Prompt { speed_index: 2 };
+
+        assert_eq!(wizard.answer(false), Some(CALIBRATION_SPEEDS[1]));
+        assert!(wizard.stage == CalibrationStage::Finished { recommended_wpm: CALIBRATION_SPEEDS[1] });
+    }
+
+    #[test]
+    fn answer_falls_back_when_the_first_speed_is_too_fast() {
+        let mut wizard = prompt_at(0);
+
+        assert_eq!(wizard.answer(false), Some(CALIBRATION_FALLBACK_WPM));
+    }
+
+    #[test]
+    fn answer_finishes_after_the_fastest_speed() {
+        let mut wizard = prompt_at(CALIBRATION_SPEEDS.len() - 1);
+
+        assert_eq!(wizard.answer(true), CALIBRATION_SPEEDS.last().copied());
+    }
+
+    #[test]
+    fn answer_is_ignored_outside_the_prompt() {
+        let mut wizard = CalibrationWizard::default();
+        wizard.open();
+
+        assert_eq!(wizard.answer(true), None);
+        assert!(wizard.stage == CalibrationStage::Intro);
+    }
+}
@@ -0,0 +1,280 @@
+//! Text cleanup actions on loaded tabs.
+//!
+//! "Fix OCR hyphens" merges words that OCR split with a spurious hyphen
+//! ("intro-" "duction") anywhere in the text, not only at line ends, with the
+//! same rules as line-break hyphens. The merge is undoable until the tab's
+//! content is replaced.
+
+use bevy::prelude::*;
+
+use crate::bookmarks::Bookmarks;
+use crate::persistence::ProgramState;
+use crate::reader::WordChanged;
+use crate::tabs::{ActiveTab, Content, EphemeralTab, ReaderTab, TabTextStats};
+use crate::text::{join_hyphenated, TextStats, Word, WordStore};
+use crate::ui::{Toast, ToastKind};
+
+pub struct CleanupPlugin;
+impl Plugin for CleanupPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .add_observer(FixOcrHyphens::on_trigger)
+            .add_observer(UndoOcrHyphens::on_trigger)
+            .add_observer(OcrHyphenUndo::on_content_inserted)
+            ;
+    }
+}
+
+// ============================================================================
+// Merging
+// ============================================================================
+
+/// Result of `join_ocr_hyphens`: the merged words and the indices, in the
+/// original list, of words that were folded into their predecessor.
+pub struct HyphenJoin {
+    pub words: WordStore,
+    pub removed: Vec<usize>,
+}
+
+/// Merges every word ending in a hyphen with a following word that starts
+/// lowercase, keeping the hyphen of compounds (see `join_hyphenated`). Chains
+/// ("a-" "b-" "c") collapse into one word. The merged word keeps the pause
+/// flags of its last part. A paragraph break between two words is respected.
+pub fn join_ocr_hyphens(words: &WordStore) -> Result<HyphenJoin, String> {
+    let mut merged = WordStore::default();
+    let mut removed = Vec::new();
+    // The word being built; flushed once the next word does not continue it.
+    let mut pending: Option<Word> = None;
+    for (index, word) in words.iter().enumerate() {
+        if let Some(previous) = &mut pending
+            && !previous.is_paragraph_end
+            && let Some(joined) = join_hyphenated(&previous.text, word.text)
+        {
+            previous.text = joined;
+            previous.is_paragraph_end = word.is_paragraph_end;
+            previous.is_line_end = word.is_line_end;
+            removed.push(index);
+            continue;
+        }
+        if let Some(previous) = pending.replace(word.to_word()) {
+            merged.push(previous.view())?;
+        }
+    }
+    if let Some(last) = pending {
+        merged.push(last.view())?;
+    }
+    Ok(HyphenJoin { words: merged, removed })
+}
+
+/// Maps an index in the original word list to the merged one. Removed words map
+/// to the word they were merged into. `removed` must be sorted.
+pub fn merged_index(removed: &[usize], index: usize) -> usize {
+    index - removed.partition_point(|&removed_index| removed_index <= index)
+}
+
+/// Maps an index in the merged word list back to the original one, landing on
+/// the first part of a merged word. `removed` must be sorted.
+pub fn original_index(removed: &[usize], index: usize) -> usize {
+    let mut original = index;
+    for &removed_index in removed {
+        if removed_index > original { break; }
+        original += 1;
+    }
+    original
+}
+
+// ============================================================================
+// Components
+// ============================================================================
+
+/// Words before the last OCR hyphen fix, kept for undo. Removed when undone,
+/// dismissed, or when the content is replaced.
+#[derive(Component)]
+pub struct OcrHyphenUndo {
+    words: WordStore,
+    removed: Vec<usize>,
+}
+impl OcrHyphenUndo {
+    pub fn merged_count(&self) -> usize {
+        self.removed.len()
+    }
+
+    /// New content (e.g. from a reload) invalidates the saved words.
+    fn on_content_inserted(
+        trigger: On<Insert, Content>,
+        mut commands: Commands,
+        undos: Query<(), With<OcrHyphenUndo>>,
+    ) {
+        if undos.contains(trigger.entity) {
+            commands.entity(trigger.entity).remove::<OcrHyphenUndo>();
+        }
+    }
+}
+
+// ============================================================================
+// Events
+// ============================================================================
+
+/// Merges OCR-split words in a reader tab and rewrites its word cache.
+#[derive(EntityEvent)]
+pub struct FixOcrHyphens {
+    pub entity: Entity,
+}
+impl FixOcrHyphens {
+    #[allow(clippy::type_complexity)]
+    fn on_trigger(
+        trigger: On<FixOcrHyphens>,
+        mut commands: Commands,
+        mut reader_tabs: Query<(&mut Content, Option<&mut Bookmarks>, Has<ActiveTab>, Has<EphemeralTab>), With<ReaderTab>>,
+    ) {
+        let entity = trigger.entity;
+        let Ok((mut content, bookmarks, is_active, is_ephemeral)) = reader_tabs.get_mut(entity) else { return };
+
+        let join = match join_ocr_hyphens(&content.words) {
+            Ok(join) => join,
+            Err(e) => {
+                commands.trigger(Toast::new(ToastKind::Error, e));
+                return;
+            }
+        };
+        if join.removed.is_empty() {
+            commands.trigger(Toast::new(ToastKind::Info, "No OCR-split words found"));
+            return;
+        }
+
+        let original_words = std::mem::replace(&mut content.words, join.words);
+        content.current_index = merged_index(&join.removed, content.current_index);
+        for section in &mut content.sections {
+            section.start_index = merged_index(&join.removed, section.start_index);
+        }
+        if let Some(mut bookmarks) = bookmarks {
+            bookmarks.remap(|index| merged_index(&join.removed, index));
+        }
+        store_words(&content, is_ephemeral);
+
+        commands.entity(entity).insert((
+            TabTextStats(TextStats::from_words(&content.words)),
+            OcrHyphenUndo { words: original_words, removed: join.removed },
+        ));
+        if is_active {
+            commands.trigger(WordChanged);
+        }
+    }
+}
+
+/// Restores the words from before the last `FixOcrHyphens` on a tab.
+#[derive(EntityEvent)]
+pub struct UndoOcrHyphens {
+    pub entity: Entity,
+}
+impl UndoOcrHyphens {
+    #[allow(clippy::type_complexity)]
+    fn on_trigger(
+        trigger: On<UndoOcrHyphens>,
+        mut commands: Commands,
+        mut reader_tabs: Query<(&mut Content, &mut OcrHyphenUndo, Option<&mut Bookmarks>, Has<ActiveTab>, Has<EphemeralTab>), With<ReaderTab>>,
+    ) {
+        let entity = trigger.entity;
+        let Ok((mut content, mut undo, bookmarks, is_active, is_ephemeral)) = reader_tabs.get_mut(entity) else { return };
+
+        content.words = std::mem::take(&mut undo.words);
+        content.current_index = original_index(&undo.removed, content.current_index);
+        for section in &mut content.sections {
+            section.start_index = original_index(&undo.removed, section.start_index);
+        }
+        if let Some(mut bookmarks) = bookmarks {
+            bookmarks.remap(|index| original_index(&undo.removed, index));
+        }
+        store_words(&content, is_ephemeral);
+
+        commands.entity(entity)
+            .insert(TabTextStats(TextStats::from_words(&content.words)))
+            .remove::<OcrHyphenUndo>();
+        if is_active {
+            commands.trigger(WordChanged);
+        }
+    }
+}
+
+fn store_words(content: &Content, is_ephemeral: bool) {
+    if !is_ephemeral {
+        ProgramState::write_word_cache(&content.content_cache_id, &content.words, &content.sections);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn words(texts: &[&str]) -> Vec<Word> {
+        texts.iter().map(|&text| Word::new(text)).collect()
+    }
+
+    fn texts(words: &WordStore) -> Vec<&str> {
+        words.iter().map(|word| word.text).collect()
+    }
+
+    #[test]
+    fn join_merges_lowercase_continuations_only() {
+        let join = join_ocr_hyphens(&WordStore::try_from(words(&["The", "intro-", "duction", "of", "well-", "Known", "x", "-", "y", "1-", "2"])).unwrap()).unwrap();
+
+        assert_eq!(texts(&join.words), vec!["The", "introduction", "of", "well-", "Known", "x", "-", "y", "1-", "2"]);
+        assert_eq!(join.removed, vec![2]);
+    }
+
+    #[test]
+    fn join_keeps_compound_hyphens() {
+        let join = join_ocr_hyphens(&WordStore::try_from(words(&["a", "well-", "known", "high-", "est", "point"])).unwrap()).unwrap();
+
+        assert_eq!(texts(&join.words), vec!["a", "well-known", "highest", "point"]);
+        assert_eq!(join.removed, vec![2, 4]);
+    }
+
+    #[test]
+    fn join_collapses_chains_and_keeps_last_flags() {
+        let mut input = words(&["a", "ex-", "tra-", "ordinary", "b"]);
+        input[3].is_paragraph_end = true;
+
+        let join = join_ocr_hyphens(&input.try_into().unwrap()).unwrap();
+
+        assert_eq!(texts(&join.words), vec!["a", "extraordinary", "b"]);
+        assert!(join.words.get(1).unwrap().is_paragraph_end);
+        assert_eq!(join.removed, vec![2, 3]);
+    }
+
+    #[test]
+    fn join_respects_paragraph_breaks() {
+        let mut input = words(&["end-", "start"]);
+        input[0].is_paragraph_end = true;
+
+        assert!(join_ocr_hyphens(&input.try_into().unwrap()).unwrap().removed.is_empty());
+    }
+
+    #[test]
+    fn merged_index_shifts_past_removed_words() {
+        // Original: 0 a, 1 ex-, 2 tra-, 3 ordinary, 4 b, 5 c-, 6 d, 7 e
+        let removed = [2, 3, 6];
+
+        let mapped: Vec<usize> = (0..8).map(|index| merged_index(&removed, index)).collect();
+
+        assert_eq!(mapped, vec![0, 1, 1, 1, 2, 3, 3, 4]);
+    }
+
+    #[test]
+    fn original_index_returns_first_part_of_merged_word() {
+        let removed = [2, 3, 6];
+
+        let mapped: Vec<usize> = (0..5).map(|index| original_index(&removed, index)).collect();
+
+        assert_eq!(mapped, vec![0, 1, 4, 5, 7]);
+    }
+
+    #[test]
+    fn index_mapping_round_trips_for_kept_words() {
+        let removed = [1, 5, 6, 10];
+        for index in (0..12).filter(|index| !removed.contains(index)) {
+            let merged = merged_index(&removed, index);
+            assert_eq!(original_index(&removed, merged), index, "index {}", index);
+        }
+    }
+}
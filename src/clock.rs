@@ -0,0 +1,15 @@
+//! Wall-clock time shared by features that store timestamps, on both native and wasm.
+
+#[cfg(not(target_arch = "wasm32"))]
+pub fn unix_now_secs() -> u64 {
+    use std::time::{SystemTime, UNIX_EPOCH};
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+#[cfg(target_arch = "wasm32")]
+pub fn unix_now_secs() -> u64 {
+    (js_sys::Date::now() / 1000.0) as u64
+}
@@ -0,0 +1,122 @@
+//! Saving generated files (vocabulary lists, tab text) through the save dialog.
+//!
+//! `SaveFile` asks for a location with `rfd` on the async task pool and writes the
+//! bytes there; in the browser the file is downloaded instead. The outcome is
+//! reported with a toast. `TabExportText` saves a reader tab's words as plain text,
+//! with blank lines between paragraphs.
+
+use bevy::prelude::*;
+use bevy::tasks::{block_on, poll_once, AsyncComputeTaskPool, Task};
+
+use crate::tabs::{Content, ReaderTab};
+use crate::text::text_from_words;
+use crate::ui::{Toast, ToastKind};
+
+pub struct ExportPlugin;
+impl Plugin for ExportPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<PendingSaves>()
+            .add_systems(Update, PendingSaves::poll.run_if(PendingSaves::is_any))
+            .add_observer(SaveFile::on_trigger)
+            .add_observer(TabExportText::on_trigger)
+            ;
+    }
+}
+
+/// Offers `contents` for saving under a user-chosen path, starting from `file_name`.
+#[derive(Event)]
+pub struct SaveFile {
+    pub file_name: String,
+    /// Shown in the dialog's file type filter, e.g. "Text".
+    pub filter_name: &'static str,
+    pub extension: &'static str,
+    pub contents: Vec<u8>,
+}
+impl SaveFile {
+    fn on_trigger(trigger: On<SaveFile>, mut pending: ResMut<PendingSaves>) {
+        let SaveFile { file_name, filter_name, extension, contents } = trigger.event();
+        let dialog = rfd::AsyncFileDialog::new()
+            .set_file_name(file_name)
+            .add_filter(*filter_name, &[*extension]);
+        let contents = contents.clone();
+        pending.0.push(AsyncComputeTaskPool::get().spawn(async move {
+            let Some(handle) = dialog.save_file().await else { return Ok(None) };
+            handle.write(&contents).await
+                .map(|()| Some(handle.file_name()))
+                .map_err(|e| format!("Failed to save '{}': {}", handle.file_name(), e))
+        }));
+    }
+}
+
+/// Offers the text of a reader tab for saving as a `.txt` file named after the tab.
+#[derive(EntityEvent)]
+pub struct TabExportText {
+    pub entity: Entity,
+}
+impl TabExportText {
+    fn on_trigger(
+        trigger: On<TabExportText>,
+        mut commands: Commands,
+        reader_tabs: Query<(&Name, &Content), With<ReaderTab>>,
+    ) {
+        let Ok((name, content)) = reader_tabs.get(trigger.entity) else { return };
+        let mut text = text_from_words(&content.words).trim_end().to_string();
+        text.push('\n');
+        commands.trigger(SaveFile {
+            file_name: format!("{}.txt", file_stem_for(name.as_str())),
+            filter_name: "Text",
+            extension: "txt",
+            contents: text.into_bytes(),
+        });
+    }
+}
+impl From<Entity> for TabExportText {
+    fn from(entity: Entity) -> Self {
+        Self { entity }
+    }
+}
+
+/// `name` with characters that file systems reject replaced, for a default file name.
+fn file_stem_for(name: &str) -> String {
+    let stem: String = name.trim()
+        .chars()
+        .map(|c| if c.is_control() || matches!(c, '/' | '\\' | ':' | '*' | '?' | '"' | '<' | '>' | '|') { '_' } else { c })
+        .collect();
+    let stem = stem.trim_matches('.');
+    if stem.is_empty() { "text".to_string() } else { stem.to_string() }
+}
+
+/// Save dialogs and writes in flight. Each resolves to the saved file's name,
+/// or `None` when the dialog was cancelled.
+#[derive(Resource, Default)]
+pub struct PendingSaves(Vec<Task<Result<Option<String>, String>>>);
+impl PendingSaves {
+    fn is_any(pending: Res<PendingSaves>) -> bool {
+        !pending.0.is_empty()
+    }
+
+    fn poll(mut commands: Commands, mut pending: ResMut<PendingSaves>) {
+        pending.0.retain_mut(|task| {
+            let Some(result) = block_on(poll_once(task)) else { return true };
+            match result {
+                Ok(Some(file_name)) => commands.trigger(Toast::new(ToastKind::Info, format!("Saved {}", file_name))),
+                Ok(None) => {}
+                Err(e) => commands.trigger(Toast::new(ToastKind::Error, e)),
+            }
+            false
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn file_stem_replaces_characters_file_systems_reject() {
+        assert_eq!(file_stem_for("Moby Dick"), "Moby Dick");
+        assert_eq!(file_stem_for("Notes: 2024/05 \"draft\""), "Notes_ 2024_05 _draft_");
+        assert_eq!(file_stem_for(" ... "), "text");
+    }
+}
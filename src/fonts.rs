@@ -1,11 +1,15 @@
 //! Font management and caching.
 //!
 //! Loads built-in fonts from assets/fonts on all platforms.
-//! On native, also discovers additional font files dropped into the assets/fonts directory.
+//! On native, also discovers additional font files dropped into the assets/fonts directory
+//! and the user's own fonts in the `fonts` folder of the data directory.
 
 use bevy::log::info;
 use bevy::prelude::*;
 
+#[cfg(not(target_arch = "wasm32"))]
+use crate::persistence::ProgramState;
+
 pub struct FontsPlugin;
 impl Plugin for FontsPlugin {
     fn build(&self, app: &mut App) {
@@ -21,6 +25,10 @@ const BUILT_IN_FONTS: &[&str] = &[
     "UbuntuMono-Regular.ttf",
 ];
 
+/// Font used instead of the tab font for chunks with CJK characters, when present
+/// in assets/fonts or the user fonts folder. Not bundled due to its size.
+pub const CJK_FALLBACK_FONT: &str = "NotoSansMonoCJKsc-Regular.otf";
+
 /// A loaded font: its filename (used as display name) paired with the Bevy asset handle.
 #[derive(Clone)]
 pub struct FontData {
@@ -35,6 +43,10 @@ pub struct FontsStore {
     fonts: Vec<FontData>,
 }
 impl FontsStore {
+    #[cfg(test)]
+    pub fn from_fonts(fonts: Vec<FontData>) -> Self {
+        Self { fonts }
+    }
     pub fn default_font(&self) -> &FontData {
         self.fonts.first().expect("FontsStore is guaranteed non-empty")
     }
@@ -45,14 +57,22 @@ impl FontsStore {
     pub fn resolve(&self, name: &str) -> &FontData {
         self.get_by_name(name).unwrap_or_else(|| self.default_font())
     }
+    /// Font covering CJK characters, if one was found.
+    pub fn cjk_fallback(&self) -> Option<&FontData> {
+        self.get_by_name(CJK_FALLBACK_FONT)
+    }
     pub fn iter(&self) -> impl Iterator<Item = &FontData> {
         self.fonts.iter()
     }
     /// Loads built-in fonts and (on native) discovers additional .ttf/.otf files
-    /// dropped into assets/fonts. Fonts are sorted alphabetically by filename.
+    /// dropped into assets/fonts or the user fonts folder. A user font named like
+    /// a bundled one is skipped. Fonts are sorted alphabetically by filename.
+    #[allow(clippy::collapsible_if)]
     fn load_fonts(
         mut fonts_store: ResMut<FontsStore>,
         asset_server: Res<AssetServer>,
+        #[cfg(not(target_arch = "wasm32"))]
+        mut font_assets: ResMut<Assets<Font>>,
     ) {
         let mut names: Vec<String> = BUILT_IN_FONTS.iter().map(|&s| s.to_string()).collect();
 
@@ -61,7 +81,7 @@ impl FontsStore {
             if let Ok(entries) = std::fs::read_dir("assets/fonts") {
                 for entry in entries.flatten() {
                     let file_name = entry.file_name().to_string_lossy().to_string();
-                    if file_name.ends_with(".ttf") || file_name.ends_with(".otf") {
+                    if is_font_file(&file_name) {
                         if !names.contains(&file_name) {
                             names.push(file_name);
                         }
@@ -77,7 +97,45 @@ impl FontsStore {
             FontData { name, handle }
         }).collect();
 
+        #[cfg(not(target_arch = "wasm32"))]
+        for (name, font) in Self::read_user_fonts() {
+            if fonts_store.get_by_name(&name).is_none() {
+                fonts_store.fonts.push(FontData { name, handle: font_assets.add(font) });
+            }
+        }
+
+        fonts_store.fonts.sort_by(|a, b| a.name.cmp(&b.name));
+
         assert!(!fonts_store.fonts.is_empty(), "No fonts found in assets/fonts");
         info!("Loaded {} fonts", fonts_store.fonts.len());
     }
+    /// Reads the fonts in `ProgramState::user_fonts_dir`, skipping unreadable ones.
+    /// They live outside the asset folder, so they are loaded from bytes.
+    #[cfg(not(target_arch = "wasm32"))]
+    fn read_user_fonts() -> Vec<(String, Font)> {
+        let Some(dir) = ProgramState::user_fonts_dir() else { return Vec::new() };
+        let Ok(entries) = std::fs::read_dir(&dir) else { return Vec::new() };
+        entries.flatten()
+            .filter_map(|entry| {
+                let file_name = entry.file_name().to_string_lossy().to_string();
+                if !is_font_file(&file_name) { return None; }
+                let font = std::fs::read(entry.path())
+                    .map_err(|e| e.to_string())
+                    .and_then(|bytes| Font::try_from_bytes(bytes).map_err(|e| format!("{:?}", e)));
+                match font {
+                    Ok(font) => Some((file_name, font)),
+                    Err(e) => {
+                        warn!("Skipping font {:?}: {}", entry.path(), e);
+                        None
+                    }
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn is_font_file(file_name: &str) -> bool {
+    let lowercase = file_name.to_ascii_lowercase();
+    lowercase.ends_with(".ttf") || lowercase.ends_with(".otf")
 }
@@ -1,56 +1,480 @@
 //! Keyboard input handling for playback control.
 //!
-//! Handles play/pause, navigation, and WPM adjustment via keyboard shortcuts.
+//! Maps key chords from the `KeyBindings` resource to `InputAction`s, which dispatch
+//! `PlaybackCommand`/`ContentNavigate` events. Also tracks the hold-to-show cheat sheet key,
+//! turns mouse wheel scrolling over the reader display into WPM changes, and toggles
+//! playback when the reader display is clicked.
 
+use bevy::input::mouse::{AccumulatedMouseScroll, MouseScrollUnit};
 use bevy::prelude::*;
-use bevy_egui::EguiContexts;
+use bevy_egui::{EguiContexts, egui};
+use serde::{Deserialize, Serialize};
 
 use crate::playback::PlaybackCommand;
+use crate::queue::CancelQueueCountdown;
 use crate::reader::{ContentNavigate, WPM_STEP};
+use crate::tabs::{ActiveTab, ReaderTab, ReopenClosedTab, TabSwitch};
+use crate::ui::{OpenSearchBar, ToggleTocPanel, ToggleVocabularyPanel};
+use crate::vocabulary::CaptureWord;
 
-const WORD_SKIP_AMOUNT: usize = 5;
+pub const WORD_SKIP_MIN: usize = 1;
+pub const WORD_SKIP_MAX: usize = 100;
+/// Ctrl+digit jumps to the tab at that position.
+const TAB_POSITION_KEYS: [KeyCode; 9] = [
+    KeyCode::Digit1, KeyCode::Digit2, KeyCode::Digit3,
+    KeyCode::Digit4, KeyCode::Digit5, KeyCode::Digit6,
+    KeyCode::Digit7, KeyCode::Digit8, KeyCode::Digit9,
+];
+/// Keys that show the shortcut cheat sheet while held. Slash is "?" on US layouts.
+const CHEAT_SHEET_KEYS: [KeyCode; 2] = [KeyCode::Slash, KeyCode::F1];
+/// Scroll distance counted as one wheel notch when the device reports pixels (touchpads).
+const WHEEL_PIXELS_PER_NOTCH: f32 = 50.0;
 
 pub struct InputPlugin;
 impl Plugin for InputPlugin {
     fn build(&self, app: &mut App) {
         app
-            .add_systems(Update, handle_input)
+            .init_resource::<KeyBindings>()
+            .init_resource::<WordSkip>()
+            .init_resource::<CheatSheet>()
+            .add_systems(Update, (handle_input, adjust_wpm_with_wheel, toggle_playback_on_click))
             ;
     }
 }
 
-/// Maps keyboard shortcuts to `PlaybackCommand` events.
+// ============================================================================
+// Actions
+// ============================================================================
+
+/// Grouping used when listing bindings.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ActionCategory {
+    Playback,
+    Navigation,
+    Tabs,
+    View,
+}
+impl ActionCategory {
+    pub const ALL: [ActionCategory; 4] = [
+        ActionCategory::Playback,
+        ActionCategory::Navigation,
+        ActionCategory::Tabs,
+        ActionCategory::View,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            ActionCategory::Playback => "Playback",
+            ActionCategory::Navigation => "Navigation",
+            ActionCategory::Tabs => "Tabs",
+            ActionCategory::View => "View",
+        }
+    }
+}
+
+/// Everything a key chord can be bound to.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum InputAction {
+    TogglePlayPause,
+    IncreaseWpm,
+    DecreaseWpm,
+    Restart,
+    SkipBackward,
+    SkipForward,
+    PreviousSentence,
+    NextSentence,
+    PreviousParagraph,
+    NextParagraph,
+    StepBackward,
+    StepForward,
+    JumpToStart,
+    JumpToEnd,
+    CancelQueueCountdown,
+    ToggleToc,
+    Search,
+    CaptureWord,
+    ToggleVocabulary,
+    ReopenClosedTab,
+    NextTab,
+    PreviousTab,
+    /// Jumps to the tab with this 1-based number.
+    SelectTab(usize),
+}
+impl InputAction {
+    pub fn category(self) -> ActionCategory {
+        match self {
+            InputAction::TogglePlayPause
+            | InputAction::IncreaseWpm
+            | InputAction::DecreaseWpm
+            | InputAction::CancelQueueCountdown => ActionCategory::Playback,
+            InputAction::Restart
+            | InputAction::SkipBackward
+            | InputAction::SkipForward
+            | InputAction::PreviousSentence
+            | InputAction::NextSentence
+            | InputAction::PreviousParagraph
+            | InputAction::NextParagraph
+            | InputAction::StepBackward
+            | InputAction::StepForward
+            | InputAction::JumpToStart
+            | InputAction::JumpToEnd => ActionCategory::Navigation,
+            InputAction::ReopenClosedTab
+            | InputAction::NextTab
+            | InputAction::PreviousTab
+            | InputAction::SelectTab(_) => ActionCategory::Tabs,
+            InputAction::ToggleToc
+            | InputAction::Search
+            | InputAction::CaptureWord
+            | InputAction::ToggleVocabulary => ActionCategory::View,
+        }
+    }
+
+    pub fn description(self, word_skip: &WordSkip) -> String {
+        match self {
+            InputAction::TogglePlayPause => "Play / Pause".to_string(),
+            InputAction::IncreaseWpm => format!("Increase WPM by {}", WPM_STEP),
+            InputAction::DecreaseWpm => format!("Decrease WPM by {}", WPM_STEP),
+            InputAction::Restart => "Restart".to_string(),
+            InputAction::SkipBackward => format!("Skip back {} words", word_skip.amount()),
+            InputAction::SkipForward => format!("Skip forward {} words", word_skip.amount()),
+            InputAction::PreviousSentence => "Previous sentence".to_string(),
+            InputAction::NextSentence => "Next sentence".to_string(),
+            InputAction::PreviousParagraph => "Previous paragraph".to_string(),
+            InputAction::NextParagraph => "Next paragraph".to_string(),
+            InputAction::StepBackward => "Step back one word (paused)".to_string(),
+            InputAction::StepForward => "Step forward one word (paused)".to_string(),
+            InputAction::JumpToStart => "Jump to the first word".to_string(),
+            InputAction::JumpToEnd => "Jump to the last word".to_string(),
+            InputAction::CancelQueueCountdown => "Cancel queue countdown".to_string(),
+            InputAction::ToggleToc => "Show / hide contents and bookmarks".to_string(),
+            InputAction::Search => "Search the text".to_string(),
+            InputAction::CaptureWord => "Save the word to vocabulary".to_string(),
+            InputAction::ToggleVocabulary => "Show / hide vocabulary".to_string(),
+            InputAction::ReopenClosedTab => "Reopen last closed tab".to_string(),
+            InputAction::NextTab => "Next tab".to_string(),
+            InputAction::PreviousTab => "Previous tab".to_string(),
+            InputAction::SelectTab(number) => format!("Go to tab {}", number),
+        }
+    }
+
+    fn dispatch(self, commands: &mut Commands, word_skip: &WordSkip) {
+        match self {
+            InputAction::TogglePlayPause => commands.trigger(PlaybackCommand::TogglePlayPause),
+            InputAction::IncreaseWpm => commands.trigger(PlaybackCommand::AdjustWpm(WPM_STEP as i32)),
+            InputAction::DecreaseWpm => commands.trigger(PlaybackCommand::AdjustWpm(-(WPM_STEP as i32))),
+            InputAction::Restart => commands.trigger(PlaybackCommand::Restart),
+            InputAction::SkipBackward => commands.trigger(ContentNavigate::SkipBackward(word_skip.amount())),
+            InputAction::SkipForward => commands.trigger(ContentNavigate::SkipForward(word_skip.amount())),
+            InputAction::PreviousSentence => commands.trigger(ContentNavigate::PreviousSentence),
+            InputAction::NextSentence => commands.trigger(ContentNavigate::NextSentence),
+            InputAction::PreviousParagraph => commands.trigger(ContentNavigate::PreviousParagraph),
+            InputAction::NextParagraph => commands.trigger(ContentNavigate::NextParagraph),
+            InputAction::StepBackward => commands.trigger(PlaybackCommand::StepBackward),
+            InputAction::StepForward => commands.trigger(PlaybackCommand::StepForward),
+            InputAction::JumpToStart => commands.trigger(ContentNavigate::Seek(0)),
+            // Seeking clamps to the last word.
+            InputAction::JumpToEnd => commands.trigger(ContentNavigate::Seek(usize::MAX)),
+            InputAction::CancelQueueCountdown => commands.trigger(CancelQueueCountdown),
+            InputAction::ToggleToc => commands.trigger(ToggleTocPanel),
+            InputAction::Search => commands.trigger(OpenSearchBar),
+            InputAction::CaptureWord => commands.trigger(CaptureWord),
+            InputAction::ToggleVocabulary => commands.trigger(ToggleVocabularyPanel),
+            InputAction::ReopenClosedTab => commands.trigger(ReopenClosedTab),
+            InputAction::NextTab => commands.trigger(TabSwitch::Next),
+            InputAction::PreviousTab => commands.trigger(TabSwitch::Previous),
+            InputAction::SelectTab(number) => commands.trigger(TabSwitch::Position(number.saturating_sub(1))),
+        }
+    }
+}
+
+// ============================================================================
+// Bindings
+// ============================================================================
+
+/// A key plus the exact set of modifiers that must be held with it.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct KeyChord {
+    pub key: KeyCode,
+    pub ctrl: bool,
+    pub shift: bool,
+    pub alt: bool,
+}
+impl KeyChord {
+    pub const fn key(key: KeyCode) -> Self {
+        Self { key, ctrl: false, shift: false, alt: false }
+    }
+    pub const fn ctrl(key: KeyCode) -> Self {
+        Self { key, ctrl: true, shift: false, alt: false }
+    }
+    pub const fn shift(key: KeyCode) -> Self {
+        Self { key, ctrl: false, shift: true, alt: false }
+    }
+    pub const fn ctrl_shift(key: KeyCode) -> Self {
+        Self { key, ctrl: true, shift: true, alt: false }
+    }
+
+    fn just_pressed(&self, keyboard: &ButtonInput<KeyCode>) -> bool {
+        keyboard.just_pressed(self.key)
+            && self.ctrl == keyboard.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight])
+            && self.shift == keyboard.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight])
+            && self.alt == keyboard.any_pressed([KeyCode::AltLeft, KeyCode::AltRight])
+    }
+
+    /// Human-readable chord, e.g. "Ctrl+Shift+Tab".
+    pub fn label(&self) -> String {
+        let mut label = String::new();
+        if self.ctrl { label.push_str("Ctrl+"); }
+        if self.shift { label.push_str("Shift+"); }
+        if self.alt { label.push_str("Alt+"); }
+        label.push_str(&key_label(self.key));
+        label
+    }
+}
+
+/// Active keyboard bindings. Several chords may map to the same action.
+/// Both input handling and the shortcut listings read from here.
+#[derive(Resource)]
+pub struct KeyBindings(Vec<(KeyChord, InputAction)>);
+impl Default for KeyBindings {
+    fn default() -> Self {
+        let mut bindings = vec![
+            (KeyChord::key(KeyCode::Space), InputAction::TogglePlayPause),
+            (KeyChord::key(KeyCode::ArrowUp), InputAction::IncreaseWpm),
+            (KeyChord::key(KeyCode::ArrowDown), InputAction::DecreaseWpm),
+            (KeyChord::key(KeyCode::KeyR), InputAction::Restart),
+            (KeyChord::key(KeyCode::ArrowLeft), InputAction::SkipBackward),
+            (KeyChord::key(KeyCode::ArrowRight), InputAction::SkipForward),
+            (KeyChord::shift(KeyCode::ArrowLeft), InputAction::PreviousSentence),
+            (KeyChord::shift(KeyCode::ArrowRight), InputAction::NextSentence),
+            (KeyChord::key(KeyCode::PageUp), InputAction::PreviousParagraph),
+            (KeyChord::key(KeyCode::PageDown), InputAction::NextParagraph),
+            (KeyChord::ctrl(KeyCode::ArrowLeft), InputAction::StepBackward),
+            (KeyChord::ctrl(KeyCode::ArrowRight), InputAction::StepForward),
+            (KeyChord::key(KeyCode::Home), InputAction::JumpToStart),
+            (KeyChord::key(KeyCode::End), InputAction::JumpToEnd),
+            (KeyChord::key(KeyCode::Escape), InputAction::CancelQueueCountdown),
+            (KeyChord::key(KeyCode::KeyT), InputAction::ToggleToc),
+            (KeyChord::ctrl(KeyCode::KeyF), InputAction::Search),
+            (KeyChord::key(KeyCode::KeyV), InputAction::CaptureWord),
+            (KeyChord::shift(KeyCode::KeyV), InputAction::ToggleVocabulary),
+            (KeyChord::ctrl_shift(KeyCode::KeyT), InputAction::ReopenClosedTab),
+            (KeyChord::ctrl(KeyCode::Tab), InputAction::NextTab),
+            (KeyChord::ctrl_shift(KeyCode::Tab), InputAction::PreviousTab),
+        ];
+        bindings.extend(TAB_POSITION_KEYS.iter()
+            .enumerate()
+            .map(|(index, &key)| (KeyChord::ctrl(key), InputAction::SelectTab(index + 1))));
+        Self(bindings)
+    }
+}
+impl KeyBindings {
+    /// Bindings in `category`, ordered by action, with chords for the same action joined.
+    pub fn describe_category(&self, category: ActionCategory, word_skip: &WordSkip) -> Vec<(String, String)> {
+        let mut rows: Vec<(InputAction, Vec<String>)> = Vec::new();
+        for (chord, action) in self.0.iter().filter(|(_, action)| action.category() == category) {
+            match rows.iter_mut().find(|(existing, _)| existing == action) {
+                Some((_, chords)) => chords.push(chord.label()),
+                None => rows.push((*action, vec![chord.label()])),
+            }
+        }
+        rows.into_iter()
+            .map(|(action, chords)| (chords.join(" / "), action.description(word_skip)))
+            .collect()
+    }
+    fn pressed_actions<'a>(&'a self, keyboard: &'a ButtonInput<KeyCode>) -> impl Iterator<Item = InputAction> + 'a {
+        self.0.iter()
+            .filter(|(chord, _)| chord.just_pressed(keyboard))
+            .map(|(_, action)| *action)
+    }
+}
+
+fn key_label(key: KeyCode) -> String {
+    match key {
+        KeyCode::ArrowLeft => "←".to_string(),
+        KeyCode::ArrowRight => "→".to_string(),
+        KeyCode::ArrowUp => "↑".to_string(),
+        KeyCode::ArrowDown => "↓".to_string(),
+        KeyCode::Slash => "?".to_string(),
+        KeyCode::PageUp => "PgUp".to_string(),
+        KeyCode::PageDown => "PgDn".to_string(),
+        KeyCode::Escape => "Esc".to_string(),
+        other => {
+            let name = format!("{:?}", other);
+            name.strip_prefix("Key")
+                .or_else(|| name.strip_prefix("Digit"))
+                .map(str::to_string)
+                .unwrap_or(name)
+        }
+    }
+}
+
+/// Words moved by the skip actions. Serialized to disk as part of `ProgramState`.
+#[derive(Resource, Clone, Serialize, Deserialize)]
+pub struct WordSkip(pub usize);
+impl Default for WordSkip {
+    fn default() -> Self {
+        Self(5)
+    }
+}
+impl WordSkip {
+    pub fn amount(&self) -> usize {
+        self.0.clamp(WORD_SKIP_MIN, WORD_SKIP_MAX)
+    }
+}
+
+/// Whether the shortcut cheat sheet key is currently held.
+#[derive(Resource, Default)]
+pub struct CheatSheet {
+    is_held: bool,
+}
+impl CheatSheet {
+    /// Run condition for the cheat sheet overlay.
+    pub fn is_visible(cheat_sheet: Res<CheatSheet>) -> bool {
+        cheat_sheet.is_held
+    }
+    /// Human-readable list of the keys that open the cheat sheet.
+    pub fn keys_label() -> String {
+        CHEAT_SHEET_KEYS.iter().map(|&key| key_label(key)).collect::<Vec<_>>().join(" or ")
+    }
+}
+
+// ============================================================================
+// Systems
+// ============================================================================
+
+/// Dispatches actions for chords pressed this frame and tracks the cheat sheet key.
 /// Skips input when egui has keyboard focus (e.g. text fields in dialogs).
 fn handle_input(
     mut commands: Commands,
     keyboard: Res<ButtonInput<KeyCode>>,
+    key_bindings: Res<KeyBindings>,
+    word_skip: Res<WordSkip>,
+    mut cheat_sheet: ResMut<CheatSheet>,
     mut contexts: EguiContexts,
 ) {
     if contexts.ctx_mut().is_ok_and(|ctx| ctx.wants_keyboard_input()) {
+        cheat_sheet.is_held = false;
         return;
     }
 
-    // Space: toggle play/pause
-    if keyboard.just_pressed(KeyCode::Space) {
-        commands.trigger(PlaybackCommand::TogglePlayPause);
+    cheat_sheet.is_held = keyboard.any_pressed(CHEAT_SHEET_KEYS);
+
+    for action in key_bindings.pressed_actions(&keyboard) {
+        action.dispatch(&mut commands, &word_skip);
+    }
+}
+
+/// Whether the pointer is over the reader display rather than an egui panel or window.
+fn is_pointer_over_reader(ctx: &egui::Context) -> bool {
+    !ctx.is_pointer_over_area() && !ctx.wants_pointer_input()
+}
+
+/// Changes the active tab's WPM by `WPM_STEP` per wheel notch scrolled over the
+/// reader display. Scrolling up speeds up. Partial notches carry over.
+#[allow(clippy::type_complexity)]
+fn adjust_wpm_with_wheel(
+    mut commands: Commands,
+    scroll: Res<AccumulatedMouseScroll>,
+    mut notches: Local<f32>,
+    mut contexts: EguiContexts,
+    active_reader: Option<Single<Entity, (With<ActiveTab>, With<ReaderTab>)>>,
+) {
+    if scroll.delta.y == 0.0 { return; }
+    let is_over_reader = contexts.ctx_mut().is_ok_and(|ctx| is_pointer_over_reader(ctx));
+    if active_reader.is_none() || !is_over_reader {
+        *notches = 0.0;
+        return;
     }
-    
-    // R: restart
-    if keyboard.just_pressed(KeyCode::KeyR) {
-        commands.trigger(ContentNavigate::Seek(0));
+    *notches += match scroll.unit {
+        MouseScrollUnit::Line => scroll.delta.y,
+        MouseScrollUnit::Pixel => scroll.delta.y / WHEEL_PIXELS_PER_NOTCH,
+    };
+    let whole_notches = notches.trunc();
+    if whole_notches != 0.0 {
+        *notches -= whole_notches;
+        commands.trigger(PlaybackCommand::AdjustWpm(whole_notches as i32 * WPM_STEP as i32));
     }
-    
-    // Arrow keys: navigation and WPM
-    if keyboard.just_pressed(KeyCode::ArrowLeft) {
-        commands.trigger(ContentNavigate::SkipBackward(WORD_SKIP_AMOUNT));
+}
+
+/// Plays or pauses on a left click on the reader display. A click that only
+/// takes focus away from a text field does not count.
+#[allow(clippy::type_complexity)]
+fn toggle_playback_on_click(
+    mut commands: Commands,
+    mouse: Res<ButtonInput<MouseButton>>,
+    mut contexts: EguiContexts,
+    active_reader: Option<Single<Entity, (With<ActiveTab>, With<ReaderTab>)>>,
+) {
+    if !mouse.just_pressed(MouseButton::Left) || active_reader.is_none() { return; }
+    let is_over_reader = contexts.ctx_mut()
+        .is_ok_and(|ctx| is_pointer_over_reader(ctx) && !ctx.wants_keyboard_input());
+    if is_over_reader {
+        commands.trigger(PlaybackCommand::TogglePlayPause);
     }
-    if keyboard.just_pressed(KeyCode::ArrowRight) {
-        commands.trigger(ContentNavigate::SkipForward(WORD_SKIP_AMOUNT));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reader::ReadingState;
+    use crate::tabs::{ActiveTab, Content, ReaderTab, TabChunkSize};
+    use crate::text::Word;
+
+    fn ctrl_chord(key: KeyCode, shift: bool) -> KeyChord {
+        KeyChord { key, ctrl: true, shift, alt: false }
     }
-    if keyboard.just_pressed(KeyCode::ArrowUp) {
-        commands.trigger(PlaybackCommand::AdjustWpm(WPM_STEP as i32));
+
+    #[test]
+    fn chord_requires_exact_modifiers() {
+        let mut keyboard = ButtonInput::<KeyCode>::default();
+        keyboard.press(KeyCode::ControlLeft);
+        keyboard.press(KeyCode::Tab);
+
+        assert!(ctrl_chord(KeyCode::Tab, false).just_pressed(&keyboard));
+        assert!(!KeyChord::key(KeyCode::Tab).just_pressed(&keyboard));
+        assert!(!ctrl_chord(KeyCode::Tab, true).just_pressed(&keyboard));
+    }
+
+    #[test]
+    fn describe_category_joins_chords_for_the_same_action() {
+        let mut key_bindings = KeyBindings::default();
+        key_bindings.0.push((KeyChord::key(KeyCode::KeyK), InputAction::TogglePlayPause));
+
+        let rows = key_bindings.describe_category(ActionCategory::Playback, &WordSkip::default());
+
+        assert_eq!(rows[0], ("Space / K".to_string(), "Play / Pause".to_string()));
+        let tab_rows = key_bindings.describe_category(ActionCategory::Tabs, &WordSkip::default());
+        assert_eq!(tab_rows[0], ("Ctrl+Shift+T".to_string(), "Reopen last closed tab".to_string()));
+        assert_eq!(tab_rows[3], ("Ctrl+1".to_string(), "Go to tab 1".to_string()));
     }
-    if keyboard.just_pressed(KeyCode::ArrowDown) {
-        commands.trigger(PlaybackCommand::AdjustWpm(-(WPM_STEP as i32)));
+
+    #[test]
+    fn skip_actions_move_by_the_configured_amount() {
+        let mut app = App::new();
+        app
+            .add_plugins(MinimalPlugins)
+            .add_plugins(bevy::state::app::StatesPlugin)
+            .init_state::<ReadingState>()
+            .add_observer(Content::on_navigate)
+            ;
+        let words: Vec<Word> = (0..20).map(|index| Word::new(format!("w{index}"))).collect();
+        let tab = app.world_mut().spawn((
+            ActiveTab,
+            ReaderTab,
+            TabChunkSize(1),
+            Content::new_from_loaded("test-cache".into(), words.try_into().unwrap(), Vec::new(), 10),
+        )).id();
+        let mut skip = |action: InputAction, amount: usize| {
+            InputAction::dispatch(action, &mut app.world_mut().commands(), &WordSkip(amount));
+            app.world_mut().flush();
+            app.world().entity(tab).get::<Content>().unwrap().current_index
+        };
+
+        assert_eq!(skip(InputAction::SkipForward, 3), 13);
+        assert_eq!(skip(InputAction::SkipBackward, 8), 5);
+        assert_eq!(skip(InputAction::SkipBackward, 0), 4);
+        assert_eq!(skip(InputAction::SkipForward, 500), 19);
+        assert_eq!(skip(InputAction::JumpToStart, 1), 0);
+        assert_eq!(skip(InputAction::JumpToEnd, 1), 19);
     }
 }
@@ -6,18 +6,38 @@
 use bevy::prelude::*;
 use bevy_egui::EguiPlugin;
 
+mod bookmarks;
+mod break_reminder;
+mod calibration;
+mod cleanup;
+mod clock;
+mod export;
 mod fonts;
 mod input;
+#[cfg(all(feature = "media_controls", not(target_arch = "wasm32")))]
+mod media_controls;
+#[cfg(feature = "metronome")]
+mod metronome;
+#[cfg(not(target_arch = "wasm32"))]
+mod open_files;
 mod orp;
 mod persistence;
 mod playback;
+mod queue;
 mod reader;
+mod reload;
+mod stats;
 mod tabs;
 mod text;
+mod theme;
 mod ui;
+#[cfg(all(feature = "update_check", not(target_arch = "wasm32")))]
+mod update_check;
+mod vocabulary;
 
 fn main() {
-    App::new()
+    let mut app = App::new();
+    app
         .add_plugins(DefaultPlugins
             .set(WindowPlugin {
                 primary_window: Some(Window {
@@ -42,14 +62,29 @@ fn main() {
             fonts::FontsPlugin,
             tabs::TabsPlugin,
             reader::ReaderPlugin,
+            (stats::StatsPlugin, break_reminder::BreakReminderPlugin),
             playback::PlaybackPlugin,
+            queue::QueuePlugin,
             input::InputPlugin,
             orp::OrpPlugin,
             persistence::PersistencePlugin,
-            ui::UiPlugin,
+            reload::ReloadPlugin,
+            calibration::CalibrationPlugin,
+            cleanup::CleanupPlugin,
+            (bookmarks::BookmarksPlugin, vocabulary::VocabularyPlugin, export::ExportPlugin),
+            (theme::ThemePlugin, ui::UiPlugin),
         ))
         .add_systems(Startup, setup)
-        .run();
+        ;
+    #[cfg(not(target_arch = "wasm32"))]
+    app.add_plugins(open_files::OpenFilesPlugin);
+    #[cfg(feature = "metronome")]
+    app.add_plugins(metronome::MetronomePlugin);
+    #[cfg(all(feature = "media_controls", not(target_arch = "wasm32")))]
+    app.add_plugins(media_controls::MediaControlsPlugin);
+    #[cfg(all(feature = "update_check", not(target_arch = "wasm32")))]
+    app.add_plugins(update_check::UpdateCheckPlugin);
+    app.run();
 }
 
 fn setup(mut commands: Commands) {
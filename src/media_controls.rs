@@ -0,0 +1,179 @@
+//! OS media controls integration (MPRIS on Linux, SMTC on Windows, Now Playing on macOS).
+//!
+//! Publishes the active reader tab and play state to the OS media overlay, and maps
+//! media keys back into `PlaybackCommand` and `TabSelect` events.
+//! Native only, compiled with the `media_controls` feature.
+
+use std::sync::mpsc::{self, Receiver};
+
+use bevy::log::{info, warn};
+use bevy::prelude::*;
+use souvlaki::{MediaControlEvent, MediaControls, MediaMetadata, MediaPlayback, PlatformConfig};
+
+use crate::playback::PlaybackCommand;
+use crate::reader::ReadingState;
+use crate::tabs::{ActiveTab, Content, ReaderTab, TabOrder, TabSelect};
+
+pub struct MediaControlsPlugin;
+impl Plugin for MediaControlsPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .add_systems(Update, (
+                MediaSession::connect.run_if(MediaSession::is_not_connected),
+                MediaSession::apply_events,
+                MediaSession::publish,
+            ).chain())
+            ;
+    }
+}
+
+const DISPLAY_NAME: &str = "Arre Mind Reader";
+const DBUS_NAME: &str = "arre_mind_reader";
+
+/// Live OS media session. Stored as a non-send resource because some platform
+/// backends are bound to the thread that created them.
+struct MediaSession {
+    controls: MediaControls,
+    events: Receiver<MediaControlEvent>,
+    published: Option<(String, ReadingState)>,
+}
+impl MediaSession {
+    fn is_not_connected(session: Option<NonSend<MediaSession>>) -> bool {
+        session.is_none()
+    }
+
+    /// Attempts to register with the OS once. A missing session bus or other
+    /// platform failure is logged and the integration stays disabled.
+    fn connect(
+        world: &mut World,
+        mut attempted: Local<bool>,
+    ) {
+        if *attempted { return; }
+
+        #[cfg(target_os = "windows")]
+        let hwnd = {
+            let mut windows = world.query_filtered::<&bevy::window::RawHandleWrapper, With<bevy::window::PrimaryWindow>>();
+            let Ok(handles) = windows.single(world) else { return };
+            match handles.get_window_handle() {
+                raw_window_handle::RawWindowHandle::Win32(handle) => Some(handle.hwnd.get() as *mut std::ffi::c_void),
+                _ => None,
+            }
+        };
+        #[cfg(not(target_os = "windows"))]
+        let hwnd = None;
+
+        *attempted = true;
+        let config = PlatformConfig {
+            display_name: DISPLAY_NAME,
+            dbus_name: DBUS_NAME,
+            hwnd,
+        };
+        let mut controls = match MediaControls::new(config) {
+            Ok(controls) => controls,
+            Err(e) => {
+                warn!("OS media controls unavailable: {:?}", e);
+                return;
+            }
+        };
+        let (sender, events) = mpsc::channel();
+        if let Err(e) = controls.attach(move |event| { let _ = sender.send(event); }) {
+            warn!("Failed to attach OS media controls: {:?}", e);
+            return;
+        }
+        info!("OS media controls connected");
+        world.insert_non_send_resource(MediaSession { controls, events, published: None });
+    }
+
+    /// Drains media key events received by the OS callback thread.
+    fn apply_events(
+        mut commands: Commands,
+        session: Option<NonSend<MediaSession>>,
+        current_state: Res<State<ReadingState>>,
+        tab_order: Res<TabOrder>,
+        reader_tabs: Query<Has<ActiveTab>, With<ReaderTab>>,
+    ) {
+        let Some(session) = session else { return };
+        let is_playing = *current_state.get() == ReadingState::Playing;
+
+        for event in session.events.try_iter() {
+            match event {
+                MediaControlEvent::Toggle => commands.trigger(PlaybackCommand::TogglePlayPause),
+                MediaControlEvent::Play if !is_playing => commands.trigger(PlaybackCommand::TogglePlayPause),
+                MediaControlEvent::Pause | MediaControlEvent::Stop if is_playing => {
+                    commands.trigger(PlaybackCommand::TogglePlayPause);
+                }
+                MediaControlEvent::Next => {
+                    if let Some(entity) = Self::cycle_reader_tab(&tab_order, &reader_tabs, 1) {
+                        commands.trigger(TabSelect { entity });
+                    }
+                }
+                MediaControlEvent::Previous => {
+                    if let Some(entity) = Self::cycle_reader_tab(&tab_order, &reader_tabs, -1) {
+                        commands.trigger(TabSelect { entity });
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Reader tab `step` positions away from the active one in `TabOrder`, wrapping around.
+    /// From a non-reader tab, starts at the first (or last) reader tab.
+    fn cycle_reader_tab(
+        tab_order: &TabOrder,
+        reader_tabs: &Query<Has<ActiveTab>, With<ReaderTab>>,
+        step: isize,
+    ) -> Option<Entity> {
+        let readers: Vec<(Entity, bool)> = tab_order.entities().iter()
+            .filter_map(|&entity| reader_tabs.get(entity).ok().map(|is_active| (entity, is_active)))
+            .collect();
+        if readers.is_empty() { return None; }
+
+        let count = readers.len() as isize;
+        let next = match readers.iter().position(|&(_, is_active)| is_active) {
+            Some(current) => (current as isize + step).rem_euclid(count),
+            None if step > 0 => 0,
+            None => count - 1,
+        };
+        Some(readers[next as usize].0)
+    }
+
+    /// Pushes the active tab name, progress and play state to the OS when they change.
+    fn publish(
+        session: Option<NonSendMut<MediaSession>>,
+        current_state: Res<State<ReadingState>>,
+        active_reader: Query<(&Name, &Content), (With<ActiveTab>, With<ReaderTab>)>,
+    ) {
+        let Some(mut session) = session else { return };
+
+        let title = match active_reader.single() {
+            Ok((name, content)) => {
+                let (current, total) = content.progress();
+                let percent = (current + 1) * 100 / total.max(1);
+                format!("{} ({}%)", name, percent)
+            }
+            Err(_) => String::new(),
+        };
+        let snapshot = (title, current_state.get().clone());
+        if session.published.as_ref() == Some(&snapshot) { return; }
+        let (title, state) = &snapshot;
+
+        let metadata = MediaMetadata {
+            title: (!title.is_empty()).then_some(title.as_str()),
+            artist: Some(DISPLAY_NAME),
+            ..default()
+        };
+        let playback = match state {
+            ReadingState::Playing => MediaPlayback::Playing { progress: None },
+            ReadingState::Paused => MediaPlayback::Paused { progress: None },
+            ReadingState::Idle => MediaPlayback::Stopped,
+        };
+        if let Err(e) = session.controls.set_metadata(metadata) {
+            warn!("Failed to publish media metadata: {:?}", e);
+        }
+        if let Err(e) = session.controls.set_playback(playback) {
+            warn!("Failed to publish media playback state: {:?}", e);
+        }
+        session.published = Some(snapshot);
+    }
+}
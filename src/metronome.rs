@@ -0,0 +1,140 @@
+//! Optional click on each word advance. Compiled with the default `metronome`
+//! feature, which also pulls in Bevy's audio.
+//!
+//! The clicks are short decaying sine bursts synthesized into in-memory WAV
+//! files at startup, so no sound assets ship with the app. Words that end a
+//! sentence or paragraph get a lower tone.
+
+use std::f32::consts::TAU;
+use std::sync::Arc;
+use bevy::audio::{AudioSource, Volume};
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::reader::{ReadingState, WordChanged};
+use crate::tabs::{ActiveTab, Content, TabChunkSize};
+
+pub struct MetronomePlugin;
+impl Plugin for MetronomePlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<Metronome>()
+            .add_systems(Startup, MetronomeSounds::setup)
+            .add_observer(Metronome::click_on_word_changed)
+            ;
+    }
+}
+
+const SAMPLE_RATE: u32 = 44_100;
+const CLICK_FREQUENCY_HZ: f32 = 1800.0;
+const CLICK_DURATION_SECS: f32 = 0.025;
+const ACCENT_FREQUENCY_HZ: f32 = 900.0;
+const ACCENT_DURATION_SECS: f32 = 0.04;
+/// Amplitude falls to 1/e this many times over a sound.
+const CLICK_DECAY: f32 = 5.0;
+
+/// Metronome settings. Serialized to disk as part of `ProgramState`.
+#[derive(Resource, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Metronome {
+    pub enabled: bool,
+    /// Linear volume, 0.0 to 1.0.
+    pub volume: f32,
+    /// Lower tone on words that end a sentence or paragraph.
+    pub accent_sentence_ends: bool,
+}
+impl Default for Metronome {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            volume: 0.3,
+            accent_sentence_ends: true,
+        }
+    }
+}
+impl Metronome {
+    /// Plays a click for the chunk just shown. `WordChanged` also fires on seeks
+    /// while paused, which stay silent.
+    fn click_on_word_changed(
+        _trigger: On<WordChanged>,
+        mut commands: Commands,
+        metronome: Res<Metronome>,
+        sounds: Option<Res<MetronomeSounds>>,
+        reading_state: Res<State<ReadingState>>,
+        active_tab: Option<Single<(&Content, &TabChunkSize), With<ActiveTab>>>,
+    ) {
+        if !metronome.enabled || *reading_state.get() != ReadingState::Playing { return; }
+        let (Some(sounds), Some(active_tab)) = (sounds, active_tab) else { return };
+        let (content, chunk_size) = active_tab.into_inner();
+        let chunk = content.current_chunk(chunk_size.0);
+        if chunk.is_empty() { return; }
+        let is_accent = metronome.accent_sentence_ends
+            && chunk.iter().any(|word| word.is_sentence_end() || word.is_paragraph_end);
+        let sound = if is_accent { &sounds.accent } else { &sounds.click };
+        commands.spawn((
+            AudioPlayer(sound.clone()),
+            PlaybackSettings::DESPAWN.with_volume(Volume::Linear(metronome.volume.clamp(0.0, 1.0))),
+        ));
+    }
+}
+
+/// Handles to the synthesized click sounds.
+#[derive(Resource)]
+pub struct MetronomeSounds {
+    click: Handle<AudioSource>,
+    accent: Handle<AudioSource>,
+}
+impl MetronomeSounds {
+    fn setup(mut commands: Commands, mut sources: ResMut<Assets<AudioSource>>) {
+        let mut add = |frequency, duration| sources.add(AudioSource {
+            bytes: Arc::from(click_wav(frequency, duration)),
+        });
+        let click = add(CLICK_FREQUENCY_HZ, CLICK_DURATION_SECS);
+        let accent = add(ACCENT_FREQUENCY_HZ, ACCENT_DURATION_SECS);
+        commands.insert_resource(MetronomeSounds { click, accent });
+    }
+}
+
+/// A decaying sine burst as a 16-bit mono PCM WAV file.
+fn click_wav(frequency_hz: f32, duration_secs: f32) -> Vec<u8> {
+    let sample_count = (SAMPLE_RATE as f32 * duration_secs) as u32;
+    let data_len = sample_count * 2;
+    let mut wav = Vec::with_capacity(44 + data_len as usize);
+    wav.extend_from_slice(b"RIFF");
+    wav.extend_from_slice(&(36 + data_len).to_le_bytes());
+    wav.extend_from_slice(b"WAVE");
+    wav.extend_from_slice(b"fmt ");
+    wav.extend_from_slice(&16u32.to_le_bytes());
+    wav.extend_from_slice(&1u16.to_le_bytes()); // PCM
+    wav.extend_from_slice(&1u16.to_le_bytes()); // mono
+    wav.extend_from_slice(&SAMPLE_RATE.to_le_bytes());
+    wav.extend_from_slice(&(SAMPLE_RATE * 2).to_le_bytes()); // bytes per second
+    wav.extend_from_slice(&2u16.to_le_bytes()); // bytes per frame
+    wav.extend_from_slice(&16u16.to_le_bytes()); // bits per sample
+    wav.extend_from_slice(b"data");
+    wav.extend_from_slice(&data_len.to_le_bytes());
+    for index in 0..sample_count {
+        let t = index as f32 / SAMPLE_RATE as f32;
+        let envelope = (-CLICK_DECAY * t / duration_secs).exp();
+        let sample = (TAU * frequency_hz * t).sin() * envelope;
+        wav.extend_from_slice(&((sample * i16::MAX as f32) as i16).to_le_bytes());
+    }
+    wav
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn click_wav_has_consistent_header() {
+        let wav = click_wav(CLICK_FREQUENCY_HZ, CLICK_DURATION_SECS);
+        let read_u32 = |offset: usize| u32::from_le_bytes(wav[offset..offset + 4].try_into().unwrap());
+
+        assert_eq!(&wav[0..4], b"RIFF");
+        assert_eq!(&wav[8..16], b"WAVEfmt ");
+        assert_eq!(read_u32(4) as usize, wav.len() - 8);
+        assert_eq!(read_u32(40) as usize, wav.len() - 44);
+        assert_eq!(read_u32(40), (SAMPLE_RATE as f32 * CLICK_DURATION_SECS) as u32 * 2);
+    }
+}
@@ -0,0 +1,156 @@
+//! Opening files handed to the app from outside: paths given on the command line
+//! and files dropped onto the window.
+//!
+//! Every path goes through `FileOpen`, which reads and parses the file on the
+//! async task pool and creates an active reader tab for it. Native only.
+
+use std::path::{Path, PathBuf};
+
+use std::collections::VecDeque;
+
+use bevy::log::warn;
+use bevy::prelude::*;
+use bevy::tasks::{block_on, poll_once, AsyncComputeTaskPool, Task};
+
+use crate::persistence::spawn_tabs_from_program_state;
+use crate::tabs::{ReaderTab, TabCreateRequest, TabFilePath, TabSelect};
+use crate::text::FileParsers;
+use crate::ui::{NewTabSettings, RawFileLoad, Toast, ToastKind};
+
+pub struct OpenFilesPlugin;
+impl Plugin for OpenFilesPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<PendingFileOpens>()
+            .add_systems(PostStartup, open_command_line_files.after(spawn_tabs_from_program_state))
+            .add_systems(Update, (
+                open_dropped_files,
+                PendingFileOpens::poll.run_if(PendingFileOpens::is_any),
+            ))
+            .add_observer(FileOpen::on_trigger)
+            ;
+    }
+}
+
+// ============================================================================
+// Resources
+// ============================================================================
+
+/// Files being read and parsed, oldest first. Finished ones open in the order
+/// they were requested, so the last file given ends up active.
+#[derive(Resource, Default)]
+pub struct PendingFileOpens(VecDeque<Task<Result<TabCreateRequest, String>>>);
+impl PendingFileOpens {
+    fn is_any(pending: Res<PendingFileOpens>) -> bool {
+        !pending.0.is_empty()
+    }
+
+    fn poll(mut commands: Commands, mut pending: ResMut<PendingFileOpens>) {
+        while let Some(task) = pending.0.front_mut() {
+            let Some(result) = block_on(poll_once(task)) else { return };
+            pending.0.pop_front();
+            match result {
+                Ok(request) => commands.trigger(request),
+                Err(e) => {
+                    warn!("{}", e);
+                    commands.trigger(Toast::new(ToastKind::Warning, e));
+                }
+            }
+        }
+    }
+}
+
+// ============================================================================
+// Events
+// ============================================================================
+
+/// Opens a file from disk as a new active reader tab. A file that is already
+/// open has its tab selected instead.
+#[derive(Event)]
+pub struct FileOpen {
+    pub path: PathBuf,
+}
+impl FileOpen {
+    fn on_trigger(
+        trigger: On<FileOpen>,
+        mut commands: Commands,
+        mut pending: ResMut<PendingFileOpens>,
+        file_parsers: Res<FileParsers>,
+        reader_tabs: Query<(Entity, &TabFilePath), With<ReaderTab>>,
+    ) {
+        let path = std::fs::canonicalize(&trigger.path).unwrap_or_else(|_| trigger.path.clone());
+        let file_path = path.to_string_lossy().into_owned();
+        if let Some((entity, _)) = reader_tabs.iter().find(|(_, open_path)| open_path.0 == file_path) {
+            commands.trigger(TabSelect { entity });
+            return;
+        }
+
+        let file_parsers = file_parsers.clone();
+        pending.0.push_back(AsyncComputeTaskPool::get().spawn(async move {
+            Self::tab_request(&path, &NewTabSettings::default(), &file_parsers)
+        }));
+    }
+
+    /// Reads the file and hands it to the same load pipeline as the New Tab dialog.
+    fn tab_request(path: &Path, settings: &NewTabSettings, file_parsers: &FileParsers) -> Result<TabCreateRequest, String> {
+        let file_name = path.file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let bytes = std::fs::read(path)
+            .map_err(|e| format!("Cannot read '{}': {}", file_name, e))?;
+        RawFileLoad {
+            file_name,
+            file_path: Some(path.to_string_lossy().into_owned()),
+            source_url: None,
+            bytes,
+        }.tab_request(settings, file_parsers)
+    }
+}
+
+// ============================================================================
+// Systems
+// ============================================================================
+
+/// Opens every file passed as an argument, after saved tabs are restored so the
+/// last one given ends up active.
+fn open_command_line_files(mut commands: Commands) {
+    for path in std::env::args_os().skip(1) {
+        commands.trigger(FileOpen { path: path.into() });
+    }
+}
+
+fn open_dropped_files(mut commands: Commands, mut drops: MessageReader<FileDragAndDrop>) {
+    for drop in drops.read() {
+        if let FileDragAndDrop::DroppedFile { path_buf, .. } = drop {
+            commands.trigger(FileOpen { path: path_buf.clone() });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tab_request_names_the_tab_after_the_file_and_reports_failures() {
+        let file_parsers = FileParsers::new();
+        let dir = std::env::temp_dir().join(format!("arre-mind-reader-{}", crate::persistence::ProgramState::generate_cache_id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let book = dir.join("Short Story.txt");
+        std::fs::write(&book, "Once upon a time.").unwrap();
+        let cover = dir.join("cover.png");
+        std::fs::write(&cover, [0x89, b'P', b'N', b'G']).unwrap();
+
+        // Ephemeral, so no word cache is written into the real config dir.
+        let settings = NewTabSettings { is_ephemeral: true, ..default() };
+        let request = FileOpen::tab_request(&book, &settings, &file_parsers);
+        let unsupported = FileOpen::tab_request(&cover, &settings, &file_parsers);
+        let missing = FileOpen::tab_request(&dir.join("missing.txt"), &settings, &file_parsers);
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        let request = request.unwrap();
+        assert_eq!((request.name.as_str(), request.content.words.len()), ("Short Story", 4));
+        assert!(unsupported.err().unwrap().starts_with("No parser found"));
+        assert!(missing.err().unwrap().starts_with("Cannot read"));
+    }
+}
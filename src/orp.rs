@@ -3,23 +3,54 @@
 //! Renders the current word with the ORP letter highlighted and centered.
 //! Uses three text entities (left, center, right) to keep the focus letter fixed.
 
-use bevy::color::palettes::css::RED;
+use std::borrow::Cow;
+use std::time::Duration;
 use bevy::prelude::*;
-use bevy::sprite::Anchor;
+use bevy::sprite::{update_text2d_layout, Anchor};
+use bevy::text::TextLayoutInfo;
+use bevy::transform::TransformSystems;
+use bevy::window::PrimaryWindow;
+use serde::{Deserialize, Serialize};
+use unicode_segmentation::UnicodeSegmentation;
 
-use crate::reader::WordChanged;
-use crate::tabs::{ActiveTab, Content, HomepageTab, ReaderTab, TabFontSettings};
+use crate::fonts::{FontsStore, CJK_FALLBACK_FONT};
+use crate::reader::{ReadingState, ReadingTimer, WordChanged, HIGHLIGHT_COLOR_DEFAULT};
+use crate::tabs::{ActiveTab, Content, HomepageTab, ReaderTab, TabChunkSize, TabFontSettings, TabWpm};
+use crate::text::WordRef;
+use crate::theme::AppliedTheme;
+use crate::ui::{Toast, ToastKind};
 
 /// Approximate ratio of character width to font size for monospace-like positioning.
-/// Used to offset left/right text so they abut the center ORP character.
+/// Used for auto-fit estimates, and to place the left/right text until the
+/// center ORP character has been laid out.
 const CHAR_WIDTH_RATIO: f32 = 0.6;
 
 pub struct OrpPlugin;
 impl Plugin for OrpPlugin {
     fn build(&self, app: &mut App) {
         app
+            .init_resource::<PacingPulse>()
+            .init_resource::<ReduceMotion>()
+            .init_resource::<WordAutoFit>()
+            .init_resource::<OrpConfig>()
+            .init_resource::<ReticleStyle>()
+            .init_resource::<WordFade>()
+            .init_resource::<WordPreview>()
+            .init_resource::<NumberDisplay>()
             .add_systems(Startup, setup_orp_display)
+            .add_systems(Update, (
+                ReticleMarker::pulse,
+                OrpSegment::fade,
+                OrpSegment::on_orp_config_changed.run_if(resource_changed::<OrpConfig>.or(resource_changed::<NumberDisplay>)),
+                ReticleMarker::on_style_changed.run_if(resource_changed::<ReticleStyle>),
+                OrpSegment::on_theme_changed.run_if(resource_changed::<AppliedTheme>),
+            ))
+            .add_systems(PostUpdate, OrpSegment::abut_measured_center
+                .after(update_text2d_layout)
+                .before(TransformSystems::Propagate))
             .add_observer(OrpSegment::on_word_changed)
+            .add_observer(warn_missing_cjk_font)
+            .add_observer(WordFade::on_word_changed)
             .add_observer(OrpSegment::on_font_settings_inserted)
             .add_observer(ReaderDisplay::on_reader_tab_activated)
             .add_observer(ReaderDisplay::on_homepage_tab_activated)
@@ -29,9 +60,382 @@ impl Plugin for OrpPlugin {
 }
 
 const RETICLE_OFFSET_Y_RATIO: f32 = 0.833;
-const RETICLE_WIDTH_RATIO: f32 = 0.0625;
-const RETICLE_HEIGHT_RATIO: f32 = 0.833;
+pub const RETICLE_WIDTH_RATIO_MIN: f32 = 0.02;
+pub const RETICLE_WIDTH_RATIO_MAX: f32 = 0.25;
+pub const RETICLE_HEIGHT_RATIO_MIN: f32 = 0.2;
+pub const RETICLE_HEIGHT_RATIO_MAX: f32 = 1.5;
 const RETICLE_ALPHA: f32 = 0.5;
+pub const PACING_PULSE_RATE_MIN: f32 = 1.0;
+pub const PACING_PULSE_RATE_MAX: f32 = 2.0;
+/// Upper bound on how far the pacing pulse may dim the reticles.
+/// Kept low to limit the flicker's photosensitivity risk.
+pub const PACING_PULSE_MAX_DEPTH: f32 = 0.3;
+pub const AUTO_FIT_MIN_SCALE_MIN: f32 = 0.2;
+pub const AUTO_FIT_MIN_SCALE_MAX: f32 = 1.0;
+/// Horizontal space kept free between a fitted word and the window edge.
+const AUTO_FIT_MARGIN: f32 = 24.0;
+pub const ORP_PIVOT_FRACTION_MIN: f32 = 0.0;
+pub const ORP_PIVOT_FRACTION_MAX: f32 = 0.7;
+pub const WORD_FADE_MS_MIN: u64 = 10;
+pub const WORD_FADE_MS_MAX: u64 = 200;
+pub const WORD_PREVIEW_COUNT_MIN: usize = 3;
+pub const WORD_PREVIEW_COUNT_MAX: usize = 20;
+/// Shorter digit runs, like years, are shown as written.
+const NUMBER_GROUP_MIN_DIGITS: usize = 5;
+const NUMBER_ONES: [&str; 20] = [
+    "", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine", "ten",
+    "eleven", "twelve", "thirteen", "fourteen", "fifteen", "sixteen", "seventeen", "eighteen", "nineteen",
+];
+const NUMBER_TENS: [&str; 10] = ["", "", "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety"];
+const NUMBER_SCALES: [&str; 7] = ["", "thousand", "million", "billion", "trillion", "quadrillion", "quintillion"];
+/// Longest part of a word's display time the fade may take, so every word is
+/// fully visible for most of its time at any WPM.
+const WORD_FADE_MAX_WORD_FRACTION: f32 = 0.5;
+
+// ============================================================================
+// Resources
+// ============================================================================
+
+/// Optional subvocalization-suppression aid. While playing, the reticles' alpha
+/// oscillates at `rate_multiplier` times the active tab's word rate.
+/// Serialized to disk as part of `ProgramState`.
+#[derive(Resource, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PacingPulse {
+    pub enabled: bool,
+    pub rate_multiplier: f32,
+    pub depth: f32,
+}
+impl Default for PacingPulse {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            rate_multiplier: 1.25,
+            depth: 0.2,
+        }
+    }
+}
+impl PacingPulse {
+    /// Reticle alpha at `elapsed_secs` for the given word rate. Depth is capped
+    /// at `PACING_PULSE_MAX_DEPTH` regardless of the stored value.
+    fn alpha_at(&self, elapsed_secs: f32, wpm: u32) -> f32 {
+        let frequency = wpm as f32 / 60.0 * self.rate_multiplier;
+        let wave = 0.5 + 0.5 * (std::f32::consts::TAU * frequency * elapsed_secs).sin();
+        RETICLE_ALPHA * (1.0 - self.depth.clamp(0.0, PACING_PULSE_MAX_DEPTH) * wave)
+    }
+
+    fn is_active(&self, reduce_motion: &ReduceMotion) -> bool {
+        self.enabled && !reduce_motion.enabled
+    }
+}
+
+/// Accessibility switch that keeps the reticles still: while on, the pacing
+/// pulse stays off whatever its own setting. Serialized to disk as part of `ProgramState`.
+#[derive(Resource, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ReduceMotion {
+    pub enabled: bool,
+}
+
+/// Faint strip of the next words under the reader display, for practicing
+/// peripheral pickup. Serialized to disk as part of `ProgramState`.
+#[derive(Resource, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WordPreview {
+    pub enabled: bool,
+    pub word_count: usize,
+}
+impl Default for WordPreview {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            word_count: 10,
+        }
+    }
+}
+impl WordPreview {
+    pub fn is_enabled(preview: Res<WordPreview>) -> bool {
+        preview.enabled
+    }
+}
+
+/// How long digit runs are shown. Only the displayed text changes; the content
+/// and timing keep the original word. Serialized to disk as part of `ProgramState`.
+#[derive(Resource, Clone, Copy, PartialEq, Eq, Debug, Default, Serialize, Deserialize)]
+pub enum NumberDisplay {
+    #[default]
+    AsWritten,
+    /// "1234567" as "1,234,567".
+    Grouped,
+    /// "1234567" as "one million two hundred thirty-four thousand five hundred sixty-seven".
+    /// Numbers with a fractional part are grouped instead.
+    SpelledOut,
+}
+impl NumberDisplay {
+    pub const ALL: [NumberDisplay; 3] = [NumberDisplay::AsWritten, NumberDisplay::Grouped, NumberDisplay::SpelledOut];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            NumberDisplay::AsWritten => "As written",
+            NumberDisplay::Grouped => "1,234,567",
+            NumberDisplay::SpelledOut => "Spelled out",
+        }
+    }
+
+    /// `text` with each run of at least `NUMBER_GROUP_MIN_DIGITS` digits reformatted.
+    /// Runs with a leading zero (codes, phone numbers) and runs next to another
+    /// separated digit group ("3.14159", "12345,67") are left alone.
+    pub fn apply(self, text: &str) -> Cow<'_, str> {
+        if self == NumberDisplay::AsWritten { return Cow::Borrowed(text); }
+        let bytes = text.as_bytes();
+        let is_separated_digit = |separator: usize, digit: usize| {
+            matches!(bytes.get(separator), Some(b'.' | b',')) && bytes.get(digit).is_some_and(u8::is_ascii_digit)
+        };
+        let mut formatted = String::new();
+        let mut copied = 0;
+        let mut index = 0;
+        while index < bytes.len() {
+            if !bytes[index].is_ascii_digit() {
+                index += 1;
+                continue;
+            }
+            let start = index;
+            while bytes.get(index).is_some_and(u8::is_ascii_digit) {
+                index += 1;
+            }
+            let run = &text[start..index];
+            let follows_group = start >= 2 && is_separated_digit(start - 1, start - 2);
+            let has_decimals = bytes.get(index) == Some(&b'.') && bytes.get(index + 1).is_some_and(u8::is_ascii_digit);
+            let has_comma_group = bytes.get(index) == Some(&b',') && bytes.get(index + 1).is_some_and(u8::is_ascii_digit);
+            if run.len() < NUMBER_GROUP_MIN_DIGITS || run.starts_with('0') || follows_group || has_comma_group { continue; }
+            let spelled = match self {
+                NumberDisplay::SpelledOut if !has_decimals => run.parse().ok().map(spell_out),
+                _ => None,
+            };
+            formatted.push_str(&text[copied..start]);
+            formatted.push_str(&spelled.unwrap_or_else(|| group_digits(run)));
+            copied = index;
+        }
+        if copied == 0 { return Cow::Borrowed(text); }
+        formatted.push_str(&text[copied..]);
+        Cow::Owned(formatted)
+    }
+}
+
+/// ASCII `digits` with a comma between each group of three, counted from the right.
+fn group_digits(digits: &str) -> String {
+    let mut grouped = String::with_capacity(digits.len() + digits.len() / 3);
+    for (position, digit) in digits.chars().enumerate() {
+        if position > 0 && (digits.len() - position).is_multiple_of(3) {
+            grouped.push(',');
+        }
+        grouped.push(digit);
+    }
+    grouped
+}
+
+/// English words for a positive `number`, hyphenating tens and ones ("forty-two").
+fn spell_out(number: u64) -> String {
+    let below_thousand = |number: usize| {
+        let mut parts = Vec::new();
+        if number >= 100 {
+            parts.push(format!("{} hundred", NUMBER_ONES[number / 100]));
+        }
+        match number % 100 {
+            0 => {}
+            rest if rest < 20 => parts.push(NUMBER_ONES[rest].to_string()),
+            rest if rest.is_multiple_of(10) => parts.push(NUMBER_TENS[rest / 10].to_string()),
+            rest => parts.push(format!("{}-{}", NUMBER_TENS[rest / 10], NUMBER_ONES[rest % 10])),
+        }
+        parts.join(" ")
+    };
+    let mut groups = Vec::new();
+    let mut rest = number;
+    for scale in NUMBER_SCALES {
+        let group = (rest % 1000) as usize;
+        if group > 0 {
+            groups.push(if scale.is_empty() { below_thousand(group) } else { format!("{} {}", below_thousand(group), scale) });
+        }
+        rest /= 1000;
+    }
+    groups.reverse();
+    groups.join(" ")
+}
+
+/// Where the fixation letter sits in a word: a word-length table by default,
+/// or a fixed fraction of the word length. Serialized to disk as part of `ProgramState`.
+#[derive(Resource, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct OrpConfig {
+    pub use_pivot_fraction: bool,
+    /// Fixation position as a fraction of the word's length, 0 being the first letter.
+    pub pivot_fraction: f32,
+}
+impl Default for OrpConfig {
+    fn default() -> Self {
+        Self {
+            use_pivot_fraction: false,
+            pivot_fraction: 0.3,
+        }
+    }
+}
+impl OrpConfig {
+    /// Index of the fixation grapheme in a word of `grapheme_count` graphemes.
+    /// Based on RSVP research: longer words need the fixation point further in.
+    pub fn pivot_index(&self, grapheme_count: usize) -> usize {
+        if self.use_pivot_fraction {
+            let fraction = self.pivot_fraction.clamp(ORP_PIVOT_FRACTION_MIN, ORP_PIVOT_FRACTION_MAX);
+            return ((grapheme_count as f32 * fraction) as usize).min(grapheme_count.saturating_sub(1));
+        }
+        match grapheme_count {
+            0 => 0,
+            1 => 0,
+            2..=5 => 1,
+            6..=9 => 2,
+            10..=13 => 3,
+            _ => 4,
+        }
+    }
+}
+
+/// Look of the alignment bars above and below the fixation letter. Sizes are
+/// ratios of the tab's font size. Serialized to disk as part of `ProgramState`.
+#[derive(Resource, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ReticleStyle {
+    pub visible: bool,
+    /// sRGB color; the alpha is owned by `PacingPulse`.
+    pub color: [f32; 3],
+    pub width_ratio: f32,
+    pub height_ratio: f32,
+}
+impl Default for ReticleStyle {
+    fn default() -> Self {
+        Self {
+            visible: true,
+            color: [1.0, 0.0, 0.0],
+            width_ratio: 0.0625,
+            height_ratio: 0.833,
+        }
+    }
+}
+impl ReticleStyle {
+    fn size(&self, font_size: f32) -> Vec2 {
+        let width = self.width_ratio.clamp(RETICLE_WIDTH_RATIO_MIN, RETICLE_WIDTH_RATIO_MAX);
+        let height = self.height_ratio.clamp(RETICLE_HEIGHT_RATIO_MIN, RETICLE_HEIGHT_RATIO_MAX);
+        Vec2::new(font_size * width, font_size * height)
+    }
+    fn color_with_alpha(&self, alpha: f32) -> Color {
+        let [red, green, blue] = self.color;
+        Color::srgba(red, green, blue, alpha)
+    }
+}
+
+/// Shrinks the font for words too wide for the window, one word at a time.
+/// The fixation letter stays at the window center, so the left and right parts
+/// are fitted against their own half of the window.
+/// Serialized to disk as part of `ProgramState`.
+#[derive(Resource, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WordAutoFit {
+    pub enabled: bool,
+    /// Smallest allowed fraction of the configured font size.
+    pub min_scale: f32,
+}
+impl Default for WordAutoFit {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            min_scale: 0.5,
+        }
+    }
+}
+impl WordAutoFit {
+    /// Font size at which `split` fits within `half_width` on both sides of the
+    /// fixation letter, never below `min_scale` of `font_size`.
+    fn fitted_font_size(&self, split: &ChunkSplit, font_size: f32, half_width: f32) -> f32 {
+        if !self.enabled { return font_size; }
+        let char_width = font_size * CHAR_WIDTH_RATIO;
+        // Distances from the center of the fixation letter to the outer edges.
+        let left_extent = (split.left.graphemes(true).count() as f32 + 0.5) * char_width;
+        let right_extent = (split.right.graphemes(true).count() as f32 + 0.5) * char_width;
+        let widest_side = left_extent.max(right_extent);
+        let available = (half_width - AUTO_FIT_MARGIN).max(0.0);
+        if widest_side <= available { return font_size; }
+
+        let min_scale = self.min_scale.clamp(AUTO_FIT_MIN_SCALE_MIN, AUTO_FIT_MIN_SCALE_MAX);
+        font_size * (available / widest_side).max(min_scale)
+    }
+}
+
+/// Optional fade-in of each new word while playing, for smoother swaps at low WPM.
+/// Serialized to disk as part of `ProgramState`.
+#[derive(Resource, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WordFade {
+    pub enabled: bool,
+    pub duration_ms: u64,
+    /// App time when the current word appeared; `None` when it appeared while stopped.
+    #[serde(skip)]
+    shown_at: Option<Duration>,
+}
+impl Default for WordFade {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            duration_ms: 40,
+            shown_at: None,
+        }
+    }
+}
+impl WordFade {
+    /// Text alpha `since_shown` after a word appeared that stays up for `word_duration`.
+    /// The fade is cut short to a fraction of `word_duration` for fast words.
+    fn alpha_at(&self, since_shown: Duration, word_duration: Duration) -> f32 {
+        let configured = Duration::from_millis(self.duration_ms.clamp(WORD_FADE_MS_MIN, WORD_FADE_MS_MAX));
+        let length = configured.min(word_duration.mul_f32(WORD_FADE_MAX_WORD_FRACTION));
+        if length.is_zero() { return 1.0; }
+        (since_shown.as_secs_f32() / length.as_secs_f32()).min(1.0)
+    }
+
+    fn on_word_changed(
+        _trigger: On<WordChanged>,
+        time: Res<Time>,
+        reading_state: Res<State<ReadingState>>,
+        mut fade: ResMut<WordFade>,
+    ) {
+        let is_playing = *reading_state.get() == ReadingState::Playing;
+        fade.shown_at = (fade.enabled && is_playing).then(|| time.elapsed());
+    }
+}
+
+/// Text of one flash split around the fixation letter. A chunk of several
+/// words is space-joined and fixated on its middle word's ORP letter. Numbers
+/// are shown as `NumberDisplay` formats them.
+struct ChunkSplit {
+    left: String,
+    center: String,
+    right: String,
+    is_rtl: bool,
+    has_cjk: bool,
+}
+impl ChunkSplit {
+    fn new(chunk: &[WordRef], orp_config: &OrpConfig, number_display: NumberDisplay) -> Option<Self> {
+        let middle = chunk.len().checked_sub(1)? / 2;
+        let texts: Vec<Cow<str>> = chunk.iter().map(|word| number_display.apply(word.text)).collect();
+        let pivot_word = WordRef { text: &texts[middle], ..chunk[middle] };
+        let (left, center, right) = pivot_word.orp_split(orp_config);
+        let before = texts[..middle].iter().map(Cow::as_ref);
+        let after = texts[middle + 1..].iter().map(Cow::as_ref);
+        Some(Self {
+            left: before.chain([left]).collect::<Vec<_>>().join(" "),
+            center: center.to_string(),
+            right: [right].into_iter().chain(after).collect::<Vec<_>>().join(" "),
+            is_rtl: pivot_word.is_rtl(),
+            has_cjk: chunk.iter().any(WordRef::has_cjk),
+        })
+    }
+}
 
 // ============================================================================
 // Components
@@ -43,6 +447,7 @@ const RETICLE_ALPHA: f32 = 0.5;
 pub struct ReaderDisplay;
 impl ReaderDisplay {
     /// Shows the ORP display and re-inserts the tab's existing `TabFontSettings`
+    #[allow(clippy::type_complexity)]
     fn on_reader_tab_activated(
         _trigger: On<Insert, ActiveTab>,
         mut commands: Commands,
@@ -53,7 +458,7 @@ impl ReaderDisplay {
         for mut visibility in displays.iter_mut() {
             *visibility = Visibility::Inherited;
         }
-        commands.entity(entity).insert(TabFontSettings::from_font(&font_settings.font, font_settings.font_size));
+        commands.entity(entity).insert(font_settings.clone());
         commands.trigger(WordChanged);
     }
 
@@ -77,75 +482,269 @@ enum OrpSegment {
     Right,
 }
 impl OrpSegment {
-    /// Splits the current word at the ORP index into three strings and assigns
-    /// each to its corresponding text entity.
+    #[allow(clippy::too_many_arguments)]
     fn on_word_changed(
         _trigger: On<WordChanged>,
-        active_tab: Single<&Content, With<ActiveTab>>,
-        mut segments: Query<(&mut Text2d, &OrpSegment)>,
+        auto_fit: Res<WordAutoFit>,
+        orp_config: Res<OrpConfig>,
+        number_display: Res<NumberDisplay>,
+        fonts: Res<FontsStore>,
+        active_tab: Single<(&Content, &TabChunkSize, &TabFontSettings), With<ActiveTab>>,
+        window: Option<Single<&Window, With<PrimaryWindow>>>,
+        segments: Query<(&mut Text2d, &mut TextFont, &mut Transform, &mut Anchor, &OrpSegment)>,
+    ) {
+        let (content, chunk_size, font_settings) = active_tab.into_inner();
+        Self::show_word(content, chunk_size, font_settings, &auto_fit, &orp_config, *number_display, &fonts, window.map(Single::into_inner), segments);
+    }
+
+    /// Moves the left and right text flush against the measured width of the
+    /// center letter, correcting the `CHAR_WIDTH_RATIO` estimate for proportional
+    /// fonts. Keeps the estimate while the center has no layout yet (font still
+    /// loading or no word shown).
+    fn abut_measured_center(
+        layouts: Query<(&TextLayoutInfo, &OrpSegment)>,
+        mut segments: Query<(&mut Transform, &OrpSegment)>,
     ) {
-        let Some(word) = active_tab.into_inner().current_word() else { return };
-        
-        let chars: Vec<char> = word.text.chars().collect();
-        let orp_index = word.orp_index();
-        
-        // Split word into three parts around the ORP letter. The center char stays at x=0,
-        // left text grows rightward toward center (Anchor::CenterRight), and right text
-        // grows leftward away from center (Anchor::CenterLeft).
-        let mut left: String = chars[..orp_index].iter().collect();
-        let mut center: String = chars.get(orp_index).map(|c| c.to_string()).unwrap_or_default();
-        let mut right: String = chars.get(orp_index + 1..).map(|s| s.iter().collect()).unwrap_or_default();
-        
-        for (mut text, segment) in segments.iter_mut() {
+        let Some((center_layout, _)) = layouts.iter().find(|(_, segment)| **segment == OrpSegment::Center) else { return };
+        let half_width = center_layout.size.x * 0.5;
+        if half_width <= 0.0 { return; }
+        for (mut transform, segment) in segments.iter_mut() {
+            if *segment == OrpSegment::Center { continue; }
+            // The sign set by `apply_layout` carries the reading direction.
+            let x = transform.translation.x.signum() * half_width;
+            if transform.translation.x != x {
+                transform.translation.x = x;
+            }
+        }
+    }
+
+    /// Re-splits the shown word so pivot and number display changes apply live.
+    #[allow(clippy::type_complexity)]
+    fn on_orp_config_changed(
+        auto_fit: Res<WordAutoFit>,
+        orp_config: Res<OrpConfig>,
+        number_display: Res<NumberDisplay>,
+        fonts: Res<FontsStore>,
+        active_tab: Option<Single<(&Content, &TabChunkSize, &TabFontSettings), With<ActiveTab>>>,
+        window: Option<Single<&Window, With<PrimaryWindow>>>,
+        segments: Query<(&mut Text2d, &mut TextFont, &mut Transform, &mut Anchor, &OrpSegment)>,
+    ) {
+        let Some(active_tab) = active_tab else { return };
+        let (content, chunk_size, font_settings) = active_tab.into_inner();
+        Self::show_word(content, chunk_size, font_settings, &auto_fit, &orp_config, *number_display, &fonts, window.map(Single::into_inner), segments);
+    }
+
+    /// Recolors the text around the fixation letter to contrast with the background.
+    fn on_theme_changed(
+        applied_theme: Res<AppliedTheme>,
+        mut segment_colors: Query<(&mut TextColor, &OrpSegment)>,
+    ) {
+        for (mut color, segment) in segment_colors.iter_mut() {
+            if *segment != OrpSegment::Center {
+                color.0 = applied_theme.text_color();
+            }
+        }
+    }
+
+    /// Applies the `WordFade` alpha to the word text. Words shown while stopped,
+    /// or with the fade disabled, are drawn fully opaque.
+    fn fade(
+        time: Res<Time>,
+        fade: Res<WordFade>,
+        reading_timer: Res<ReadingTimer>,
+        mut segment_colors: Query<&mut TextColor, With<OrpSegment>>,
+    ) {
+        let alpha = match fade.shown_at {
+            Some(shown_at) if fade.enabled => fade.alpha_at(time.elapsed().saturating_sub(shown_at), reading_timer.timer.duration()),
+            _ => 1.0,
+        };
+        for mut color in segment_colors.iter_mut() {
+            if color.0.alpha() != alpha {
+                color.0.set_alpha(alpha);
+            }
+        }
+    }
+
+    /// Splits the current chunk at the ORP index into three strings and assigns
+    /// each to its corresponding text entity, then fits the chunk to the window.
+    #[allow(clippy::too_many_arguments)]
+    fn show_word(
+        content: &Content,
+        chunk_size: &TabChunkSize,
+        font_settings: &TabFontSettings,
+        auto_fit: &WordAutoFit,
+        orp_config: &OrpConfig,
+        number_display: NumberDisplay,
+        fonts: &FontsStore,
+        window: Option<&Window>,
+        mut segments: Query<(&mut Text2d, &mut TextFont, &mut Transform, &mut Anchor, &OrpSegment)>,
+    ) {
+        // Split into three parts around the ORP letter. The center letter stays at x=0;
+        // see `apply_layout` for how the other two parts are anchored against it.
+        let Some(split) = ChunkSplit::new(&content.current_chunk(chunk_size.0), orp_config, number_display) else { return };
+
+        let font_size = match window {
+            Some(window) => auto_fit.fitted_font_size(&split, font_settings.font_size, window.width() * 0.5),
+            None => font_settings.font_size,
+        };
+        let font = Self::font_for(&split, font_settings, fonts).clone();
+        let is_rtl = split.is_rtl;
+        let ChunkSplit { mut left, mut center, mut right, .. } = split;
+        for (mut text, _, _, _, segment) in segments.iter_mut() {
             **text = match segment {
                 OrpSegment::Left => std::mem::take(&mut left),
                 OrpSegment::Center => std::mem::take(&mut center),
                 OrpSegment::Right => std::mem::take(&mut right),
             };
         }
+
+        let segments = segments.iter_mut().map(|(_, font, transform, anchor, segment)| (font, transform, anchor, segment));
+        Self::apply_layout(segments, &font, font_size, is_rtl);
+    }
+
+    /// The tab font, or the CJK fallback for chunks the tab font likely cannot render.
+    fn font_for<'a>(split: &ChunkSplit, font_settings: &'a TabFontSettings, fonts: &'a FontsStore) -> &'a Handle<Font> {
+        match fonts.cjk_fallback() {
+            Some(fallback) if split.has_cjk => &fallback.handle,
+            _ => &font_settings.font.handle,
+        }
+    }
+
+    /// Sets the segments' font and size, and places Left/Right so their edges meet the
+    /// center character's edges. Left (the text before the ORP letter) ends at the
+    /// center's left edge (Anchor::CENTER_RIGHT) and Right starts at its right edge
+    /// (Anchor::CENTER_LEFT). Right-to-left words mirror this so they read from the right.
+    fn apply_layout<'a>(
+        segments: impl Iterator<Item = (Mut<'a, TextFont>, Mut<'a, Transform>, Mut<'a, Anchor>, &'a OrpSegment)>,
+        font: &Handle<Font>,
+        font_size: f32,
+        is_rtl: bool,
+    ) {
+        let half_char = font_size * CHAR_WIDTH_RATIO * 0.5;
+        let direction = if is_rtl { -1.0 } else { 1.0 };
+        let (before_anchor, after_anchor) = if is_rtl {
+            (Anchor::CENTER_LEFT, Anchor::CENTER_RIGHT)
+        } else {
+            (Anchor::CENTER_RIGHT, Anchor::CENTER_LEFT)
+        };
+        for (mut text_font, mut transform, mut anchor, segment) in segments {
+            if text_font.font != *font {
+                text_font.font = font.clone();
+            }
+            if text_font.font_size != font_size {
+                text_font.font_size = font_size;
+            }
+            let (x, segment_anchor) = match segment {
+                OrpSegment::Left => (-half_char * direction, before_anchor),
+                OrpSegment::Center => continue,
+                OrpSegment::Right => (half_char * direction, after_anchor),
+            };
+            transform.translation.x = x;
+            if *anchor != segment_anchor {
+                *anchor = segment_anchor;
+            }
+        }
     }
 
-    /// Single source of truth for applying font to the ORP display.
-    /// Updates font handle, size, and repositions Left/Right segments
-    /// based on estimated character width.
+    /// Single source of truth for applying the tab font to the ORP display.
+    /// Updates the font handle (or CJK fallback), size (auto-fitted for the
+    /// current chunk) and the highlight color of the center letter.
+    #[allow(clippy::too_many_arguments, clippy::type_complexity)]
     fn on_font_settings_inserted(
         _trigger: On<Insert, TabFontSettings>,
-        font_settings: Single<&TabFontSettings, With<ActiveTab>>,
-        mut segments: Query<(&mut TextFont, &mut Transform, &OrpSegment)>,
+        auto_fit: Res<WordAutoFit>,
+        orp_config: Res<OrpConfig>,
+        number_display: Res<NumberDisplay>,
+        fonts: Res<FontsStore>,
+        active_tab: Single<(&TabFontSettings, Option<&Content>, Option<&TabChunkSize>), With<ActiveTab>>,
+        window: Option<Single<&Window, With<PrimaryWindow>>>,
+        mut segments: Query<(&mut TextFont, &mut Transform, &mut Anchor, &OrpSegment)>,
+        mut segment_colors: Query<(&mut TextColor, &OrpSegment)>,
     ) {
-        // half_char = half the estimated width of the center character,
-        // so left/right text edges meet the center character's edges.
-        let half_char = font_settings.font_size * CHAR_WIDTH_RATIO * 0.5;
-
-        for (mut font, mut transform, segment) in segments.iter_mut() {
-            font.font_size = font_settings.font_size;
-            font.font = font_settings.font.handle.clone();
-            match segment {
-                OrpSegment::Left => transform.translation.x = -half_char,
-                OrpSegment::Center => {},
-                OrpSegment::Right => transform.translation.x = half_char,
+        let (font_settings, content, chunk_size) = active_tab.into_inner();
+        let [red, green, blue] = font_settings.highlight_color;
+        for (mut color, segment) in segment_colors.iter_mut() {
+            if *segment == OrpSegment::Center {
+                color.0 = Color::srgb(red, green, blue);
             }
         }
+
+        let chunk_size = chunk_size.map_or(1, |chunk_size| chunk_size.0);
+        let split = content.and_then(|content| ChunkSplit::new(&content.current_chunk(chunk_size), &orp_config, *number_display));
+        let font_size = match (&split, window) {
+            (Some(split), Some(window)) => auto_fit.fitted_font_size(split, font_settings.font_size, window.width() * 0.5),
+            _ => font_settings.font_size,
+        };
+        let font = match &split {
+            Some(split) => Self::font_for(split, font_settings, &fonts),
+            None => &font_settings.font.handle,
+        };
+        Self::apply_layout(segments.iter_mut(), font, font_size, split.is_some_and(|split| split.is_rtl));
     }
 }
 
-/// Visual alignment guides (thin red bars) above and below the ORP letter.
+/// Visual alignment guides (thin bars styled by `ReticleStyle`) above and below the ORP letter.
 #[derive(Component)]
 struct ReticleMarker;
 impl ReticleMarker {
     fn on_font_settings_inserted(
         _trigger: On<Insert, TabFontSettings>,
+        style: Res<ReticleStyle>,
         font_settings: Single<&TabFontSettings, With<ActiveTab>>,
-        mut reticles: Query<(&mut Sprite, &mut Transform), With<ReticleMarker>>,
+        mut reticles: Query<(&mut Sprite, &mut Transform, &mut Visibility), With<ReticleMarker>>,
     ) {
-        let size = font_settings.font_size;
-        let offset_y = size * RETICLE_OFFSET_Y_RATIO;
-        let reticle_size = Vec2::new(size * RETICLE_WIDTH_RATIO, size * RETICLE_HEIGHT_RATIO);
+        Self::apply_style(&style, font_settings.font_size, reticles.iter_mut());
+    }
+
+    /// Restyles the reticles while a reader tab is shown. Otherwise they are
+    /// restyled when a reader tab is next activated.
+    #[allow(clippy::type_complexity)]
+    fn on_style_changed(
+        style: Res<ReticleStyle>,
+        active_reader: Option<Single<&TabFontSettings, (With<ActiveTab>, With<ReaderTab>)>>,
+        mut reticles: Query<(&mut Sprite, &mut Transform, &mut Visibility), With<ReticleMarker>>,
+    ) {
+        let Some(font_settings) = active_reader else { return };
+        Self::apply_style(&style, font_settings.font_size, reticles.iter_mut());
+    }
+
+    fn apply_style<'a>(
+        style: &ReticleStyle,
+        font_size: f32,
+        reticles: impl Iterator<Item = (Mut<'a, Sprite>, Mut<'a, Transform>, Mut<'a, Visibility>)>,
+    ) {
+        let offset_y = font_size * RETICLE_OFFSET_Y_RATIO;
+        let reticle_size = style.size(font_size);
+        let target_visibility = if style.visible { Visibility::Inherited } else { Visibility::Hidden };
 
-        for (mut sprite, mut transform) in reticles.iter_mut() {
+        for (mut sprite, mut transform, mut visibility) in reticles {
             sprite.custom_size = Some(reticle_size);
+            sprite.color = style.color_with_alpha(sprite.color.alpha());
             let sign = transform.translation.y.signum();
             transform.translation.y = sign * offset_y;
+            *visibility = target_visibility;
+        }
+    }
+
+    /// Applies the `PacingPulse` modulation while playing and restores the
+    /// base alpha otherwise or when `ReduceMotion` is on.
+    fn pulse(
+        time: Res<Time>,
+        pacing_pulse: Res<PacingPulse>,
+        reduce_motion: Res<ReduceMotion>,
+        reading_state: Res<State<ReadingState>>,
+        active_tab: Option<Single<&TabWpm, With<ActiveTab>>>,
+        mut reticles: Query<&mut Sprite, With<ReticleMarker>>,
+    ) {
+        let is_pulsing = pacing_pulse.is_active(&reduce_motion) && *reading_state.get() == ReadingState::Playing;
+        let alpha = match active_tab {
+            Some(tab_wpm) if is_pulsing => pacing_pulse.alpha_at(time.elapsed_secs(), tab_wpm.0),
+            _ => RETICLE_ALPHA,
+        };
+        for mut sprite in reticles.iter_mut() {
+            if sprite.color.alpha() != alpha {
+                sprite.color.set_alpha(alpha);
+            }
         }
     }
 }
@@ -158,9 +757,11 @@ fn setup_orp_display(
     mut commands: Commands,
 ) {
     let default_size = crate::reader::FONT_SIZE_DEFAULT;
-    let reticle_color = RED.with_alpha(RETICLE_ALPHA);
-    let reticle_size = Vec2::new(default_size * RETICLE_WIDTH_RATIO, default_size * RETICLE_HEIGHT_RATIO);
+    let reticle_style = ReticleStyle::default();
+    let reticle_color = reticle_style.color_with_alpha(RETICLE_ALPHA);
+    let reticle_size = reticle_style.size(default_size);
     let offset_y = default_size * RETICLE_OFFSET_Y_RATIO;
+    let [highlight_red, highlight_green, highlight_blue] = HIGHLIGHT_COLOR_DEFAULT;
     
     // Top reticle
     commands.spawn((
@@ -192,7 +793,7 @@ fn setup_orp_display(
     // Center text (ORP letter) - fixed at x=0, aligned with reticles
     commands.spawn((
         Text2d::new(""),
-        TextColor(RED.into()),
+        TextColor(Color::srgb(highlight_red, highlight_green, highlight_blue)),
         Anchor::CENTER,
         OrpSegment::Center,
         ReaderDisplay,
@@ -210,3 +811,163 @@ fn setup_orp_display(
     ));
 }
 
+/// Warns once per session when CJK text is shown without the CJK fallback
+/// font, since the tab font then renders it as missing-glyph boxes.
+fn warn_missing_cjk_font(
+    _trigger: On<WordChanged>,
+    mut commands: Commands,
+    mut has_warned: Local<bool>,
+    fonts: Res<FontsStore>,
+    active_tab: Single<(&Content, &TabChunkSize), With<ActiveTab>>,
+) {
+    if *has_warned || fonts.cjk_fallback().is_some() { return; }
+    let (content, chunk_size) = active_tab.into_inner();
+    if !content.current_chunk(chunk_size.0).iter().any(WordRef::has_cjk) { return; }
+    *has_warned = true;
+    let message = if cfg!(target_arch = "wasm32") {
+        "This text has CJK characters the reader font cannot show".to_string()
+    } else {
+        format!("This text has CJK characters the reader font cannot show. Add {} to the fonts folder to display them", CJK_FALLBACK_FONT)
+    };
+    commands.trigger(Toast::new(ToastKind::Warning, message));
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::text::{Word, WordStore};
+
+    fn auto_fit(min_scale: f32) -> WordAutoFit {
+        WordAutoFit { enabled: true, min_scale }
+    }
+
+    fn split(word: &Word) -> ChunkSplit {
+        ChunkSplit::new(&[word.view()], &OrpConfig::default(), NumberDisplay::AsWritten).unwrap()
+    }
+
+    #[test]
+    fn pivot_index_uses_table_by_default_and_fraction_when_enabled() {
+        let table: Vec<usize> = [0, 1, 5, 6, 13, 20].iter().map(|&count| OrpConfig::default().pivot_index(count)).collect();
+        assert_eq!(table, vec![0, 0, 1, 2, 3, 4]);
+
+        let fraction = OrpConfig { use_pivot_fraction: true, pivot_fraction: 0.5 };
+        let indices: Vec<usize> = [0, 1, 4, 9].iter().map(|&count| fraction.pivot_index(count)).collect();
+        assert_eq!(indices, vec![0, 0, 2, 4]);
+    }
+
+    #[test]
+    fn fitted_font_size_keeps_short_words_at_configured_size() {
+        let size = auto_fit(0.5).fitted_font_size(&split(&Word::new("short")), 48.0, 640.0);
+        assert_eq!(size, 48.0);
+    }
+
+    #[test]
+    fn fitted_font_size_shrinks_wider_side_to_fit() {
+        let word = Word::new("incomprehensibilities");
+        let half_width = 300.0;
+        let size = auto_fit(0.2).fitted_font_size(&split(&word), 96.0, half_width);
+
+        let char_width = size * CHAR_WIDTH_RATIO;
+        let char_count = word.view().grapheme_count() as f32;
+        let right_extent = (char_count - word.view().orp_index(&OrpConfig::default()) as f32 - 0.5) * char_width;
+        assert!(size < 96.0);
+        assert!((right_extent - (half_width - AUTO_FIT_MARGIN)).abs() < 0.01);
+    }
+
+    #[test]
+    fn fitted_font_size_respects_scale_floor_and_toggle() {
+        let word = Word::new("incomprehensibilities");
+        assert_eq!(auto_fit(0.75).fitted_font_size(&split(&word), 96.0, 100.0), 72.0);
+
+        let disabled = WordAutoFit { enabled: false, ..auto_fit(0.5) };
+        assert_eq!(disabled.fitted_font_size(&split(&word), 96.0, 100.0), 96.0);
+    }
+
+    #[test]
+    fn word_fade_is_capped_by_word_duration() {
+        let fade = WordFade { enabled: true, duration_ms: 40, ..default() };
+        let ms = Duration::from_millis;
+
+        assert_eq!(fade.alpha_at(ms(0), ms(500)), 0.0);
+        assert!((fade.alpha_at(ms(20), ms(500)) - 0.5).abs() < 1e-4);
+        assert_eq!(fade.alpha_at(ms(40), ms(500)), 1.0);
+        // A 60 ms word fades in over 30 ms, leaving it fully visible for the rest.
+        assert_eq!(fade.alpha_at(ms(30), ms(60)), 1.0);
+        assert_eq!(fade.alpha_at(ms(0), Duration::ZERO), 1.0);
+    }
+
+    #[test]
+    fn reduce_motion_stops_the_pacing_pulse() {
+        let pulse = PacingPulse { enabled: true, ..default() };
+        assert!(pulse.is_active(&ReduceMotion::default()));
+        assert!(!pulse.is_active(&ReduceMotion { enabled: true }));
+        assert!(!PacingPulse::default().is_active(&ReduceMotion::default()));
+    }
+
+    #[test]
+    fn chunk_split_fixates_on_middle_word() {
+        let words = [Word::new("the"), Word::new("quick"), Word::new("fox")];
+        let chunk: Vec<WordRef> = words.iter().map(Word::view).collect();
+
+        let three = ChunkSplit::new(&chunk, &OrpConfig::default(), NumberDisplay::AsWritten).unwrap();
+        assert_eq!((three.left.as_str(), three.center.as_str(), three.right.as_str()), ("the q", "u", "ick fox"));
+
+        let two = ChunkSplit::new(&chunk[..2], &OrpConfig::default(), NumberDisplay::AsWritten).unwrap();
+        assert_eq!((two.left.as_str(), two.center.as_str(), two.right.as_str()), ("t", "h", "e quick"));
+
+        assert!(ChunkSplit::new(&[], &OrpConfig::default(), NumberDisplay::AsWritten).is_none());
+    }
+
+    #[test]
+    fn number_display_groups_long_digit_runs() {
+        let grouped = |text| NumberDisplay::Grouped.apply(text).into_owned();
+        assert_eq!(grouped("1234567"), "1,234,567");
+        assert_eq!(grouped("$12345.50,"), "$12,345.50,");
+        assert_eq!(grouped("123456789012"), "123,456,789,012");
+        assert_eq!(grouped("1984"), "1984");
+        assert_eq!(grouped("01234567"), "01234567");
+        assert_eq!(grouped("3.14159265"), "3.14159265");
+        assert_eq!(grouped("1,234,567"), "1,234,567");
+        assert_eq!(NumberDisplay::AsWritten.apply("1234567"), "1234567");
+    }
+
+    #[test]
+    fn number_display_spells_out_whole_numbers() {
+        let spelled = |text| NumberDisplay::SpelledOut.apply(text).into_owned();
+        assert_eq!(spelled("1234567"), "one million two hundred thirty-four thousand five hundred sixty-seven");
+        assert_eq!(spelled("(40000)"), "(forty thousand)");
+        assert_eq!(spelled("1000010"), "one million ten");
+        assert_eq!(spelled("12345.6"), "12,345.6");
+        assert_eq!(spelled("99999999999999999999"), "99,999,999,999,999,999,999");
+    }
+
+    #[test]
+    fn chunk_split_shows_formatted_numbers() {
+        let word = Word::new("1234567.");
+        let split = ChunkSplit::new(&[word.view()], &OrpConfig::default(), NumberDisplay::Grouped).unwrap();
+        assert_eq!(format!("{}{}{}", split.left, split.center, split.right), "1,234,567.");
+    }
+    #[derive(Resource, Default)]
+    struct ToastCount(usize);
+
+    #[test]
+    fn missing_cjk_font_warns_once_on_cjk_words() {
+        let mut app = App::new();
+        app
+            .insert_resource(FontsStore::from_fonts(Vec::new()))
+            .init_resource::<ToastCount>()
+            .add_observer(warn_missing_cjk_font)
+            .add_observer(|_trigger: On<Toast>, mut count: ResMut<ToastCount>| count.0 += 1)
+            ;
+        let words = WordStore::try_from(["hello", "東京", "大阪"].map(Word::new).to_vec()).unwrap();
+        let tab = app.world_mut().spawn((Content::new_ephemeral(words, Vec::new()), TabChunkSize(1), ActiveTab)).id();
+
+        for index in 0..3 {
+            app.world_mut().get_mut::<Content>(tab).unwrap().current_index = index;
+            app.world_mut().trigger(WordChanged);
+            app.world_mut().flush();
+            assert_eq!(app.world().resource::<ToastCount>().0, index.min(1));
+        }
+    }
+}
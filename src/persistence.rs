@@ -4,72 +4,576 @@
 //! per tab, written once on creation.
 
 use std::collections::HashSet;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
 
 use bevy::log::{debug, info, warn};
+use bevy::ecs::query::QueryData;
+use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
+use bevy::tasks::{block_on, poll_once, AsyncComputeTaskPool, Task};
 use serde::{Deserialize, Serialize};
 
+use crate::bookmarks::{Bookmark, Bookmarks};
+use crate::break_reminder::BreakReminder;
+use crate::calibration::CalibrationHistory;
+use crate::clock::unix_now_secs;
 use crate::fonts::FontsStore;
-use crate::reader::{FONT_SIZE_DEFAULT, FONT_SIZE_MAX, FONT_SIZE_MIN, WPM_DEFAULT, WPM_MAX, WPM_MIN};
+use crate::input::WordSkip;
+#[cfg(feature = "metronome")]
+use crate::metronome::Metronome;
+use crate::orp::{OrpConfig, PacingPulse, ReduceMotion, ReticleStyle, WordAutoFit, NumberDisplay, WordFade, WordPreview};
+use crate::playback::ResumeRewind;
+use crate::queue::ReadingQueue;
+use crate::reader::{AutoPause, ProgressMode, ReadingState, TimingConfig, FONT_SIZE_DEFAULT, FONT_SIZE_MAX, FONT_SIZE_MIN, HIGHLIGHT_COLOR_DEFAULT, WPM_DEFAULT, WPM_MAX, WPM_MIN};
+use crate::stats::{DailyGoal, ReadingStats};
 use crate::tabs::{
-    ActiveTab, Content, DefaultTabSettings, ReaderTab, TabCreateRequest, TabFilePath,
-    TabFontSettings, TabMarker, TabWpm,
+    ActiveTab, ClosedTabHistory, Content, DefaultTabSettings, EphemeralTab, HomepageTab, ReadTime, ReaderTab, TabBookMeta, TabClose, TabCreateRequest, TabSelect,
+    TabChunkSize, TabFilePath, TabFontSettings, TabSourceUrl, TabMarker, TabParseOptions, TabCloseConfirm, TabTextStats, TabTitleWidth, TabWpm, WpmMemory,
 };
-use crate::text::Word;
+use crate::text::{BookMeta, ParseOptions, Section, TextStats, WordStore};
+use crate::theme::{ReaderBackground, Theme};
+#[cfg(all(feature = "update_check", not(target_arch = "wasm32")))]
+use crate::update_check::UpdateCheck;
+use crate::ui::{Toast, ToastKind};
+use crate::vocabulary::Vocabulary;
 
 pub struct PersistencePlugin;
 impl Plugin for PersistencePlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<TabSaveTimer>()
+            .init_resource::<StorageStatus>()
+            .init_resource::<StorageUsage>()
             .add_systems(PostStartup, spawn_tabs_from_program_state)
+            .add_systems(Update, StorageUsage::poll)
             .add_systems(Last, persist_program_state)
+            .add_systems(OnEnter(ReadingState::Paused), TabSaveTimer::request_on_stop)
+            .add_systems(OnEnter(ReadingState::Idle), TabSaveTimer::request_on_stop)
+            .add_observer(TabSaveTimer::request_on::<TabSelect>)
+            .add_observer(TabSaveTimer::request_on::<TabClose>)
+            .add_observer(StorageUsage::on_homepage_selected)
+            .add_observer(MeasureStorage::on_trigger)
+            .add_observer(ClearOrphanCaches::on_trigger)
+            .add_observer(ClearAllData::on_trigger)
             ;
+        #[cfg(not(target_arch = "wasm32"))]
+        app.add_observer(OpenDataDir::on_trigger);
     }
 }
 
 const TABS_FILE: &str = "tabs.ron";
+/// A `tabs.ron` that could not be loaded, set aside so starting fresh loses nothing.
+const TABS_BACKUP_FILE: &str = "tabs.ron.bak";
+/// Text pasted into the New Tab dialog but not opened yet; kept out of `tabs.ron`.
+const PASTE_DRAFT_FILE: &str = "paste_draft.txt";
+/// Earlier saves kept as `tabs.ron.1` (newest) to `tabs.ron.3`, used when `tabs.ron` fails to load.
+#[cfg(not(target_arch = "wasm32"))]
+const TABS_BACKUP_GENERATIONS: usize = 3;
+/// Minimum age of `tabs.ron.1` before a save rotates the backups again, so the
+/// generations span real reading time instead of the last few autosaves.
+#[cfg(not(target_arch = "wasm32"))]
+const TABS_BACKUP_INTERVAL: Duration = Duration::from_secs(30 * 60);
+/// Layout version of `ProgramState`. Files without one are version 0.
+const PROGRAM_STATE_VERSION: u32 = 1;
 const SAVE_INTERVAL_SECS: f32 = 5.0;
+/// Delay of a save requested by a pause or tab change, so a burst of them saves once.
+const SAVE_DEBOUNCE_SECS: f32 = 0.5;
+#[cfg(not(target_arch = "wasm32"))]
+const USER_FONTS_DIR: &str = "fonts";
+/// localStorage key prefix of word caches, followed by the cache id.
+#[cfg(target_arch = "wasm32")]
+const WORD_CACHE_KEY_PREFIX: &str = "word_cache_";
+/// Word caches up to this size are written as pretty RON; larger ones compactly.
+#[cfg(not(target_arch = "wasm32"))]
+const PRETTY_CACHE_MAX_WORDS: usize = 2_000;
+/// Overrides the data directory when set.
+#[cfg(not(target_arch = "wasm32"))]
+const DATA_DIR_ENV: &str = "ARRE_MIND_READER_DATA_DIR";
+/// Portable marker next to the executable; its contents are the data directory path.
+#[cfg(not(target_arch = "wasm32"))]
+const PORTABLE_MARKER_FILE: &str = "arre-mind-reader.datadir";
+
+/// Session-wide data directory chosen by the user after saving failed.
+#[cfg(not(target_arch = "wasm32"))]
+static DATA_DIR_OVERRIDE: std::sync::RwLock<Option<std::path::PathBuf>> = std::sync::RwLock::new(None);
+/// Set by any failed word cache write; consumed by `persist_program_state`.
+static CACHE_WRITE_FAILED: AtomicBool = AtomicBool::new(false);
+/// Held by a `TestDataDir`, so tests redirecting the data directory run one at a time.
+#[cfg(test)]
+static TEST_DATA_DIR_LOCK: std::sync::Mutex<()> = std::sync::Mutex::new(());
+
+/// Points the data directory at a fresh temp dir until dropped, so tests that
+/// write, read or delete word caches never touch the user's data.
+#[cfg(test)]
+pub struct TestDataDir {
+    pub dir: std::path::PathBuf,
+    _lock: std::sync::MutexGuard<'static, ()>,
+}
+#[cfg(test)]
+impl TestDataDir {
+    pub fn acquire() -> Self {
+        let lock = TEST_DATA_DIR_LOCK.lock().unwrap_or_else(std::sync::PoisonError::into_inner);
+        let dir = std::env::temp_dir().join(format!("arre-mind-reader-{}", ProgramState::generate_cache_id()));
+        *DATA_DIR_OVERRIDE.write().unwrap_or_else(std::sync::PoisonError::into_inner) = Some(dir.clone());
+        Self { dir, _lock: lock }
+    }
+}
+#[cfg(test)]
+impl Drop for TestDataDir {
+    fn drop(&mut self) {
+        *DATA_DIR_OVERRIDE.write().unwrap_or_else(std::sync::PoisonError::into_inner) = None;
+        let _ = std::fs::remove_dir_all(&self.dir);
+    }
+}
+
+// ============================================================================
+// Resources
+// ============================================================================
+
+/// Health of on-disk persistence, updated after every save attempt.
+#[derive(Resource, Default)]
+pub struct StorageStatus {
+    /// Last save error; `None` while saves succeed.
+    pub error: Option<String>,
+    /// Set when the data directory changes, so the next save runs immediately
+    /// and rewrites the word caches of open tabs.
+    pub is_relocated: bool,
+    /// Unix time of the last save that succeeded.
+    pub last_saved_secs: Option<u64>,
+}
+impl StorageStatus {
+    /// Human-readable age of the last successful save, e.g. "3 min ago".
+    pub fn describe_last_save(&self) -> Option<String> {
+        let elapsed = unix_now_secs().saturating_sub(self.last_saved_secs?);
+        Some(match elapsed {
+            0..=59 => "just now".to_string(),
+            60..=3_599 => format!("{} min ago", elapsed / 60),
+            _ => format!("{} h ago", elapsed / 3_600),
+        })
+    }
+}
+
+/// Word cache footprint.
+#[derive(Clone, Copy, Default)]
+pub struct CacheUsage {
+    pub file_count: usize,
+    pub total_bytes: u64,
+    /// Caches not referenced by any open tab.
+    pub orphan_count: usize,
+}
+
+/// Last measured `CacheUsage`. Invalidated whenever the homepage is opened, so
+/// the Storage tile requests a fresh measurement, which runs off the main thread.
+#[derive(Resource, Default)]
+pub struct StorageUsage {
+    pub usage: Option<CacheUsage>,
+    task: Option<Task<CacheUsage>>,
+}
+impl StorageUsage {
+    pub fn is_measuring(&self) -> bool {
+        self.task.is_some()
+    }
+
+    fn on_homepage_selected(
+        trigger: On<Insert, ActiveTab>,
+        mut storage_usage: ResMut<StorageUsage>,
+        homepages: Query<(), With<HomepageTab>>,
+    ) {
+        if homepages.contains(trigger.entity) {
+            storage_usage.usage = None;
+        }
+    }
+
+    fn poll(mut storage_usage: ResMut<StorageUsage>) {
+        let Some(task) = &mut storage_usage.task else { return };
+        if let Some(usage) = block_on(poll_once(task)) {
+            storage_usage.usage = Some(usage);
+            storage_usage.task = None;
+        }
+    }
+}
+
+/// Global settings and history stored in `ProgramState`. Grouped into one system
+/// parameter so loading, saving and resetting them share a single field list.
+#[derive(SystemParam)]
+pub struct PersistedSettings<'w> {
+    defaults: ResMut<'w, DefaultTabSettings>,
+    wpm_memory: ResMut<'w, WpmMemory>,
+    tab_close_confirm: ResMut<'w, TabCloseConfirm>,
+    tab_title_width: ResMut<'w, TabTitleWidth>,
+    pacing_pulse: ResMut<'w, PacingPulse>,
+    reduce_motion: ResMut<'w, ReduceMotion>,
+    calibration: ResMut<'w, CalibrationHistory>,
+    reading_queue: ResMut<'w, ReadingQueue>,
+    word_auto_fit: ResMut<'w, WordAutoFit>,
+    orp: ResMut<'w, OrpConfig>,
+    reticle_style: ResMut<'w, ReticleStyle>,
+    timing: ResMut<'w, TimingConfig>,
+    resume_rewind: ResMut<'w, ResumeRewind>,
+    word_skip: ResMut<'w, WordSkip>,
+    auto_pause: ResMut<'w, AutoPause>,
+    progress_mode: ResMut<'w, ProgressMode>,
+    reading_stats: ResMut<'w, ReadingStats>,
+    daily_goal: ResMut<'w, DailyGoal>,
+    vocabulary: ResMut<'w, Vocabulary>,
+    theme: ResMut<'w, Theme>,
+    reader_background: ResMut<'w, ReaderBackground>,
+    break_reminder: ResMut<'w, BreakReminder>,
+    #[cfg(feature = "metronome")]
+    metronome: ResMut<'w, Metronome>,
+    word_fade: ResMut<'w, WordFade>,
+    word_preview: ResMut<'w, WordPreview>,
+    number_display: ResMut<'w, NumberDisplay>,
+    #[cfg(all(feature = "update_check", not(target_arch = "wasm32")))]
+    update_check: ResMut<'w, UpdateCheck>,
+}
+impl PersistedSettings<'_> {
+    fn load(&mut self, state: &ProgramState) {
+        *self.defaults = state.defaults.clone();
+        *self.wpm_memory = state.wpm_memory.clone();
+        *self.tab_close_confirm = state.tab_close_confirm.clone();
+        *self.tab_title_width = state.tab_title_width.clone();
+        *self.pacing_pulse = state.pacing_pulse.clone();
+        *self.reduce_motion = state.reduce_motion.clone();
+        *self.calibration = state.calibration.clone();
+        *self.reading_queue = state.reading_queue.clone();
+        *self.word_auto_fit = state.word_auto_fit.clone();
+        *self.orp = state.orp.clone();
+        *self.reticle_style = state.reticle_style.clone();
+        *self.timing = state.timing.clone();
+        *self.resume_rewind = state.resume_rewind.clone();
+        *self.word_skip = state.word_skip.clone();
+        *self.auto_pause = state.auto_pause.clone();
+        *self.progress_mode = state.progress_mode;
+        *self.reading_stats = state.reading_stats.clone();
+        *self.daily_goal = state.daily_goal.clone();
+        *self.vocabulary = state.vocabulary.clone();
+        *self.theme = state.theme;
+        *self.reader_background = state.reader_background.clone();
+        *self.break_reminder = state.break_reminder.clone();
+        #[cfg(feature = "metronome")]
+        {
+            *self.metronome = state.metronome.clone();
+        }
+        *self.word_fade = state.word_fade.clone();
+        *self.word_preview = state.word_preview.clone();
+        *self.number_display = state.number_display;
+        #[cfg(all(feature = "update_check", not(target_arch = "wasm32")))]
+        {
+            *self.update_check = state.update_check.clone();
+        }
+    }
+    fn reset(&mut self) {
+        self.load(&ProgramState::default());
+    }
+    fn to_program_state(&self, tabs: Vec<SavedTab>) -> ProgramState {
+        ProgramState {
+            version: PROGRAM_STATE_VERSION,
+            tabs,
+            defaults: self.defaults.clone(),
+            wpm_memory: self.wpm_memory.clone(),
+            tab_close_confirm: self.tab_close_confirm.clone(),
+            tab_title_width: self.tab_title_width.clone(),
+            pacing_pulse: self.pacing_pulse.clone(),
+            reduce_motion: self.reduce_motion.clone(),
+            calibration: self.calibration.clone(),
+            reading_queue: self.reading_queue.clone(),
+            word_auto_fit: self.word_auto_fit.clone(),
+            orp: self.orp.clone(),
+            reticle_style: self.reticle_style.clone(),
+            timing: self.timing.clone(),
+            resume_rewind: self.resume_rewind.clone(),
+            word_skip: self.word_skip.clone(),
+            auto_pause: self.auto_pause.clone(),
+            progress_mode: *self.progress_mode,
+            reading_stats: self.reading_stats.clone(),
+            daily_goal: self.daily_goal.clone(),
+            vocabulary: self.vocabulary.clone(),
+            theme: *self.theme,
+            reader_background: self.reader_background.clone(),
+            break_reminder: self.break_reminder.clone(),
+            #[cfg(feature = "metronome")]
+            metronome: self.metronome.clone(),
+            word_fade: self.word_fade.clone(),
+            word_preview: self.word_preview.clone(),
+            number_display: *self.number_display,
+            #[cfg(all(feature = "update_check", not(target_arch = "wasm32")))]
+            update_check: self.update_check.clone(),
+        }
+    }
+}
+
+// ============================================================================
+// Events
+// ============================================================================
+
+/// Starts measuring `StorageUsage` unless a measurement is already running.
+#[derive(Event)]
+pub struct MeasureStorage;
+impl MeasureStorage {
+    fn on_trigger(
+        _trigger: On<MeasureStorage>,
+        mut storage_usage: ResMut<StorageUsage>,
+        reader_tabs: Query<&Content, (With<ReaderTab>, Without<EphemeralTab>)>,
+        closed_tabs: Res<ClosedTabHistory>,
+    ) {
+        if storage_usage.is_measuring() { return; }
+        let valid_ids = cache_ids(&reader_tabs, &closed_tabs);
+        storage_usage.task = Some(AsyncComputeTaskPool::get().spawn(async move {
+            ProgramState::measure_word_caches(&valid_ids)
+        }));
+    }
+}
+
+/// Deletes word caches that no open tab references.
+#[derive(Event)]
+pub struct ClearOrphanCaches;
+impl ClearOrphanCaches {
+    fn on_trigger(
+        _trigger: On<ClearOrphanCaches>,
+        mut commands: Commands,
+        reader_tabs: Query<&Content, (With<ReaderTab>, Without<EphemeralTab>)>,
+        closed_tabs: Res<ClosedTabHistory>,
+    ) {
+        ProgramState::cleanup_orphan_caches(&cache_ids(&reader_tabs, &closed_tabs));
+        commands.trigger(MeasureStorage);
+    }
+}
+
+/// Closes every reader tab, deletes all saved data, and resets persisted
+/// settings to their defaults.
+#[derive(Event)]
+pub struct ClearAllData;
+impl ClearAllData {
+    fn on_trigger(
+        _trigger: On<ClearAllData>,
+        mut commands: Commands,
+        mut settings: PersistedSettings,
+        mut storage_status: ResMut<StorageStatus>,
+        reader_tabs: Query<Entity, With<ReaderTab>>,
+    ) {
+        for entity in reader_tabs.iter() {
+            commands.trigger(TabClose { entity });
+        }
+        // Runs after the queued closes, which record each tab as reopenable.
+        commands.queue(|world: &mut World| world.resource_mut::<ClosedTabHistory>().clear());
+        settings.reset();
+        storage_status.last_saved_secs = None;
+
+        match ProgramState::clear_all() {
+            Ok(()) => commands.trigger(Toast::new(ToastKind::Info, "All saved data cleared")),
+            Err(e) => commands.trigger(Toast::new(ToastKind::Error, e)),
+        }
+        commands.trigger(MeasureStorage);
+    }
+}
+
+/// Opens the data folder in the system file manager.
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Event)]
+pub struct OpenDataDir;
+#[cfg(not(target_arch = "wasm32"))]
+impl OpenDataDir {
+    fn on_trigger(
+        _trigger: On<OpenDataDir>,
+        mut commands: Commands,
+    ) {
+        if let Err(e) = ProgramState::open_data_dir() {
+            commands.trigger(Toast::new(ToastKind::Error, e));
+        }
+    }
+}
+
+/// Word caches still in use: those of open tabs and of closed tabs that can be reopened.
+fn cache_ids(reader_tabs: &Query<&Content, (With<ReaderTab>, Without<EphemeralTab>)>, closed_tabs: &ClosedTabHistory) -> HashSet<String> {
+    reader_tabs.iter()
+        .map(|content| content.content_cache_id.clone())
+        .chain(closed_tabs.cache_ids().map(str::to_string))
+        .collect()
+}
 
 // ============================================================================
 // Persistence-only Data Structures
 // ============================================================================
 
-/// Serialization-only mirror of a reader tab's ECS components.
+/// Reader tab components captured by `SavedTab::snapshot`.
+#[derive(QueryData)]
+pub struct SavedTabQuery {
+    name: &'static Name,
+    font_settings: &'static TabFontSettings,
+    wpm: &'static TabWpm,
+    chunk_size: &'static TabChunkSize,
+    content: &'static Content,
+    stats: &'static TabTextStats,
+    book_meta: Option<&'static TabBookMeta>,
+    parse_options: &'static TabParseOptions,
+    bookmarks: &'static Bookmarks,
+    read_time: &'static ReadTime,
+    file_path: Option<&'static TabFilePath>,
+    source_url: Option<&'static TabSourceUrl>,
+    is_active: Has<ActiveTab>,
+}
+
+/// Serialization mirror of a reader tab's ECS components, also kept in
+/// `ClosedTabHistory` to reopen closed tabs.
 /// Font is stored as a name string (resolved back to `FontData` on load).
 #[derive(Serialize, Deserialize)]
 #[serde(default)]
-struct SavedTab {
+pub struct SavedTab {
     name: String,
     file_path: Option<String>,
+    source_url: Option<String>,
     font_name: String,
     font_size: f32,
+    highlight_color: [f32; 3],
     wpm: u32,
+    chunk_size: usize,
     content_cache_id: String,
     current_index: usize,
     is_active: bool,
+    stats: TextStats,
+    book_meta: BookMeta,
+    parse_options: ParseOptions,
+    bookmarks: Vec<Bookmark>,
+    read_time: Duration,
 }
 impl Default for SavedTab {
     fn default() -> Self {
         Self {
             name: String::new(),
             file_path: None,
+            source_url: None,
             font_name: String::new(),
             font_size: FONT_SIZE_DEFAULT,
+            highlight_color: HIGHLIGHT_COLOR_DEFAULT,
             wpm: WPM_DEFAULT,
+            chunk_size: 1,
             content_cache_id: String::new(),
             current_index: 0,
             is_active: false,
+            stats: TextStats::default(),
+            book_meta: BookMeta::default(),
+            parse_options: ParseOptions::saved_default(),
+            bookmarks: Vec::new(),
+            read_time: Duration::ZERO,
         }
     }
 }
+impl SavedTab {
+    pub fn snapshot(tab: &SavedTabQueryItem) -> Self {
+        Self {
+            name: tab.name.to_string(),
+            file_path: tab.file_path.map(|fp| fp.0.clone()),
+            source_url: tab.source_url.map(|url| url.0.clone()),
+            font_name: tab.font_settings.font.name.clone(),
+            font_size: tab.font_settings.font_size,
+            highlight_color: tab.font_settings.highlight_color,
+            wpm: tab.wpm.0,
+            chunk_size: tab.chunk_size.0,
+            content_cache_id: tab.content.content_cache_id.clone(),
+            current_index: tab.content.current_index,
+            is_active: tab.is_active,
+            stats: tab.stats.0,
+            book_meta: tab.book_meta.map(|book_meta| book_meta.0.clone()).unwrap_or_default(),
+            parse_options: tab.parse_options.0.clone(),
+            bookmarks: tab.bookmarks.0.clone(),
+            read_time: tab.read_time.0,
+        }
+    }
+
+    pub fn content_cache_id(&self) -> &str {
+        &self.content_cache_id
+    }
+
+    /// Rebuilds the tab from its cached words, clamping values that may be out of range.
+    pub fn into_request(self, words: WordStore, sections: Vec<Section>) -> TabCreateRequest {
+        let current_index = self.current_index.min(words.len().saturating_sub(1));
+        let wpm = self.wpm.clamp(WPM_MIN, WPM_MAX);
+        let font_size = self.font_size.clamp(FONT_SIZE_MIN, FONT_SIZE_MAX);
+        let content = Content::new_from_loaded(self.content_cache_id, words, sections, current_index);
+        let mut request = TabCreateRequest::new(self.name, content)
+            .with_font(self.font_name, font_size)
+            .with_highlight_color(self.highlight_color.map(|channel| channel.clamp(0.0, 1.0)))
+            .with_wpm(wpm)
+            .with_chunk_size(self.chunk_size)
+            .with_active(self.is_active)
+            .with_book_meta(self.book_meta)
+            .with_parse_options(self.parse_options)
+            .with_bookmarks(self.bookmarks)
+            .with_read_time(self.read_time);
+
+        // Tabs saved before stats were recorded fall back to counting the loaded words.
+        if self.stats.word_count > 0 {
+            request = request.with_stats(self.stats);
+        }
+        if let Some(path) = self.file_path {
+            request = request.with_file_path(path);
+        }
+        if let Some(url) = self.source_url {
+            request = request.with_source_url(url);
+        }
+        request
+    }
+}
+
+/// Word cache contents: a tab's words and the sections parsed with them.
+#[derive(Deserialize)]
+pub struct WordCache {
+    pub words: WordStore,
+    #[serde(default)]
+    pub sections: Vec<Section>,
+}
+impl WordCache {
+    /// Caches written before sections were stored hold a bare word list.
+    fn parse(content: &str) -> Option<Self> {
+        ron::from_str(content).ok()
+            .or_else(|| ron::from_str(content).ok().map(|words| Self { words, sections: Vec::new() }))
+    }
+}
+
+/// Borrowed form of `WordCache`, for writing.
+#[derive(Serialize)]
+struct WordCacheRef<'a> {
+    words: &'a WordStore,
+    sections: &'a [Section],
+}
 
 /// Root serialization structure written to `tabs.ron`.
 /// Contains all reader tabs and the global default settings.
 #[derive(Serialize, Deserialize, Default)]
 #[serde(default)]
 pub struct ProgramState {
+    version: u32,
     tabs: Vec<SavedTab>,
     defaults: DefaultTabSettings,
+    wpm_memory: WpmMemory,
+    tab_close_confirm: TabCloseConfirm,
+    tab_title_width: TabTitleWidth,
+    pacing_pulse: PacingPulse,
+    reduce_motion: ReduceMotion,
+    calibration: CalibrationHistory,
+    reading_queue: ReadingQueue,
+    word_auto_fit: WordAutoFit,
+    orp: OrpConfig,
+    reticle_style: ReticleStyle,
+    timing: TimingConfig,
+    resume_rewind: ResumeRewind,
+    word_skip: WordSkip,
+    auto_pause: AutoPause,
+    progress_mode: ProgressMode,
+    reading_stats: ReadingStats,
+    daily_goal: DailyGoal,
+    vocabulary: Vocabulary,
+    theme: Theme,
+    reader_background: ReaderBackground,
+    break_reminder: BreakReminder,
+    #[cfg(feature = "metronome")]
+    metronome: Metronome,
+    word_fade: WordFade,
+    word_preview: WordPreview,
+    number_display: NumberDisplay,
+    #[cfg(all(feature = "update_check", not(target_arch = "wasm32")))]
+    update_check: UpdateCheck,
 }
 impl ProgramState {
     /// Generates a unique ID using timestamp + atomic counter.
@@ -93,49 +597,212 @@ impl ProgramState {
 
         format!("{:x}_{}", timestamp, count)
     }
+    /// Parses saved state and upgrades older layouts. Fails on unreadable content
+    /// and on files written by a newer version, which must not be overwritten.
+    fn parse(content: &str) -> Result<Self, String> {
+        let state: ProgramState = ron::from_str(content)
+            .map_err(|e| format!("Failed to parse tabs file: {}", e))?;
+        if state.version > PROGRAM_STATE_VERSION {
+            return Err(format!("Tabs file has unknown version {}", state.version));
+        }
+        Ok(state.migrate())
+    }
+    /// Upgrades to `PROGRAM_STATE_VERSION`, one version at a time.
+    fn migrate(mut self) -> Self {
+        // 0 → 1: only the version field is new; fields added since have serde defaults.
+        self.version = PROGRAM_STATE_VERSION;
+        self
+    }
+    fn take_cache_write_failure() -> bool {
+        CACHE_WRITE_FAILED.swap(false, Ordering::Relaxed)
+    }
+    fn cache_ids(&self) -> HashSet<String> {
+        self.tabs.iter().map(|tab| tab.content_cache_id.clone()).collect()
+    }
 }
 #[cfg(not(target_arch = "wasm32"))]
 impl ProgramState {
+    /// Resolution order: directory chosen this session, `DATA_DIR_ENV`,
+    /// portable marker next to the executable, then the OS config directory.
     fn config_dir() -> Option<std::path::PathBuf> {
+        if let Some(dir) = DATA_DIR_OVERRIDE.read().ok().and_then(|dir| dir.clone()) {
+            return Some(dir);
+        }
+        if let Some(dir) = std::env::var_os(DATA_DIR_ENV).filter(|dir| !dir.is_empty()) {
+            return Some(dir.into());
+        }
+        if let Some(dir) = Self::portable_marker_path()
+            .and_then(|marker| std::fs::read_to_string(marker).ok())
+            .map(|contents| contents.trim().to_string())
+            .filter(|dir| !dir.is_empty())
+        {
+            return Some(dir.into());
+        }
         dirs::config_dir().map(|p| p.join("arre-mind-reader"))
     }
+    /// Folder of the user's own fonts, loaded next to the bundled ones.
+    pub fn user_fonts_dir() -> Option<std::path::PathBuf> {
+        Self::config_dir().map(|dir| dir.join(USER_FONTS_DIR))
+    }
     fn cache_dir() -> Option<std::path::PathBuf> {
-        Self::config_dir().map(|p| p.join("cache"))
+        Self::config_dir().map(|dir| Self::cache_dir_in(&dir))
+    }
+    fn cache_dir_in(dir: &std::path::Path) -> std::path::PathBuf {
+        dir.join("cache")
+    }
+    fn portable_marker_path() -> Option<std::path::PathBuf> {
+        let exe = std::env::current_exe().ok()?;
+        Some(exe.parent()?.join(PORTABLE_MARKER_FILE))
+    }
+    /// Switches all further IO to `dir` for this session. With `remember`, also
+    /// writes the portable marker so later sessions use it too.
+    pub fn relocate_data_dir(dir: std::path::PathBuf, remember: bool) -> Result<(), String> {
+        std::fs::create_dir_all(&dir)
+            .map_err(|e| format!("Cannot use {}: {}", dir.display(), e))?;
+        if remember {
+            let marker = Self::portable_marker_path()
+                .ok_or_else(|| "Could not locate the executable directory".to_string())?;
+            std::fs::write(&marker, dir.to_string_lossy().as_bytes())
+                .map_err(|e| format!("Failed to write {}: {}", marker.display(), e))?;
+        }
+        info!("Data directory relocated to {:?}", dir);
+        if let Ok(mut data_dir) = DATA_DIR_OVERRIDE.write() {
+            *data_dir = Some(dir);
+        }
+        Ok(())
     }
-    pub fn write_word_cache(cache_id: &str, words: &[Word]) {
+    pub fn write_word_cache(cache_id: &str, words: &WordStore, sections: &[Section]) {
         let Some(dir) = Self::cache_dir() else {
             warn!("Could not determine cache directory");
+            CACHE_WRITE_FAILED.store(true, Ordering::Relaxed);
             return;
         };
         if let Err(e) = std::fs::create_dir_all(&dir) {
             warn!("Failed to create cache directory: {}", e);
+            CACHE_WRITE_FAILED.store(true, Ordering::Relaxed);
             return;
         }
         let path = dir.join(format!("{}.ron", cache_id));
-        match ron::ser::to_string(words) {
+        // Small caches stay readable when opened by hand; large ones are kept compact.
+        let cache = WordCacheRef { words, sections };
+        let serialized = if words.len() <= PRETTY_CACHE_MAX_WORDS {
+            ron::ser::to_string_pretty(&cache, ron::ser::PrettyConfig::default())
+        } else {
+            ron::ser::to_string(&cache)
+        };
+        match serialized {
             Ok(content) => {
                 if let Err(e) = std::fs::write(&path, content) {
                     warn!("Failed to write word cache: {}", e);
+                    CACHE_WRITE_FAILED.store(true, Ordering::Relaxed);
                 }
             }
             Err(e) => warn!("Failed to serialize word cache: {}", e),
         }
     }
-    pub fn load_word_cache(cache_id: &str) -> Option<Vec<Word>> {
+    pub fn has_word_cache(cache_id: &str) -> bool {
+        Self::cache_dir().is_some_and(|dir| dir.join(format!("{}.ron", cache_id)).exists())
+    }
+    pub fn load_word_cache(cache_id: &str) -> Option<WordCache> {
         let path = Self::cache_dir()?.join(format!("{}.ron", cache_id));
         let content = std::fs::read_to_string(&path).ok()?;
-        ron::from_str(&content).ok()
+        WordCache::parse(&content)
     }
     pub fn delete_word_cache(cache_id: &str) {
         if let Some(path) = Self::cache_dir().map(|d| d.join(format!("{}.ron", cache_id))) {
             let _ = std::fs::remove_file(path);
         }
     }
+    /// Folder holding `tabs.ron` and the word caches.
+    pub fn storage_location() -> String {
+        Self::config_dir()
+            .map(|dir| dir.display().to_string())
+            .unwrap_or_else(|| "unknown".to_string())
+    }
+    fn open_data_dir() -> Result<(), String> {
+        let dir = Self::config_dir()
+            .ok_or_else(|| "Could not determine config directory".to_string())?;
+        std::fs::create_dir_all(&dir)
+            .map_err(|e| format!("Cannot create {}: {}", dir.display(), e))?;
+        let program = if cfg!(target_os = "windows") {
+            "explorer"
+        } else if cfg!(target_os = "macos") {
+            "open"
+        } else {
+            "xdg-open"
+        };
+        std::process::Command::new(program)
+            .arg(&dir)
+            .spawn()
+            .map(drop)
+            .map_err(|e| format!("Cannot open {}: {}", dir.display(), e))
+    }
+    fn measure_word_caches(valid_ids: &HashSet<String>) -> CacheUsage {
+        let mut usage = CacheUsage::default();
+        let Some(dir) = Self::cache_dir() else { return usage };
+        let Ok(entries) = std::fs::read_dir(&dir) else { return usage };
+        for entry in entries.flatten() {
+            let Ok(metadata) = entry.metadata() else { continue };
+            if !metadata.is_file() { continue; }
+            usage.file_count += 1;
+            usage.total_bytes += metadata.len();
+            let is_referenced = entry.path().file_stem()
+                .and_then(|s| s.to_str())
+                .is_some_and(|stem| valid_ids.contains(stem));
+            if !is_referenced {
+                usage.orphan_count += 1;
+            }
+        }
+        usage
+    }
+    /// Deletes `tabs.ron` and every word cache.
+    fn clear_all() -> Result<(), String> {
+        let (Some(dir), Some(cache_dir)) = (Self::config_dir(), Self::cache_dir()) else {
+            return Err("Could not determine config directory".to_string());
+        };
+        let backups = (1..=TABS_BACKUP_GENERATIONS).map(|generation| Self::backup_path(&dir, generation));
+        for path in [dir.join(TABS_FILE), dir.join(TABS_BACKUP_FILE), dir.join(PASTE_DRAFT_FILE), cache_dir].into_iter().chain(backups) {
+            let result = if path.is_dir() {
+                std::fs::remove_dir_all(&path)
+            } else {
+                std::fs::remove_file(&path)
+            };
+            match result {
+                Err(e) if e.kind() != std::io::ErrorKind::NotFound => {
+                    return Err(format!("Cannot delete {}: {}", path.display(), e));
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+    /// Writes the New Tab paste draft, or deletes it when `text` is empty.
+    pub fn save_paste_draft(text: &str) {
+        let Some(dir) = Self::config_dir() else { return };
+        let path = dir.join(PASTE_DRAFT_FILE);
+        let result = if text.is_empty() {
+            std::fs::remove_file(&path).or_else(|e| if e.kind() == std::io::ErrorKind::NotFound { Ok(()) } else { Err(e) })
+        } else {
+            std::fs::create_dir_all(&dir).and_then(|()| std::fs::write(&path, text))
+        };
+        if let Err(e) = result {
+            warn!("Failed to save the paste draft to {:?}: {}", path, e);
+        }
+    }
+    pub fn load_paste_draft() -> String {
+        Self::config_dir()
+            .and_then(|dir| std::fs::read_to_string(dir.join(PASTE_DRAFT_FILE)).ok())
+            .unwrap_or_default()
+    }
     /// Removes cache files not referenced by any saved tab.
     /// Prevents unbounded disk growth from closed/abandoned tabs.
     fn cleanup_orphan_caches(valid_ids: &HashSet<String>) {
         let Some(dir) = Self::cache_dir() else { return };
-        let Ok(entries) = std::fs::read_dir(&dir) else { return };
+        Self::cleanup_orphan_caches_in(&dir, valid_ids);
+    }
+    #[allow(clippy::collapsible_if)]
+    fn cleanup_orphan_caches_in(dir: &std::path::Path, valid_ids: &HashSet<String>) {
+        let Ok(entries) = std::fs::read_dir(dir) else { return };
         for entry in entries.flatten() {
             if let Some(stem) = entry.path().file_stem().and_then(|s| s.to_str()) {
                 if !valid_ids.contains(stem) {
@@ -145,129 +812,275 @@ impl ProgramState {
             }
         }
     }
-    fn save(&self) {
+    fn save(&self) -> Result<(), String> {
         let Some(dir) = Self::config_dir() else {
-            warn!("Could not determine config directory for saving");
-            return;
+            return Err("Could not determine config directory for saving".to_string());
         };
-        if let Err(e) = std::fs::create_dir_all(&dir) {
-            warn!("Failed to create config directory: {}", e);
-            return;
-        }
+        std::fs::create_dir_all(&dir)
+            .map_err(|e| format!("Failed to create config directory {}: {}", dir.display(), e))?;
         let path = dir.join(TABS_FILE);
-        match ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default()) {
-            Ok(content) => {
-                if let Err(e) = std::fs::write(&path, content) {
-                    warn!("Failed to write tabs file: {}", e);
-                } else {
-                    debug!("Saved {} tabs to {:?}", self.tabs.len(), path);
-                }
-            }
-            Err(e) => warn!("Failed to serialize tabs: {}", e),
+        let content = ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())
+            .map_err(|e| format!("Failed to serialize tabs: {}", e))?;
+        if Self::is_backup_due(&dir) {
+            Self::rotate_backups(&dir);
         }
+        std::fs::write(&path, content)
+            .map_err(|e| format!("Failed to write {}: {}", path.display(), e))?;
+        debug!("Saved {} tabs to {:?}", self.tabs.len(), path);
+        Ok(())
     }
     fn load() -> Self {
         let Some(dir) = Self::config_dir() else {
             warn!("Could not determine config directory");
             return ProgramState::default();
         };
+        Self::load_in(&dir)
+    }
+    /// Loads the saved state in `dir` and deletes word caches no loaded tab uses.
+    /// When the saved state could not be loaded, all caches are kept: the file set
+    /// aside as `TABS_BACKUP_FILE` may still reference them.
+    fn load_in(dir: &std::path::Path) -> Self {
+        let (state, is_fallback) = Self::load_from(dir);
+        if is_fallback {
+            warn!("Keeping all word caches, as the saved state could not be loaded");
+        } else {
+            Self::cleanup_orphan_caches_in(&Self::cache_dir_in(dir), &state.cache_ids());
+        }
+        state
+    }
+    /// Reads `tabs.ron` in `dir`. The flag is set when the file exists but could not
+    /// be loaded, so the state is a backup or the default.
+    fn load_from(dir: &std::path::Path) -> (Self, bool) {
         let path = dir.join(TABS_FILE);
         if !path.exists() {
             debug!("No saved tabs file found at {:?}", path);
-            return ProgramState::default();
+            return (ProgramState::default(), false);
         }
-        match std::fs::read_to_string(&path) {
-            Ok(content) => match ron::from_str::<ProgramState>(&content) {
+        let state = match std::fs::read_to_string(&path) {
+            Ok(content) => match Self::parse(&content) {
                 Ok(state) => {
                     debug!("Loaded {} tabs from {:?}", state.tabs.len(), path);
-                    state
+                    return (state, false);
                 }
                 Err(e) => {
-                    warn!("Failed to parse tabs file, starting fresh: {}", e);
-                    ProgramState::default()
+                    // Moved rather than copied so the next save does not rotate it into the backups.
+                    let set_aside = dir.join(TABS_BACKUP_FILE);
+                    match std::fs::rename(&path, &set_aside) {
+                        Ok(()) => warn!("{}. The file was moved to {:?}", e, set_aside),
+                        Err(move_error) => warn!("{}. Moving it to {:?} failed: {}", e, set_aside, move_error),
+                    }
+                    Self::restore_from_backup(dir).unwrap_or_else(|| {
+                        warn!("No usable backup, starting fresh");
+                        ProgramState::default()
+                    })
                 }
             },
             Err(e) => {
                 warn!("Failed to read tabs file: {}", e);
                 ProgramState::default()
             }
+        };
+        (state, true)
+    }
+    fn backup_path(dir: &std::path::Path, generation: usize) -> std::path::PathBuf {
+        dir.join(format!("{}.{}", TABS_FILE, generation))
+    }
+    /// True when there is no `tabs.ron.1` or it is older than `TABS_BACKUP_INTERVAL`.
+    fn is_backup_due(dir: &std::path::Path) -> bool {
+        std::fs::metadata(Self::backup_path(dir, 1))
+            .and_then(|metadata| metadata.modified())
+            .ok()
+            .and_then(|modified| modified.elapsed().ok())
+            .is_none_or(|age| age >= TABS_BACKUP_INTERVAL)
+    }
+    /// Shifts each `tabs.ron.N` up one generation, dropping the oldest, and copies
+    /// the current `tabs.ron` to `tabs.ron.1`.
+    fn rotate_backups(dir: &std::path::Path) {
+        let path = dir.join(TABS_FILE);
+        if !path.exists() { return; }
+        for generation in (1..TABS_BACKUP_GENERATIONS).rev() {
+            let older = Self::backup_path(dir, generation);
+            if older.exists() && let Err(e) = std::fs::rename(&older, Self::backup_path(dir, generation + 1)) {
+                warn!("Failed to rotate {:?}: {}", older, e);
+            }
+        }
+        if let Err(e) = std::fs::copy(&path, Self::backup_path(dir, 1)) {
+            warn!("Failed to back up {:?}: {}", path, e);
         }
     }
+    /// Loads the newest backup that parses, for when `tabs.ron` cannot be loaded.
+    fn restore_from_backup(dir: &std::path::Path) -> Option<Self> {
+        (1..=TABS_BACKUP_GENERATIONS).find_map(|generation| {
+            let path = Self::backup_path(dir, generation);
+            let state = Self::parse(&std::fs::read_to_string(&path).ok()?).ok()?;
+            warn!("Restored {} tabs from backup {:?}", state.tabs.len(), path);
+            Some(state)
+        })
+    }
 }
 
 #[cfg(target_arch = "wasm32")]
 impl ProgramState {
     fn cache_key(cache_id: &str) -> String {
-        format!("word_cache_{}", cache_id)
+        format!("{}{}", WORD_CACHE_KEY_PREFIX, cache_id)
     }
-    pub fn write_word_cache(cache_id: &str, words: &[Word]) {
+    /// Ids of all word caches in localStorage, whether or not a tab uses them.
+    fn stored_cache_ids() -> Vec<String> {
+        let Some(storage) = web_sys::window().and_then(|window| window.local_storage().ok().flatten()) else {
+            return Vec::new();
+        };
+        let count = storage.length().unwrap_or(0);
+        (0..count)
+            .filter_map(|index| storage.key(index).ok().flatten())
+            .filter_map(|key| key.strip_prefix(WORD_CACHE_KEY_PREFIX).map(str::to_string))
+            .collect()
+    }
+    pub fn write_word_cache(cache_id: &str, words: &WordStore, sections: &[Section]) {
         use gloo_storage::Storage;
-        match ron::ser::to_string(words) {
+        match ron::ser::to_string(&WordCacheRef { words, sections }) {
             Ok(content) => {
                 if let Err(e) = gloo_storage::LocalStorage::set(&Self::cache_key(cache_id), content) {
                     warn!("Failed to write word cache to localStorage: {:?}", e);
+                    CACHE_WRITE_FAILED.store(true, Ordering::Relaxed);
                 }
             }
             Err(e) => warn!("Failed to serialize word cache: {}", e),
         }
     }
-    pub fn load_word_cache(cache_id: &str) -> Option<Vec<Word>> {
+    pub fn load_word_cache(cache_id: &str) -> Option<WordCache> {
         use gloo_storage::Storage;
         let content: String = gloo_storage::LocalStorage::get(&Self::cache_key(cache_id)).ok()?;
-        ron::from_str(&content).ok()
+        WordCache::parse(&content)
+    }
+    pub fn has_word_cache(cache_id: &str) -> bool {
+        use gloo_storage::Storage;
+        gloo_storage::LocalStorage::get::<String>(&Self::cache_key(cache_id)).is_ok()
     }
     pub fn delete_word_cache(cache_id: &str) {
         use gloo_storage::Storage;
         gloo_storage::LocalStorage::delete(&Self::cache_key(cache_id));
     }
-    fn cleanup_orphan_caches(_valid_ids: &HashSet<String>) {
-        // localStorage iteration not available without extra web-sys features.
+    /// Removes word caches not referenced by any saved tab, keeping localStorage within quota.
+    fn cleanup_orphan_caches(valid_ids: &HashSet<String>) {
+        for cache_id in Self::stored_cache_ids() {
+            if !valid_ids.contains(&cache_id) {
+                debug!("Removing orphan cache: {}", cache_id);
+                Self::delete_word_cache(&cache_id);
+            }
+        }
+    }
+    pub fn storage_location() -> String {
+        "browser localStorage".to_string()
     }
-    fn save(&self) {
+    fn measure_word_caches(valid_ids: &HashSet<String>) -> CacheUsage {
         use gloo_storage::Storage;
-        match ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default()) {
-            Ok(content) => {
-                if let Err(e) = gloo_storage::LocalStorage::set(TABS_FILE, content) {
-                    warn!("Failed to save to localStorage: {:?}", e);
-                } else {
-                    debug!("Saved {} tabs to localStorage", self.tabs.len());
-                }
+        let mut usage = CacheUsage::default();
+        for cache_id in Self::stored_cache_ids() {
+            let Ok(content) = gloo_storage::LocalStorage::get::<String>(&Self::cache_key(&cache_id)) else { continue };
+            usage.file_count += 1;
+            usage.total_bytes += content.len() as u64;
+            if !valid_ids.contains(&cache_id) {
+                usage.orphan_count += 1;
             }
-            Err(e) => warn!("Failed to serialize tabs: {}", e),
         }
+        usage
+    }
+    /// Clears this origin's localStorage: saved tabs, settings, and word caches.
+    fn clear_all() -> Result<(), String> {
+        use gloo_storage::Storage;
+        gloo_storage::LocalStorage::clear();
+        Ok(())
+    }
+    /// Writes the New Tab paste draft, or deletes it when `text` is empty.
+    pub fn save_paste_draft(text: &str) {
+        use gloo_storage::Storage;
+        if text.is_empty() {
+            gloo_storage::LocalStorage::delete(PASTE_DRAFT_FILE);
+        } else if let Err(e) = gloo_storage::LocalStorage::set(PASTE_DRAFT_FILE, text) {
+            warn!("Failed to save the paste draft to localStorage: {:?}", e);
+        }
+    }
+    pub fn load_paste_draft() -> String {
+        use gloo_storage::Storage;
+        gloo_storage::LocalStorage::get(PASTE_DRAFT_FILE).unwrap_or_default()
+    }
+    fn save(&self) -> Result<(), String> {
+        use gloo_storage::Storage;
+        let content = ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())
+            .map_err(|e| format!("Failed to serialize tabs: {}", e))?;
+        gloo_storage::LocalStorage::set(TABS_FILE, content)
+            .map_err(|e| format!("Failed to save to localStorage: {:?}", e))?;
+        debug!("Saved {} tabs to localStorage", self.tabs.len());
+        Ok(())
     }
+    /// Loads the saved state and deletes word caches no loaded tab uses. A state
+    /// that cannot be parsed keeps all caches, as its copy may still reference them.
     fn load() -> Self {
         use gloo_storage::Storage;
         match gloo_storage::LocalStorage::get::<String>(TABS_FILE) {
-            Ok(content) => match ron::from_str::<ProgramState>(&content) {
+            Ok(content) => match Self::parse(&content) {
                 Ok(state) => {
                     debug!("Loaded {} tabs from localStorage", state.tabs.len());
+                    Self::cleanup_orphan_caches(&state.cache_ids());
                     state
                 }
                 Err(e) => {
-                    warn!("Failed to parse tabs from localStorage, starting fresh: {}", e);
+                    match gloo_storage::LocalStorage::set(TABS_BACKUP_FILE, content) {
+                        Ok(()) => warn!("{}, starting fresh. The saved state was copied to {}", e, TABS_BACKUP_FILE),
+                        Err(write_error) => warn!("{}, starting fresh. Backup failed: {:?}", e, write_error),
+                    }
+                    warn!("Keeping all word caches, as the saved state could not be loaded");
                     ProgramState::default()
                 }
             },
             Err(_) => {
                 debug!("No saved tabs found in localStorage");
+                Self::cleanup_orphan_caches(&HashSet::new());
                 ProgramState::default()
             }
         }
     }
 }
 
+/// Periodic save timer, plus the countdown of a save requested sooner.
 #[derive(Resource)]
 struct TabSaveTimer {
     timer: Timer,
+    debounce: Option<Timer>,
 }
 
 impl Default for TabSaveTimer {
     fn default() -> Self {
         Self {
             timer: Timer::from_seconds(SAVE_INTERVAL_SECS, TimerMode::Repeating),
+            debounce: None,
+        }
+    }
+}
+impl TabSaveTimer {
+    /// Saves within `SAVE_DEBOUNCE_SECS`. Requests made while one is pending share its save.
+    fn request(&mut self) {
+        if self.debounce.is_none() {
+            self.debounce = Some(Timer::from_seconds(SAVE_DEBOUNCE_SECS, TimerMode::Once));
         }
     }
+    /// Advances the timers and returns whether a save is due. A requested save
+    /// restarts the periodic interval.
+    fn tick(&mut self, delta: Duration) -> bool {
+        self.timer.tick(delta);
+        let is_requested = self.debounce.as_mut().is_some_and(|debounce| debounce.tick(delta).is_finished());
+        if is_requested {
+            self.debounce = None;
+            self.timer.reset();
+        }
+        is_requested || self.timer.just_finished()
+    }
+    fn request_on_stop(mut save_timer: ResMut<TabSaveTimer>) {
+        save_timer.request();
+    }
+    fn request_on<E: Event>(_trigger: On<E>, mut save_timer: ResMut<TabSaveTimer>) {
+        save_timer.request();
+    }
 }
 
 // ============================================================================
@@ -276,26 +1089,22 @@ impl Default for TabSaveTimer {
 
 /// Restores tabs from the last saved `ProgramState`. Runs at `PostStartup`
 /// (after fonts are loaded) so `FontsStore::resolve` can validate font names.
-/// Also cleans up orphan word caches from previously-closed tabs.
-fn spawn_tabs_from_program_state(
+/// Orphan word caches from previously-closed tabs are cleaned up by `ProgramState::load`.
+pub fn spawn_tabs_from_program_state(
     mut commands: Commands,
-    mut defaults: ResMut<DefaultTabSettings>,
+    mut settings: PersistedSettings,
     fonts: Res<FontsStore>,
 ) {
     let program_state = ProgramState::load();
 
-    *defaults = program_state.defaults.clone();
+    settings.load(&program_state);
     // Check if the current font still exists. If not, it will be overwritten with default font
-    defaults.font_name = fonts.resolve(&defaults.font_name).name.clone();
+    settings.defaults.font_name = fonts.resolve(&settings.defaults.font_name).name.clone();
     let total_tabs = program_state.tabs.len();
 
-    let valid_ids: HashSet<String> = program_state.tabs.iter()
-        .map(|t| t.content_cache_id.clone())
-        .collect();
-
     let mut restored = 0;
     for tab in program_state.tabs {
-        let Some(words) = ProgramState::load_word_cache(&tab.content_cache_id) else {
+        let Some(WordCache { words, sections }) = ProgramState::load_word_cache(&tab.content_cache_id) else {
             warn!("Cache miss for tab '{}' ({}), skipping", tab.name, tab.content_cache_id);
             continue;
         };
@@ -304,71 +1113,94 @@ fn spawn_tabs_from_program_state(
             continue;
         }
 
-        let current_index = tab.current_index.min(words.len().saturating_sub(1));
-        let wpm = tab.wpm.clamp(WPM_MIN, WPM_MAX);
-        let font_size = tab.font_size.clamp(FONT_SIZE_MIN, FONT_SIZE_MAX);
-        let content = Content::new_from_loaded(tab.content_cache_id, words, current_index);
-        let mut request = TabCreateRequest::new(tab.name, content)
-            .with_font(tab.font_name, font_size)
-            .with_wpm(wpm)
-            .with_active(tab.is_active);
-
-        if let Some(path) = tab.file_path {
-            request = request.with_file_path(path);
-        }
-
-        commands.trigger(request);
+        commands.trigger(tab.into_request(words, sections));
         restored += 1;
     }
 
-    ProgramState::cleanup_orphan_caches(&valid_ids);
     info!("Restored {}/{} tabs from saved state", restored, total_tabs);
 }
 
-/// Snapshots all reader tab state to disk on a timer and on app exit.
+/// Snapshots all non-ephemeral reader tab state to disk on a timer, shortly after
+/// pausing or changing tabs, and on app exit, and records the outcome in `StorageStatus`.
+#[allow(clippy::type_complexity)]
 fn persist_program_state(
     time: Res<Time>,
     mut save_timer: ResMut<TabSaveTimer>,
+    mut storage_status: ResMut<StorageStatus>,
     app_exit_events: MessageReader<AppExit>,
-    defaults: Res<DefaultTabSettings>,
-    tabs: Query<(
-        &Name,
-        &TabFontSettings,
-        &TabWpm,
-        &Content,
-        Option<&TabFilePath>,
-        Has<ActiveTab>,
-    ), (With<TabMarker>, With<ReaderTab>)>,
+    settings: PersistedSettings,
+    tabs: Query<SavedTabQuery, (With<TabMarker>, With<ReaderTab>, Without<EphemeralTab>)>,
 ) {
-    save_timer.timer.tick(time.delta());
-    if !save_timer.timer.just_finished() && app_exit_events.is_empty() { return; }
+    let is_due = save_timer.tick(time.delta());
+    let is_relocated = std::mem::take(&mut storage_status.is_relocated);
+    if !is_due && app_exit_events.is_empty() && !is_relocated { return; }
 
-    let saved_tabs: Vec<SavedTab> = tabs.iter()
-        .map(|(name, font_settings, wpm, content, file_path, is_active)| {
-            SavedTab {
-                name: name.to_string(),
-                file_path: file_path.map(|fp| fp.0.clone()),
-                font_name: font_settings.font.name.clone(),
-                font_size: font_settings.font_size,
-                wpm: wpm.0,
-                content_cache_id: content.content_cache_id.clone(),
-                current_index: content.current_index,
-                is_active,
+    if is_relocated {
+        for tab in tabs.iter() {
+            if !ProgramState::has_word_cache(&tab.content.content_cache_id) {
+                ProgramState::write_word_cache(&tab.content.content_cache_id, &tab.content.words, &tab.content.sections);
             }
-        })
+        }
+    }
+
+    let saved_tabs: Vec<SavedTab> = tabs.iter()
+        .map(|tab| SavedTab::snapshot(&tab))
         .collect();
 
-    ProgramState {
-        tabs: saved_tabs,
-        defaults: defaults.clone(),
-    }.save();
-    debug!("The program state was saved");
+    let result = settings.to_program_state(saved_tabs).save();
+
+    let error = match result {
+        Err(e) => Some(e),
+        Ok(()) if ProgramState::take_cache_write_failure() => Some("Failed to write word cache".to_string()),
+        Ok(()) => None,
+    };
+    if let Some(e) = &error {
+        warn!("The program state was not saved: {}", e);
+    } else {
+        debug!("The program state was saved");
+        storage_status.last_saved_secs = Some(unix_now_secs());
+    }
+    if storage_status.error != error {
+        storage_status.error = error;
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn requested_saves_are_debounced_and_restart_the_interval() {
+        let half_debounce = Duration::from_secs_f32(SAVE_DEBOUNCE_SECS / 2.0);
+        let mut save_timer = TabSaveTimer::default();
+
+        save_timer.request();
+        assert!(!save_timer.tick(half_debounce));
+        save_timer.request();
+        assert!(save_timer.tick(half_debounce));
+
+        assert!(!save_timer.tick(Duration::from_secs_f32(SAVE_INTERVAL_SECS - 0.1)));
+        assert!(save_timer.tick(Duration::from_secs_f32(0.2)));
+    }
+
+    #[test]
+    fn word_cache_round_trips_sections_and_reads_bare_word_lists() {
+        use crate::text::Word;
+
+        let words = WordStore::try_from(vec![Word::new("One"), Word::new("Two")]).unwrap();
+        let sections = vec![Section { title: "Second".into(), start_index: 1 }];
+        let written = ron::ser::to_string(&WordCacheRef { words: &words, sections: &sections }).unwrap();
+        let cache = WordCache::parse(&written).expect("Word cache should parse");
+        assert_eq!(cache.words.len(), 2);
+        assert_eq!(cache.sections.len(), 1);
+        assert_eq!((cache.sections[0].title.as_str(), cache.sections[0].start_index), ("Second", 1));
+
+        let legacy = ron::ser::to_string(&words).unwrap();
+        let cache = WordCache::parse(&legacy).expect("Legacy word cache should parse");
+        assert_eq!(cache.words.len(), 2);
+        assert!(cache.sections.is_empty());
+    }
+
     #[test]
     fn program_state_deserializes_with_missing_tab_fields() {
         let ron = r#"(
@@ -397,6 +1229,69 @@ mod tests {
         assert_eq!(state.defaults.wpm, WPM_DEFAULT);
     }
 
+    #[test]
+    fn unversioned_program_state_migrates_and_keeps_its_tabs() {
+        let ron = r#"(
+            tabs: [
+                (
+                    name: "Moby Dick",
+                    wpm: 420,
+                    content_cache_id: "cache-1",
+                    current_index: 17,
+                    is_active: true,
+                ),
+            ],
+            defaults: (font_size: 48.0),
+        )"#;
+
+        let state = ProgramState::parse(ron).expect("version 0 state should load");
+
+        assert_eq!(state.version, PROGRAM_STATE_VERSION);
+        assert_eq!(state.tabs.len(), 1);
+        assert_eq!((state.tabs[0].name.as_str(), state.tabs[0].wpm, state.tabs[0].current_index), ("Moby Dick", 420, 17));
+        assert_eq!((state.defaults.font_size, state.defaults.wpm), (48.0, WPM_DEFAULT));
+    }
+
+    #[test]
+    fn program_state_from_a_newer_version_is_rejected() {
+        let ron = format!("(version: {}, tabs: [])", PROGRAM_STATE_VERSION + 1);
+
+        assert!(ProgramState::parse(&ron).is_err());
+        assert!(ProgramState::parse("not ron").is_err());
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[test]
+    fn program_state_from_a_newer_version_keeps_its_word_caches() {
+        let dir = std::env::temp_dir().join(format!("arre-mind-reader-{}", ProgramState::generate_cache_id()));
+        let cache_dir = ProgramState::cache_dir_in(&dir);
+        std::fs::create_dir_all(&cache_dir).unwrap();
+        let newer = format!("(version: {}, tabs: [(name: \"Book\", content_cache_id: \"book\")])", PROGRAM_STATE_VERSION + 1);
+        std::fs::write(dir.join(TABS_FILE), &newer).unwrap();
+        std::fs::write(cache_dir.join("book.ron"), "[]").unwrap();
+
+        let state = ProgramState::load_in(&dir);
+        let is_cache_kept = cache_dir.join("book.ron").exists();
+        let set_aside = std::fs::read_to_string(dir.join(TABS_BACKUP_FILE)).unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert!(state.tabs.is_empty());
+        assert!(is_cache_kept);
+        assert_eq!(set_aside, newer);
+    }
+
+    #[test]
+    fn closed_tab_history_drops_the_oldest_beyond_its_cap() {
+        let mut closed_tabs = ClosedTabHistory::default();
+        let dropped: Vec<Option<String>> = (0..12)
+            .map(|index| closed_tabs.push(index, SavedTab { content_cache_id: format!("cache-{}", index), ..default() }))
+            .collect();
+
+        assert!(dropped[..10].iter().all(Option::is_none));
+        assert_eq!(dropped[10..], [Some("cache-0".to_string()), Some("cache-1".to_string())]);
+        assert_eq!(closed_tabs.cache_ids().next(), Some("cache-2"));
+    }
+
     #[test]
     fn default_tab_settings_deserializes_with_partial_fields() {
         let ron = r#"(
@@ -412,4 +1307,58 @@ mod tests {
         assert_eq!(state.defaults.font_name, String::new());
         assert_eq!(state.defaults.font_size, FONT_SIZE_DEFAULT);
     }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[test]
+    fn relocate_data_dir_rejects_unusable_directory() {
+        let data_dir = TestDataDir::acquire();
+        let blocker = std::env::temp_dir().join(format!("arre-mind-reader-{}", ProgramState::generate_cache_id()));
+        std::fs::write(&blocker, "not a directory").unwrap();
+
+        let result = ProgramState::relocate_data_dir(blocker.join("data"), false);
+        std::fs::remove_file(&blocker).unwrap();
+
+        assert!(result.is_err());
+        assert_eq!(ProgramState::config_dir(), Some(data_dir.dir.clone()));
+    }
+
+    #[test]
+    fn backups_rotate_and_restore_skips_unreadable_ones() {
+        let dir = std::env::temp_dir().join(format!("arre-mind-reader-{}", ProgramState::generate_cache_id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let saved_state = |name: &str| format!("(version: {}, tabs: [(name: {:?})])", PROGRAM_STATE_VERSION, name);
+        for name in ["first", "second", "third", "fourth", "fifth"] {
+            ProgramState::rotate_backups(&dir);
+            std::fs::write(dir.join(TABS_FILE), saved_state(name)).unwrap();
+        }
+        let read = |generation| std::fs::read_to_string(ProgramState::backup_path(&dir, generation)).unwrap();
+        let rotated = [read(1), read(2), read(3)];
+        let has_fourth_generation = ProgramState::backup_path(&dir, 4).exists();
+
+        std::fs::write(ProgramState::backup_path(&dir, 1), "not ron").unwrap();
+        let restored = ProgramState::restore_from_backup(&dir);
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(rotated, [saved_state("fourth"), saved_state("third"), saved_state("second")]);
+        assert!(!has_fourth_generation);
+        assert_eq!(restored.unwrap().tabs[0].name, "third");
+    }
+
+    #[test]
+    fn backups_rotate_only_once_the_newest_is_old() {
+        let dir = std::env::temp_dir().join(format!("arre-mind-reader-{}", ProgramState::generate_cache_id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let is_due_without_backup = ProgramState::is_backup_due(&dir);
+        std::fs::write(dir.join(TABS_FILE), "()").unwrap();
+        ProgramState::rotate_backups(&dir);
+        let is_due_after_rotation = ProgramState::is_backup_due(&dir);
+        let backup = std::fs::File::options().write(true).open(ProgramState::backup_path(&dir, 1)).unwrap();
+        backup.set_modified(std::time::SystemTime::now() - TABS_BACKUP_INTERVAL).unwrap();
+        let is_due_once_old = ProgramState::is_backup_due(&dir);
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert!(is_due_without_backup);
+        assert!(!is_due_after_rotation);
+        assert!(is_due_once_old);
+    }
 }
@@ -2,20 +2,51 @@
 //!
 //! Centralizes playback control logic that can be triggered from UI or keyboard.
 
+use std::time::Duration;
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 
 use crate::tabs::{ActiveTab, Content, TabWpm};
-use crate::reader::ReadingState;
+use crate::reader::{ContentNavigate, ReadingState, WordChanged};
 
 pub struct PlaybackPlugin;
 impl Plugin for PlaybackPlugin {
     fn build(&self, app: &mut App) {
         app
+            .init_resource::<ResumeRewind>()
+            .init_resource::<PausedAt>()
             .add_observer(PlaybackCommand::on_trigger)
             ;
     }
 }
 
+pub const RESUME_REWIND_MAX: usize = 20;
+/// Resuming sooner than this after pausing keeps the position: it was a quick toggle, not a break.
+const RESUME_REWIND_GRACE: Duration = Duration::from_millis(500);
+
+// ============================================================================
+// Resources
+// ============================================================================
+
+/// Words stepped back when playback resumes, to recover context after a pause.
+/// 0 disables it. Serialized to disk as part of `ProgramState`.
+#[derive(Resource, Clone, Serialize, Deserialize)]
+pub struct ResumeRewind(pub usize);
+impl Default for ResumeRewind {
+    fn default() -> Self {
+        Self(3)
+    }
+}
+
+/// The last Playing → Paused transition.
+#[derive(Resource, Default)]
+pub struct PausedAt {
+    /// Real time of the pause.
+    pub time: Option<Duration>,
+    /// Paused by `AutoPause`: the position is already a clean boundary, so resuming does not rewind.
+    pub is_boundary: bool,
+}
+
 // ============================================================================
 // Playback Commands
 // ============================================================================
@@ -26,24 +57,47 @@ impl Plugin for PlaybackPlugin {
 pub enum PlaybackCommand {
     TogglePlayPause,
     AdjustWpm(i32),
+    /// Sets an absolute WPM, clamped to `WPM_MIN..=WPM_MAX`.
+    SetWpm(u32),
+    /// Stops playback and returns to the first word.
+    Restart,
+    /// Moves one word while paused or idle; ignored during playback.
+    StepForward,
+    StepBackward,
+    /// Pauses playback at a paragraph or section boundary; see `AutoPause`.
+    PauseAtBoundary,
 }
 impl PlaybackCommand {
     /// Central command handler. Uses `Query` (not `Single`) for `active_tabs` because
     /// some commands (e.g. `Stop`) are valid even without an active reader tab.
+    #[allow(clippy::too_many_arguments)]
     fn on_trigger(
         trigger: On<PlaybackCommand>,
+        mut commands: Commands,
+        time: Res<Time<Real>>,
+        resume_rewind: Res<ResumeRewind>,
+        mut paused_at: ResMut<PausedAt>,
         current_state: Res<State<ReadingState>>,
         mut next_state: ResMut<NextState<ReadingState>>,
-        mut active_tabs: Query<(&mut TabWpm, &Content), With<ActiveTab>>,
+        mut active_tabs: Query<(&mut TabWpm, &mut Content), With<ActiveTab>>,
     ) {
         match trigger.event() {
             PlaybackCommand::TogglePlayPause => {
                 match current_state.get() {
-                    ReadingState::Playing => next_state.set(ReadingState::Paused),
+                    ReadingState::Playing => {
+                        next_state.set(ReadingState::Paused);
+                        *paused_at = PausedAt { time: Some(time.elapsed()), is_boundary: false };
+                    }
                     _ => {
                         let can_play = active_tabs.single()
                             .is_ok_and(|(_, content)| content.has_words());
                         if can_play {
+                            let paused = std::mem::take(&mut *paused_at);
+                            let is_quick_toggle = paused.time
+                                .is_some_and(|paused| time.elapsed().saturating_sub(paused) < RESUME_REWIND_GRACE);
+                            if resume_rewind.0 > 0 && !is_quick_toggle && !paused.is_boundary {
+                                commands.trigger(ContentNavigate::SkipBackward(resume_rewind.0));
+                            }
                             next_state.set(ReadingState::Playing);
                         }
                     }
@@ -57,6 +111,34 @@ impl PlaybackCommand {
                     tab_wpm.0 = new_wpm as u32;
                 }
             }
+            PlaybackCommand::SetWpm(wpm) => {
+                if let Ok((mut tab_wpm, _)) = active_tabs.single_mut() {
+                    tab_wpm.0 = (*wpm).clamp(crate::reader::WPM_MIN, crate::reader::WPM_MAX);
+                }
+            }
+            PlaybackCommand::Restart => {
+                if let Ok((_, mut content)) = active_tabs.single_mut() {
+                    content.restart();
+                    next_state.set(ReadingState::Idle);
+                    commands.trigger(WordChanged);
+                }
+            }
+            PlaybackCommand::StepForward => {
+                if *current_state.get() != ReadingState::Playing {
+                    commands.trigger(ContentNavigate::SkipForward(1));
+                }
+            }
+            PlaybackCommand::StepBackward => {
+                if *current_state.get() != ReadingState::Playing {
+                    commands.trigger(ContentNavigate::SkipBackward(1));
+                }
+            }
+            PlaybackCommand::PauseAtBoundary => {
+                if *current_state.get() == ReadingState::Playing {
+                    next_state.set(ReadingState::Paused);
+                    *paused_at = PausedAt { time: Some(time.elapsed()), is_boundary: true };
+                }
+            }
         }
     }
 }
@@ -66,6 +148,7 @@ impl PlaybackCommand {
 mod tests {
     use super::*;
     use crate::reader::{ReadingState, WPM_MAX, WPM_MIN};
+    use crate::tabs::{ReaderTab, TabChunkSize};
     use crate::text::Word;
 
     fn make_test_app() -> App {
@@ -74,7 +157,10 @@ mod tests {
             .add_plugins(MinimalPlugins)
             .add_plugins(bevy::state::app::StatesPlugin)
             .init_state::<ReadingState>()
+            .init_resource::<ResumeRewind>()
+            .init_resource::<PausedAt>()
             .add_observer(PlaybackCommand::on_trigger)
+            .add_observer(Content::on_navigate)
             ;
         app
     }
@@ -82,12 +168,10 @@ mod tests {
     fn spawn_active_tab(app: &mut App, words: Vec<Word>, current_index: usize, wpm: u32) -> Entity {
         app.world_mut().spawn((
             ActiveTab,
+            ReaderTab,
             TabWpm(wpm),
-            Content {
-                content_cache_id: "test-cache".into(),
-                words,
-                current_index,
-            },
+            TabChunkSize(1),
+            Content::new_from_loaded("test-cache".into(), words.try_into().unwrap(), Vec::new(), current_index),
         )).id()
     }
 
@@ -116,6 +200,97 @@ mod tests {
         assert_eq!(app.world().resource::<State<ReadingState>>().get(), &ReadingState::Paused);
     }
 
+    #[test]
+    fn resuming_rewinds_unless_toggled_right_back() {
+        let mut app = make_test_app();
+        let words = (0..20).map(|index| Word::new(format!("w{index}"))).collect();
+        let active_tab_entity = spawn_active_tab(&mut app, words, 10, 300);
+        let current_index = |app: &App| app.world().entity(active_tab_entity).get::<Content>().unwrap().current_index;
+
+        app.world_mut().trigger(PlaybackCommand::TogglePlayPause);
+        app.update();
+        assert_eq!(current_index(&app), 7);
+
+        app.world_mut().trigger(PlaybackCommand::TogglePlayPause);
+        app.update();
+        app.world_mut().trigger(PlaybackCommand::TogglePlayPause);
+        app.update();
+        assert_eq!(app.world().resource::<State<ReadingState>>().get(), &ReadingState::Playing);
+        assert_eq!(current_index(&app), 7);
+
+        app.world_mut().trigger(PlaybackCommand::TogglePlayPause);
+        app.update();
+        app.insert_resource(bevy::time::TimeUpdateStrategy::ManualDuration(Duration::from_secs(5)));
+        app.update();
+        app.world_mut().trigger(PlaybackCommand::TogglePlayPause);
+        app.update();
+        assert_eq!(current_index(&app), 4);
+    }
+
+    #[test]
+    fn resuming_after_boundary_pause_does_not_rewind() {
+        let mut app = make_test_app();
+        let words = (0..20).map(|index| Word::new(format!("w{index}"))).collect();
+        let active_tab_entity = spawn_active_tab(&mut app, words, 10, 300);
+        let current_index = |app: &App| app.world().entity(active_tab_entity).get::<Content>().unwrap().current_index;
+        app.world_mut().resource_mut::<ResumeRewind>().0 = 0;
+        app.world_mut().trigger(PlaybackCommand::TogglePlayPause);
+        app.update();
+        app.world_mut().resource_mut::<ResumeRewind>().0 = 3;
+
+        app.world_mut().trigger(PlaybackCommand::PauseAtBoundary);
+        app.update();
+        assert_eq!(app.world().resource::<State<ReadingState>>().get(), &ReadingState::Paused);
+
+        app.insert_resource(bevy::time::TimeUpdateStrategy::ManualDuration(Duration::from_secs(5)));
+        app.update();
+        app.world_mut().trigger(PlaybackCommand::TogglePlayPause);
+        app.update();
+        assert_eq!(app.world().resource::<State<ReadingState>>().get(), &ReadingState::Playing);
+        assert_eq!(current_index(&app), 10);
+    }
+
+    #[test]
+    fn restart_returns_to_first_word_and_stops() {
+        let mut app = make_test_app();
+        let words = vec![Word::new("one"), Word::new("two"), Word::new("three")];
+        let active_tab_entity = spawn_active_tab(&mut app, words, 2, 300);
+        app.world_mut().trigger(PlaybackCommand::TogglePlayPause);
+        app.update();
+
+        app.world_mut().trigger(PlaybackCommand::Restart);
+        app.update();
+
+        let content = app.world().entity(active_tab_entity).get::<Content>().unwrap();
+        assert_eq!(content.current_index, 0);
+        assert_eq!(app.world().resource::<State<ReadingState>>().get(), &ReadingState::Idle);
+    }
+
+    #[test]
+    fn stepping_moves_one_word_only_while_not_playing() {
+        let mut app = make_test_app();
+        let words = vec![Word::new("one"), Word::new("two"), Word::new("three")];
+        let active_tab_entity = spawn_active_tab(&mut app, words, 1, 300);
+        let current_index = |app: &App| app.world().entity(active_tab_entity).get::<Content>().unwrap().current_index;
+
+        app.world_mut().trigger(PlaybackCommand::StepForward);
+        app.world_mut().trigger(PlaybackCommand::StepForward);
+        app.update();
+        assert_eq!(current_index(&app), 2);
+        assert_eq!(app.world().resource::<State<ReadingState>>().get(), &ReadingState::Idle);
+
+        app.world_mut().trigger(PlaybackCommand::StepBackward);
+        app.update();
+        assert_eq!(current_index(&app), 1);
+
+        app.world_mut().resource_mut::<ResumeRewind>().0 = 0;
+        app.world_mut().trigger(PlaybackCommand::TogglePlayPause);
+        app.update();
+        app.world_mut().trigger(PlaybackCommand::StepBackward);
+        app.update();
+        assert_eq!(current_index(&app), 1);
+    }
+
     #[test]
     fn adjust_wpm_clamps_to_limits() {
         let mut app = make_test_app();
@@ -132,4 +307,25 @@ mod tests {
         assert_eq!(tab_wpm.0, WPM_MIN);
     }
 
+    #[test]
+    fn set_wpm_applies_exact_value_and_clamps_to_limits() {
+        let mut app = make_test_app();
+        let active_tab_entity = spawn_active_tab(&mut app, vec![Word::new("hello")], 0, 300);
+
+        app.world_mut().trigger(PlaybackCommand::SetWpm(525));
+        let tab_wpm = app.world().entity(active_tab_entity).get::<TabWpm>()
+            .expect("Active tab should have TabWpm component");
+        assert_eq!(tab_wpm.0, 525);
+
+        app.world_mut().trigger(PlaybackCommand::SetWpm(0));
+        let tab_wpm = app.world().entity(active_tab_entity).get::<TabWpm>()
+            .expect("Active tab should have TabWpm component");
+        assert_eq!(tab_wpm.0, WPM_MIN);
+
+        app.world_mut().trigger(PlaybackCommand::SetWpm(99_999));
+        let tab_wpm = app.world().entity(active_tab_entity).get::<TabWpm>()
+            .expect("Active tab should have TabWpm component");
+        assert_eq!(tab_wpm.0, WPM_MAX);
+    }
+
 }
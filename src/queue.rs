@@ -0,0 +1,153 @@
+//! Reading queue: auto-advance between reader tabs.
+//!
+//! When queue mode is on and a tab's content finishes, the next unfinished reader tab
+//! in `TabOrder` is selected and playback resumes after a short countdown.
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::playback::PlaybackCommand;
+use crate::reader::{ContentFinished, ReadingState};
+use crate::tabs::{ActiveTab, Content, ReaderTab, TabOrder, TabSelect};
+
+pub struct QueuePlugin;
+impl Plugin for QueuePlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<ReadingQueue>()
+            .add_systems(Update, QueueCountdown::tick.run_if(resource_exists::<QueueCountdown>))
+            .add_observer(ReadingQueue::on_content_finished)
+            .add_observer(CancelQueueCountdown::on_trigger)
+            ;
+    }
+}
+
+const QUEUE_COUNTDOWN: Duration = Duration::from_secs(3);
+
+/// Queue mode toggle. Serialized to disk as part of `ProgramState`.
+#[derive(Resource, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ReadingQueue {
+    pub enabled: bool,
+}
+impl ReadingQueue {
+    /// 1-based position of `active` among reader tabs in `TabOrder`, and the reader tab count.
+    pub fn position(
+        tab_order: &TabOrder,
+        reader_tabs: &Query<(Entity, &Content), With<ReaderTab>>,
+        active: Entity,
+    ) -> Option<(usize, usize)> {
+        let readers: Vec<Entity> = tab_order.entities().iter()
+            .copied()
+            .filter(|&entity| reader_tabs.contains(entity))
+            .collect();
+        let index = readers.iter().position(|&entity| entity == active)?;
+        Some((index + 1, readers.len()))
+    }
+
+    /// First unfinished tab after `current`, wrapping around. `tabs` is in display order.
+    fn next_unfinished(tabs: &[(Entity, bool)], current: Entity) -> Option<Entity> {
+        let start = tabs.iter().position(|&(entity, _)| entity == current)? + 1;
+        tabs.iter().cycle()
+            .skip(start)
+            .take(tabs.len() - 1)
+            .find(|&&(_, is_finished)| !is_finished)
+            .map(|&(entity, _)| entity)
+    }
+
+    fn on_content_finished(
+        trigger: On<ContentFinished>,
+        mut commands: Commands,
+        queue: Res<ReadingQueue>,
+        tab_order: Res<TabOrder>,
+        reader_tabs: Query<&Content, With<ReaderTab>>,
+    ) {
+        if !queue.enabled { return; }
+
+        let tabs: Vec<(Entity, bool)> = tab_order.entities().iter()
+            .filter_map(|&entity| {
+                reader_tabs.get(entity).ok()
+                    .map(|content| (entity, !content.has_words() || content.is_at_end()))
+            })
+            .collect();
+        let Some(next) = Self::next_unfinished(&tabs, trigger.entity) else { return };
+
+        commands.trigger(TabSelect { entity: next });
+        commands.insert_resource(QueueCountdown {
+            target: next,
+            timer: Timer::new(QUEUE_COUNTDOWN, TimerMode::Once),
+        });
+    }
+}
+
+/// Pending auto-start of the next queued tab. Present only while counting down.
+#[derive(Resource)]
+pub struct QueueCountdown {
+    target: Entity,
+    timer: Timer,
+}
+impl QueueCountdown {
+    /// Whole seconds left, rounded up, for display.
+    pub fn remaining_secs(&self) -> u64 {
+        self.timer.remaining().as_secs_f32().ceil() as u64
+    }
+
+    /// Starts playback when the countdown ends. Gives up if the user switched
+    /// tabs or started playback themselves in the meantime.
+    fn tick(
+        mut commands: Commands,
+        time: Res<Time>,
+        mut countdown: ResMut<QueueCountdown>,
+        current_state: Res<State<ReadingState>>,
+        active_tab: Option<Single<Entity, With<ActiveTab>>>,
+    ) {
+        let is_target_active = active_tab.is_some_and(|active| *active == countdown.target);
+        if !is_target_active || *current_state.get() == ReadingState::Playing {
+            commands.remove_resource::<QueueCountdown>();
+            return;
+        }
+        countdown.timer.tick(time.delta());
+        if countdown.timer.is_finished() {
+            commands.remove_resource::<QueueCountdown>();
+            commands.trigger(PlaybackCommand::TogglePlayPause);
+        }
+    }
+}
+
+/// Cancels a running queue countdown, leaving the next tab selected but idle.
+#[derive(Event)]
+pub struct CancelQueueCountdown;
+impl CancelQueueCountdown {
+    fn on_trigger(
+        _trigger: On<CancelQueueCountdown>,
+        mut commands: Commands,
+    ) {
+        commands.remove_resource::<QueueCountdown>();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn next_unfinished_skips_finished_tabs_and_wraps() {
+        let mut world = World::new();
+        let [first, second, third, fourth] = std::array::from_fn(|_| world.spawn_empty().id());
+        let tabs = [(first, false), (second, true), (third, false), (fourth, true)];
+
+        assert_eq!(ReadingQueue::next_unfinished(&tabs, first), Some(third));
+        assert_eq!(ReadingQueue::next_unfinished(&tabs, third), Some(first));
+    }
+
+    #[test]
+    fn next_unfinished_returns_none_when_everything_is_finished() {
+        let mut world = World::new();
+        let [first, second] = std::array::from_fn(|_| world.spawn_empty().id());
+
+        assert_eq!(ReadingQueue::next_unfinished(&[(first, false), (second, true)], first), None);
+        assert_eq!(ReadingQueue::next_unfinished(&[(first, false)], first), None);
+    }
+}
@@ -2,23 +2,45 @@
 
 use std::time::Duration;
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 
-use crate::tabs::{ActiveTab, Content, TabWpm};
+use crate::playback::PlaybackCommand;
+use crate::stats::ReadingStats;
+use crate::tabs::{ActiveTab, Content, ReadTime, TabChunkSize, TabWpm};
 
 pub const WPM_DEFAULT: u32 = 300;
 pub const WPM_MIN: u32 = 100;
 pub const WPM_MAX: u32 = 1000;
 pub const WPM_STEP: u32 = 50;
+pub const CHUNK_SIZE_MAX: usize = 5;
 pub const FONT_SIZE_DEFAULT: f32 = 48.0;
 pub const FONT_SIZE_MIN: f32 = 16.0;
 pub const FONT_SIZE_MAX: f32 = 128.0;
+/// sRGB color of the ORP letter.
+pub const HIGHLIGHT_COLOR_DEFAULT: [f32; 3] = [1.0, 0.0, 0.0];
+pub const PAUSE_MULTIPLIER_MIN: f32 = 1.0;
+pub const PAUSE_MULTIPLIER_MAX: f32 = 6.0;
+pub const STOP_WORD_MULTIPLIER_MIN: f32 = 0.3;
+pub const STOP_WORD_MULTIPLIER_MAX: f32 = 1.0;
+pub const LONG_WORD_THRESHOLD_MIN: usize = 4;
+pub const LONG_WORD_THRESHOLD_MAX: usize = 20;
+pub const MIN_WORD_MS_MAX: u64 = 300;
+pub const WARM_UP_WORDS_MIN: usize = 1;
+pub const WARM_UP_WORDS_MAX: usize = 100;
+pub const WARM_UP_START_FRACTION_MIN: f32 = 0.2;
+pub const WARM_UP_START_FRACTION_MAX: f32 = 1.0;
 
 pub struct ReaderPlugin;
 impl Plugin for ReaderPlugin {
     fn build(&self, app: &mut App) {
         app.init_state::<ReadingState>()
             .init_resource::<ReadingTimer>()
+            .init_resource::<TimingConfig>()
+            .init_resource::<AutoPause>()
+            .init_resource::<TimeLeft>()
+            .init_resource::<ProgressMode>()
             .add_systems(Update, ReadingTimer::tick.run_if(in_state(ReadingState::Playing)))
+            .add_systems(Update, TimeLeft::update.after(ReadingTimer::tick))
             .add_systems(OnEnter(ReadingState::Playing), ReadingState::on_start_playing)
             .add_observer(ReadingTimer::reset_on_word_changed)
             ;
@@ -37,37 +59,232 @@ pub enum ReadingState {
 impl ReadingState {
     /// Fires `WordChanged` on play start so the timer is initialized
     /// for the current word (which may have been changed while paused/idle).
-    fn on_start_playing(mut commands: Commands) {
+    /// Also restarts the warm-up ramp.
+    fn on_start_playing(mut commands: Commands, mut timer: ResMut<ReadingTimer>) {
+        timer.words_since_play = 0;
         commands.trigger(WordChanged);
     }
 }
 
+/// User-tunable word timing. Multipliers scale the base per-word delay; when
+/// several apply, the largest wins. Serialized to disk as part of `ProgramState`.
+#[derive(Resource, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TimingConfig {
+    /// Words with more characters than this get `long_word_multiplier`.
+    pub long_word_threshold: usize,
+    pub long_word_multiplier: f32,
+    /// Pause after words ending in ',' or ';'.
+    pub comma_multiplier: f32,
+    /// Pause after the last word of a line when line breaks are preserved.
+    pub line_end_multiplier: f32,
+    pub sentence_end_multiplier: f32,
+    pub paragraph_end_multiplier: f32,
+    /// Slows down on long words missing from the bundled common English words.
+    pub rare_word_enabled: bool,
+    pub rare_word_multiplier: f32,
+    /// Shortens function words ("the", "of") that no other pause applies to.
+    pub stop_word_enabled: bool,
+    pub stop_word_multiplier: f32,
+    /// Shortest time any word stays on screen, after multipliers.
+    pub min_word_ms: u64,
+    /// Starts each Play at `warm_up_start_fraction` of the tab's WPM and ramps
+    /// linearly to full speed over `warm_up_words` words.
+    pub warm_up_enabled: bool,
+    pub warm_up_words: usize,
+    pub warm_up_start_fraction: f32,
+}
+impl Default for TimingConfig {
+    fn default() -> Self {
+        Self {
+            long_word_threshold: 10,
+            long_word_multiplier: 1.3,
+            comma_multiplier: 2.0,
+            line_end_multiplier: 2.5,
+            sentence_end_multiplier: 3.0,
+            paragraph_end_multiplier: 4.0,
+            rare_word_enabled: false,
+            rare_word_multiplier: 1.5,
+            stop_word_enabled: false,
+            stop_word_multiplier: 0.6,
+            min_word_ms: 80,
+            warm_up_enabled: false,
+            warm_up_words: 15,
+            warm_up_start_fraction: 0.6,
+        }
+    }
+}
+impl TimingConfig {
+    /// Factor applied to a word's delay when it is the `words_since_play`-th
+    /// word shown since Play. 1.0 once the warm-up is over or disabled.
+    pub fn warm_up_delay_scale(&self, words_since_play: usize) -> f64 {
+        if !self.warm_up_enabled { return 1.0; }
+        let ramp_words = self.warm_up_words.max(WARM_UP_WORDS_MIN);
+        let start = self.warm_up_start_fraction.clamp(WARM_UP_START_FRACTION_MIN, WARM_UP_START_FRACTION_MAX) as f64;
+        let progress = (words_since_play as f64 / ramp_words as f64).min(1.0);
+        1.0 / (start + (1.0 - start) * progress)
+    }
+}
+
+/// Boundaries at which playback pauses by itself, e.g. to reflect while studying.
+/// Serialized to disk as part of `ProgramState`.
+#[derive(Resource, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AutoPause {
+    /// Pause after the last word of a paragraph.
+    pub paragraph: bool,
+    /// Pause before the first word of a section (chapter, page, heading).
+    pub section: bool,
+}
+
 /// Per-word countdown. Reset by the `WordChanged` observer to the current
 /// word's display duration. When it expires, `tick` advances to the next word.
 #[derive(Resource, Default)]
 pub struct ReadingTimer {
     pub timer: Timer,
+    /// Words shown since the last transition to `Playing`; drives the warm-up ramp.
+    pub words_since_play: usize,
 }
 impl ReadingTimer {
+    #[allow(clippy::type_complexity)]
     fn tick(
         mut commands: Commands,
         time: Res<Time>,
         mut timer: ResMut<ReadingTimer>,
+        mut stats: ResMut<ReadingStats>,
+        auto_pause: Res<AutoPause>,
+        active_tab: Option<Single<(&Content, &TabChunkSize, &mut ReadTime), With<ActiveTab>>>,
     ) {
+        let Some(active_tab) = active_tab else { return };
+        let (content, chunk_size, mut read_time) = active_tab.into_inner();
         timer.timer.tick(time.delta());
-        if timer.timer.just_finished() {
-            commands.trigger(ContentNavigate::Advance);
+        stats.record_time(time.delta());
+        read_time.0 += time.delta();
+        if !timer.timer.just_finished() { return; }
+        commands.trigger(ContentNavigate::Advance);
+        let chunk = content.current_chunk(chunk_size.0);
+        stats.record_words(chunk.len());
+        let next_index = content.current_index + chunk.len();
+        // The last word finishes the content instead.
+        if next_index >= content.words.len() { return; }
+        let is_paragraph_boundary = auto_pause.paragraph && chunk.iter().any(|word| word.is_paragraph_end);
+        let is_section_boundary = auto_pause.section && content.is_section_start(next_index);
+        if is_paragraph_boundary || is_section_boundary {
+            commands.trigger(PlaybackCommand::PauseAtBoundary);
         }
     }
     fn reset_on_word_changed(
         _trigger: On<WordChanged>,
         mut timer: ResMut<ReadingTimer>,
-        active_tab: Single<(&TabWpm, &Content), With<ActiveTab>>,
+        timing: Res<TimingConfig>,
+        active_tab: Single<(&TabWpm, &Content, &TabChunkSize), With<ActiveTab>>,
     ) {
-        let (wpm, content) = active_tab.into_inner();
-        if let Some(word) = content.current_word() {
-            let delay = Duration::from_millis(word.display_duration_ms(wpm.0));
-            timer.timer = Timer::new(delay, TimerMode::Once);
+        let (wpm, content, chunk_size) = active_tab.into_inner();
+        let chunk = content.current_chunk(chunk_size.0);
+        if chunk.is_empty() { return; }
+        // A chunk stays up as long as its words would have one after another.
+        let chunk_ms: u64 = chunk.iter().map(|word| word.display_duration_ms(wpm.0, &timing)).sum();
+        let delay_ms = chunk_ms as f64 * timing.warm_up_delay_scale(timer.words_since_play);
+        timer.timer = Timer::new(Duration::from_millis(delay_ms.round() as u64), TimerMode::Once);
+        timer.words_since_play += chunk.len();
+    }
+}
+
+/// What the progress bar measures. Serialized to disk as part of `ProgramState`.
+#[derive(Resource, Clone, Copy, PartialEq, Eq, Default, Debug, Serialize, Deserialize)]
+pub enum ProgressMode {
+    /// Position among the words.
+    #[default]
+    Words,
+    /// Share of the total playback time already read, so long and punctuated
+    /// words move the bar further than short ones.
+    Time,
+}
+
+/// Estimated playback time of the active tab at its WPM, before and after the
+/// current word. Keeps the cumulative display time of every word, so moving the
+/// position is a lookup; a different tab, content, WPM or `TimingConfig` re-sums them.
+#[derive(Resource, Default)]
+pub struct TimeLeft {
+    source: Option<TimeLeftSource>,
+    index: usize,
+    /// `cumulative_ms[i]` is the display time of the words before index `i`.
+    cumulative_ms: Vec<u64>,
+}
+/// What `TimeLeft.cumulative_ms` was summed for.
+struct TimeLeftSource {
+    entity: Entity,
+    content_cache_id: String,
+    word_count: usize,
+    wpm: u32,
+}
+impl TimeLeftSource {
+    fn matches(&self, entity: Entity, content: &Content, wpm: u32) -> bool {
+        self.entity == entity
+            && self.content_cache_id == content.content_cache_id
+            && self.word_count == content.words.len()
+            && self.wpm == wpm
+    }
+}
+impl TimeLeft {
+    pub fn remaining(&self) -> Duration {
+        Duration::from_millis(self.total_ms().saturating_sub(self.elapsed_ms()))
+    }
+
+    /// Share of the total playback time before the current word, 0.0 to 1.0.
+    pub fn time_fraction(&self) -> f32 {
+        let total_ms = self.total_ms();
+        if total_ms == 0 { return 0.0; }
+        self.elapsed_ms() as f32 / total_ms as f32
+    }
+
+    /// Word at which `fraction` of the total playback time has elapsed.
+    pub fn index_at_time_fraction(&self, fraction: f32) -> usize {
+        let target_ms = (self.total_ms() as f64 * fraction.clamp(0.0, 1.0) as f64).round() as u64;
+        let word_count = self.cumulative_ms.len().saturating_sub(1);
+        // Last word starting at or before the target.
+        self.cumulative_ms.partition_point(|&ms| ms <= target_ms).saturating_sub(1).min(word_count.saturating_sub(1))
+    }
+
+    fn total_ms(&self) -> u64 {
+        self.cumulative_ms.last().copied().unwrap_or(0)
+    }
+
+    fn elapsed_ms(&self) -> u64 {
+        self.cumulative_ms.get(self.index).copied().unwrap_or_else(|| self.total_ms())
+    }
+
+    #[allow(clippy::type_complexity)]
+    fn update(
+        mut time_left: ResMut<TimeLeft>,
+        timing: Res<TimingConfig>,
+        active_tab: Option<Single<(Entity, &Content, &TabWpm), With<ActiveTab>>>,
+    ) {
+        let Some(active_tab) = active_tab else {
+            time_left.source = None;
+            return;
+        };
+        let (entity, content, wpm) = active_tab.into_inner();
+        let index = content.current_index.min(content.words.len());
+        let is_same_source = time_left.source.as_ref()
+            .is_some_and(|source| source.matches(entity, content, wpm.0));
+
+        if !is_same_source || timing.is_changed() {
+            let source = TimeLeftSource {
+                entity,
+                content_cache_id: content.content_cache_id.clone(),
+                word_count: content.words.len(),
+                wpm: wpm.0,
+            };
+            let cumulative_ms = std::iter::once(0)
+                .chain(content.words.iter().scan(0, |total_ms, word| {
+                    *total_ms += word.display_duration_ms(wpm.0, &timing);
+                    Some(*total_ms)
+                }))
+                .collect();
+            *time_left = TimeLeft { source: Some(source), index, cumulative_ms };
+        } else if index != time_left.index {
+            time_left.index = index;
         }
     }
 }
@@ -80,8 +297,95 @@ pub enum ContentNavigate {
     Seek(usize),
     SkipForward(usize),
     SkipBackward(usize),
+    NextSentence,
+    /// Start of the current sentence, or of the previous one when already there.
+    PreviousSentence,
+    NextParagraph,
+    /// Start of the current paragraph, or of the previous one when already there.
+    PreviousParagraph,
 }
 
 /// Fired after content navigation and tab switch to refresh timer and ORP display.
 #[derive(Event)]
 pub struct WordChanged;
+
+/// Fired when playback runs past the last word of a tab's content.
+#[derive(EntityEvent)]
+pub struct ContentFinished {
+    pub entity: Entity,
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::text::Word;
+
+    #[test]
+    fn warm_up_ramps_linearly_to_full_speed() {
+        let timing = TimingConfig { warm_up_enabled: true, warm_up_words: 4, warm_up_start_fraction: 0.5, ..default() };
+
+        assert_eq!(timing.warm_up_delay_scale(0), 2.0);
+        assert_eq!(timing.warm_up_delay_scale(2), 1.0 / 0.75);
+        assert_eq!(timing.warm_up_delay_scale(4), 1.0);
+        assert_eq!(timing.warm_up_delay_scale(40), 1.0);
+        assert_eq!(TimingConfig::default().warm_up_delay_scale(0), 1.0);
+    }
+
+    #[test]
+    fn time_left_follows_position_and_wpm() {
+        let mut app = App::new();
+        app
+            .init_resource::<TimingConfig>()
+            .init_resource::<TimeLeft>()
+            .add_systems(Update, TimeLeft::update)
+            ;
+        let words: Vec<Word> = ["One", "two,", "three.", "four"].into_iter().map(Word::new).collect();
+        let entity = app.world_mut().spawn((
+            ActiveTab,
+            TabWpm(300),
+            Content::new_from_loaded("test-cache".into(), words.try_into().unwrap(), Vec::new(), 0),
+        )).id();
+        let remaining = |app: &App| app.world().resource::<TimeLeft>().remaining().as_millis();
+
+        app.update();
+        assert_eq!(remaining(&app), 200 + 400 + 600 + 200);
+
+        app.world_mut().entity_mut(entity).get_mut::<Content>().unwrap().current_index = 2;
+        app.update();
+        assert_eq!(remaining(&app), 600 + 200);
+
+        app.world_mut().entity_mut(entity).get_mut::<Content>().unwrap().current_index = 1;
+        app.world_mut().entity_mut(entity).insert(TabWpm(600));
+        app.update();
+        assert_eq!(remaining(&app), 200 + 300 + 100);
+    }
+
+    #[test]
+    fn time_fraction_weights_words_by_display_time() {
+        let mut app = App::new();
+        app
+            .init_resource::<TimingConfig>()
+            .init_resource::<TimeLeft>()
+            .add_systems(Update, TimeLeft::update)
+            ;
+        // 200 + 400 + 600 + 200 ms at 300 WPM.
+        let words: Vec<Word> = ["One", "two,", "three.", "four"].into_iter().map(Word::new).collect();
+        let entity = app.world_mut().spawn((
+            ActiveTab,
+            TabWpm(300),
+            Content::new_from_loaded("test-cache".into(), words.try_into().unwrap(), Vec::new(), 2),
+        )).id();
+
+        app.update();
+        let time_left = app.world().resource::<TimeLeft>();
+        assert_eq!(time_left.time_fraction(), 600.0 / 1400.0);
+        assert_eq!(time_left.index_at_time_fraction(0.0), 0);
+        assert_eq!(time_left.index_at_time_fraction(0.5), 2);
+        assert_eq!(time_left.index_at_time_fraction(1.0), 3);
+
+        app.world_mut().entity_mut(entity).get_mut::<Content>().unwrap().current_index = 4;
+        app.update();
+        assert_eq!(app.world().resource::<TimeLeft>().time_fraction(), 1.0);
+    }
+}
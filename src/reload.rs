@@ -0,0 +1,443 @@
+//! Reloading and re-parsing tab content.
+//!
+//! Re-parses the tab's file (or, without one, its current words) asynchronously,
+//! then maps the reading position into the new word list by aligning the words
+//! before the cursor, so edits earlier in the file do not shift the reader to the
+//! wrong place.
+
+use std::collections::HashMap;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::ops::Range;
+use std::sync::Arc;
+
+use bevy::prelude::*;
+use bevy::tasks::{block_on, poll_once, AsyncComputeTaskPool, Task};
+
+use crate::bookmarks::Bookmarks;
+use crate::persistence::ProgramState;
+use crate::reader::WordChanged;
+use crate::tabs::{ActiveTab, Content, EphemeralTab, ReaderTab, TabBookMeta, TabFilePath, TabParseOptions, TabTextStats};
+use crate::text::{text_from_words, FileParsers, ParseOptions, ParseResult, TextParser, WordStore};
+use crate::ui::{Toast, ToastKind};
+
+pub struct ReloadPlugin;
+impl Plugin for ReloadPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<PendingReloads>()
+            .add_systems(Update, PendingReloads::poll)
+            .add_observer(TabReload::on_trigger)
+            .add_observer(TabReparse::on_trigger)
+            ;
+    }
+}
+
+/// Number of words before the cursor used as the alignment anchor.
+const ALIGNMENT_WINDOW: usize = 30;
+/// Shortest anchor still trusted for an approximate match.
+const ALIGNMENT_MIN_WINDOW: usize = 8;
+
+// ============================================================================
+// Alignment
+// ============================================================================
+
+/// Where the reading position landed after a reload.
+#[derive(Debug, PartialEq)]
+pub enum PositionMatch {
+    /// The full anchor and the current word were found unchanged.
+    Exact(usize),
+    /// Only part of the anchor matched, or the current word itself changed.
+    Approximate(usize),
+    /// No anchor matched; the old index was clamped to the new length.
+    Reset(usize),
+}
+impl PositionMatch {
+    pub fn index(&self) -> usize {
+        match self {
+            PositionMatch::Exact(index)
+            | PositionMatch::Approximate(index)
+            | PositionMatch::Reset(index) => *index,
+        }
+    }
+}
+
+/// Maps positions in the text before a reload to the text after it.
+///
+/// Takes up to `ALIGNMENT_WINDOW` words before a position and looks the same
+/// sequence up in `new`, shrinking the window down to `ALIGNMENT_MIN_WINDOW` words
+/// when the full one no longer matches. Among several matches, the one closest to
+/// the old position wins. Word hashes, and per window length the rolling hashes of
+/// every window in `new`, are built once and shared by all remapped positions.
+pub struct Alignment {
+    old_hashes: Vec<u64>,
+    new_hashes: Vec<u64>,
+    /// Window length → rolling hash → starts of the windows in `new_hashes` with it, ascending.
+    windows: HashMap<usize, HashMap<u64, Vec<usize>>>,
+}
+impl Alignment {
+    pub fn new(old: &WordStore, new: &WordStore) -> Self {
+        Self {
+            old_hashes: old.iter().map(|word| word_hash(word.text)).collect(),
+            new_hashes: new.iter().map(|word| word_hash(word.text)).collect(),
+            windows: HashMap::new(),
+        }
+    }
+
+    /// Maps `index` in the old text to the corresponding position in the new one.
+    pub fn remap(&mut self, index: usize) -> PositionMatch {
+        let new_len = self.new_hashes.len();
+        let clamped = index.min(new_len.saturating_sub(1));
+        if self.old_hashes.is_empty() || new_len == 0 {
+            return PositionMatch::Reset(clamped);
+        }
+        if index == 0 {
+            return PositionMatch::Exact(0);
+        }
+
+        let index = index.min(self.old_hashes.len() - 1);
+        let mut window = ALIGNMENT_WINDOW.min(index);
+        let min_window = ALIGNMENT_MIN_WINDOW.min(window);
+        loop {
+            if let Some(start) = self.find_nearest(index - window..index, index - window) {
+                let new_index = (start + window).min(new_len - 1);
+                let is_full_window = window == ALIGNMENT_WINDOW.min(index);
+                let is_same_word = self.new_hashes.get(start + window) == Some(&self.old_hashes[index]);
+                return if is_full_window && is_same_word {
+                    PositionMatch::Exact(new_index)
+                } else {
+                    PositionMatch::Approximate(new_index)
+                };
+            }
+            if window == min_window { break; }
+            window = (window / 2).max(min_window);
+        }
+        PositionMatch::Reset(clamped)
+    }
+
+    /// Start of the occurrence of the old words in `anchor` within the new text
+    /// closest to `preferred_start`. Candidates come from the window index and are
+    /// verified element-wise.
+    fn find_nearest(&mut self, anchor: Range<usize>, preferred_start: usize) -> Option<usize> {
+        let anchor = &self.old_hashes[anchor];
+        let new_hashes = &self.new_hashes;
+        let starts_by_hash = self.windows
+            .entry(anchor.len())
+            .or_insert_with(|| index_windows(new_hashes, anchor.len()));
+        starts_by_hash.get(&rolling_hash(anchor))?
+            .iter()
+            .copied()
+            .filter(|&start| new_hashes[start..start + anchor.len()] == *anchor)
+            .min_by_key(|start| start.abs_diff(preferred_start))
+    }
+}
+
+fn word_hash(text: &str) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    text.hash(&mut hasher);
+    hasher.finish()
+}
+
+const ROLLING_HASH_BASE: u64 = 1_000_003;
+
+fn combine(hash: u64, item: u64) -> u64 {
+    hash.wrapping_mul(ROLLING_HASH_BASE).wrapping_add(item)
+}
+
+fn rolling_hash(items: &[u64]) -> u64 {
+    items.iter().fold(0, |hash, &item| combine(hash, item))
+}
+
+/// Starts of every `window`-long run of `haystack`, grouped by polynomial rolling hash.
+fn index_windows(haystack: &[u64], window: usize) -> HashMap<u64, Vec<usize>> {
+    let mut starts_by_hash: HashMap<u64, Vec<usize>> = HashMap::new();
+    if window == 0 || haystack.len() < window { return starts_by_hash; }
+
+    let highest_power = (1..window).fold(1u64, |power, _| power.wrapping_mul(ROLLING_HASH_BASE));
+    let mut rolling = rolling_hash(&haystack[..window]);
+    for start in 0..=haystack.len() - window {
+        if start > 0 {
+            let outgoing = haystack[start - 1].wrapping_mul(highest_power);
+            rolling = combine(rolling.wrapping_sub(outgoing), haystack[start + window - 1]);
+        }
+        starts_by_hash.entry(rolling).or_default().push(start);
+    }
+    starts_by_hash
+}
+
+// ============================================================================
+// Events
+// ============================================================================
+
+/// Re-reads a file-backed tab from disk with its stored `TabParseOptions`
+/// and swaps in the new content.
+#[derive(EntityEvent)]
+pub struct TabReload {
+    pub entity: Entity,
+}
+impl TabReload {
+    fn on_trigger(
+        trigger: On<TabReload>,
+        mut commands: Commands,
+        mut pending_reloads: ResMut<PendingReloads>,
+        file_parsers: Res<FileParsers>,
+        reader_tabs: Query<(&TabFilePath, &TabParseOptions), With<ReaderTab>>,
+    ) {
+        let entity = trigger.entity;
+        let Ok((file_path, parse_options)) = reader_tabs.get(entity) else { return };
+        if pending_reloads.contains(entity) { return; }
+
+        match file_source(&file_parsers, &file_path.0) {
+            Ok(source) => pending_reloads.spawn(entity, source, parse_options.0.clone(), ReloadKind::Reload),
+            Err(e) => commands.trigger(Toast::new(ToastKind::Error, e)),
+        }
+    }
+}
+
+/// Re-parses a tab with new options and records them in `TabParseOptions`.
+/// Reads the file on native when the tab has one; otherwise rebuilds text from
+/// the current words, so options already applied to them cannot be undone.
+#[derive(EntityEvent)]
+pub struct TabReparse {
+    pub entity: Entity,
+    pub options: ParseOptions,
+}
+impl TabReparse {
+    /// Whether re-parsing `has_file_path` tabs reads the original file.
+    pub fn uses_file(has_file_path: bool) -> bool {
+        has_file_path && cfg!(not(target_arch = "wasm32"))
+    }
+
+    fn on_trigger(
+        trigger: On<TabReparse>,
+        mut commands: Commands,
+        mut pending_reloads: ResMut<PendingReloads>,
+        file_parsers: Res<FileParsers>,
+        reader_tabs: Query<(&Content, Option<&TabFilePath>), With<ReaderTab>>,
+    ) {
+        let entity = trigger.entity;
+        let Ok((content, file_path)) = reader_tabs.get(entity) else { return };
+        if pending_reloads.contains(entity) { return; }
+
+        let source = match file_path {
+            Some(file_path) if Self::uses_file(true) => file_source(&file_parsers, &file_path.0),
+            _ => file_parsers.get_arc_for_extension("txt")
+                .map(|parser| ParseSource::Text(text_from_words(&content.words), parser))
+                .ok_or_else(|| "No parser for plain text".to_string()),
+        };
+        match source {
+            Ok(source) => pending_reloads.spawn(entity, source, trigger.options.clone(), ReloadKind::Reparse),
+            Err(e) => commands.trigger(Toast::new(ToastKind::Error, e)),
+        }
+    }
+}
+
+/// Input for a background parse.
+enum ParseSource {
+    File(std::path::PathBuf, Arc<dyn TextParser>),
+    Text(String, Arc<dyn TextParser>),
+}
+
+fn file_source(file_parsers: &FileParsers, file_path: &str) -> Result<ParseSource, String> {
+    let path = std::path::PathBuf::from(file_path);
+    file_parsers.get_arc_for_path(&path)
+        .map(|parser| ParseSource::File(path, parser))
+        .ok_or_else(|| format!("No parser for '{}'", file_path))
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn read_file(path: &std::path::Path) -> Result<Vec<u8>, String> {
+    std::fs::read(path).map_err(|e| format!("Cannot read '{}': {}", path.display(), e))
+}
+
+#[cfg(target_arch = "wasm32")]
+fn read_file(_path: &std::path::Path) -> Result<Vec<u8>, String> {
+    Err("Reloading files is not available in the browser".to_string())
+}
+
+// ============================================================================
+// Resources
+// ============================================================================
+
+#[derive(Clone, Copy)]
+enum ReloadKind {
+    Reload,
+    Reparse,
+}
+impl ReloadKind {
+    fn label(self) -> &'static str {
+        match self {
+            ReloadKind::Reload => "Reloaded",
+            ReloadKind::Reparse => "Re-parsed",
+        }
+    }
+}
+
+struct PendingReload {
+    entity: Entity,
+    options: ParseOptions,
+    kind: ReloadKind,
+    task: Task<Result<ParseResult, String>>,
+}
+
+/// In-flight reload and re-parse tasks, at most one per tab.
+#[derive(Resource, Default)]
+pub struct PendingReloads(Vec<PendingReload>);
+impl PendingReloads {
+    fn contains(&self, entity: Entity) -> bool {
+        self.0.iter().any(|pending| pending.entity == entity)
+    }
+
+    fn spawn(&mut self, entity: Entity, source: ParseSource, options: ParseOptions, kind: ReloadKind) {
+        let task_options = options.clone();
+        let task = AsyncComputeTaskPool::get().spawn(async move {
+            match source {
+                ParseSource::File(path, parser) => parser.parse(&read_file(&path)?, &task_options),
+                ParseSource::Text(text, parser) => parser.parse(text.as_bytes(), &task_options),
+            }
+        });
+        self.0.push(PendingReload { entity, options, kind, task });
+    }
+
+    /// Swaps finished reloads into their tabs and reports how the position was kept.
+    #[allow(clippy::type_complexity)]
+    fn poll(
+        mut commands: Commands,
+        mut pending_reloads: ResMut<PendingReloads>,
+        reader_tabs: Query<(&Content, Option<&Bookmarks>, Has<ActiveTab>, Has<EphemeralTab>), With<ReaderTab>>,
+    ) {
+        let mut finished = Vec::new();
+        pending_reloads.0.retain_mut(|pending| match block_on(poll_once(&mut pending.task)) {
+            Some(result) => {
+                finished.push((pending.entity, pending.options.clone(), pending.kind, result));
+                false
+            }
+            None => true,
+        });
+
+        for (entity, options, kind, result) in finished {
+            let Ok((old_content, bookmarks, is_active, is_ephemeral)) = reader_tabs.get(entity) else { continue };
+            let parsed = match result {
+                Ok(parsed) if !parsed.words.is_empty() => parsed,
+                Ok(_) => {
+                    commands.trigger(Toast::new(ToastKind::Error, format!("{} content has no words", kind.label())));
+                    continue;
+                }
+                Err(e) => {
+                    commands.trigger(Toast::new(ToastKind::Error, e));
+                    continue;
+                }
+            };
+
+            let mut alignment = Alignment::new(&old_content.words, &parsed.words);
+            let position = alignment.remap(old_content.current_index);
+            let stats = TabTextStats(parsed.stats);
+            if !parsed.meta.is_empty() {
+                commands.entity(entity).insert(TabBookMeta(parsed.meta.clone()));
+            }
+            if let Some(bookmarks) = bookmarks {
+                let mut bookmarks = bookmarks.clone();
+                bookmarks.remap(|index| alignment.remap(index).index());
+                commands.entity(entity).insert(bookmarks);
+            }
+            let mut content = if is_ephemeral {
+                Content::new_ephemeral(parsed.words, parsed.sections)
+            } else {
+                // The old cache goes only once the new one is on disk; a failed write
+                // leaves it for the orphan sweep instead of losing both.
+                let content = Content::new(parsed.words, parsed.sections);
+                if ProgramState::has_word_cache(&content.content_cache_id) {
+                    ProgramState::delete_word_cache(&old_content.content_cache_id);
+                }
+                content
+            };
+            content.seek(position.index());
+            commands.entity(entity).insert((content, stats, TabParseOptions(options)));
+            if is_active {
+                commands.trigger(WordChanged);
+            }
+
+            let toast = match position {
+                PositionMatch::Exact(_) => Toast::new(ToastKind::Info, format!("{}: position matched exactly", kind.label())),
+                PositionMatch::Approximate(_) => Toast::new(ToastKind::Info, format!("{}: position matched approximately", kind.label())),
+                PositionMatch::Reset(_) => Toast::new(ToastKind::Warning, format!("{}: previous position not found", kind.label())),
+            };
+            commands.trigger(toast);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::text::Word;
+
+    fn words(count: usize) -> Vec<Word> {
+        (0..count).map(|index| Word::new(format!("w{}", index))).collect()
+    }
+
+    #[test]
+    fn alignment_follows_text_inserted_before_cursor() {
+        let old = words(200);
+        let mut new = words(200);
+        new.splice(10..10, (0..25).map(|index| Word::new(format!("inserted{}", index))));
+
+        assert_eq!(Alignment::new(&old.try_into().unwrap(), &new.try_into().unwrap()).remap(120), PositionMatch::Exact(145));
+    }
+
+    #[test]
+    fn alignment_is_approximate_when_anchor_was_edited() {
+        let old = words(200);
+        let mut new = words(200);
+        // Edit inside the full 30-word anchor; the closest 15 words still match.
+        new[95] = Word::new("edited");
+        new.splice(0..5, []);
+
+        assert_eq!(Alignment::new(&old.try_into().unwrap(), &new.try_into().unwrap()).remap(120), PositionMatch::Approximate(115));
+    }
+
+    #[test]
+    fn alignment_resets_when_nothing_matches() {
+        let old = words(200);
+        let new: Vec<Word> = (0..50).map(|index| Word::new(format!("other{}", index))).collect();
+
+        assert_eq!(Alignment::new(&old.try_into().unwrap(), &new.try_into().unwrap()).remap(120), PositionMatch::Reset(49));
+    }
+
+    #[test]
+    fn alignment_remaps_several_positions_against_one_index() {
+        let old = words(200);
+        let mut new = words(200);
+        new.splice(10..10, (0..25).map(|index| Word::new(format!("inserted{}", index))));
+        let mut alignment = Alignment::new(&old.try_into().unwrap(), &new.try_into().unwrap());
+
+        assert_eq!(alignment.remap(120), PositionMatch::Exact(145));
+        assert_eq!(alignment.remap(60), PositionMatch::Exact(85));
+        assert_eq!(alignment.remap(5), PositionMatch::Exact(5));
+        assert_eq!(alignment.windows.len(), 2);
+    }
+
+    #[test]
+    fn alignment_falls_back_to_the_shortest_window() {
+        let old = words(200);
+        let mut new = words(200);
+        // Breaks every anchor longer than 8 words.
+        new[111] = Word::new("edited");
+        // A closer copy of only the last 7 anchor words must not win.
+        for offset in 0..7 {
+            new[60 + offset] = Word::new(format!("w{}", 113 + offset));
+        }
+        new.splice(0..0, (0..40).map(|index| Word::new(format!("inserted{}", index))));
+
+        assert_eq!(Alignment::new(&old.try_into().unwrap(), &new.try_into().unwrap()).remap(120), PositionMatch::Approximate(160));
+    }
+
+    #[test]
+    fn alignment_prefers_match_closest_to_old_index() {
+        let repeated: Vec<Word> = words(40);
+        let old: Vec<Word> = repeated.iter().chain(repeated.iter()).cloned().collect();
+        let new = old.clone();
+
+        assert_eq!(Alignment::new(&old.try_into().unwrap(), &new.try_into().unwrap()).remap(75), PositionMatch::Exact(75));
+    }
+}
@@ -0,0 +1,325 @@
+//! Lifetime reading statistics.
+//!
+//! The reader tick reports shown words and playing time; a session spans one
+//! Playing state, and its speed feeds a rolling average WPM and the per-session
+//! history behind the stats tile's sparkline. `ReadingSession`
+//! spans a whole run from Play until playback stops, pauses included, and backs
+//! the summary shown when playback reaches the end of a document. `DailyGoal`
+//! tallies today's share of the totals against a words or minutes target.
+
+use std::time::Duration;
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::reader::{ContentNavigate, ReadingState};
+use crate::ui::{Toast, ToastKind};
+
+pub struct StatsPlugin;
+impl Plugin for StatsPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<ReadingStats>()
+            .init_resource::<ReadingSession>()
+            .init_resource::<DailyGoal>()
+            .add_systems(Update, DailyGoal::track.run_if(resource_changed::<ReadingStats>))
+            .add_systems(OnExit(ReadingState::Playing), ReadingStats::end_session)
+            .add_systems(OnExit(ReadingState::Idle), ReadingSession::begin)
+            .add_observer(ReadingSession::on_navigate)
+            ;
+    }
+}
+
+/// Weight of the latest session in the rolling average WPM.
+const AVERAGE_WPM_WEIGHT: f64 = 0.2;
+/// Sessions shorter than this are counted but too noisy for the average WPM.
+const AVERAGE_WPM_MIN_SESSION_SECS: f64 = 10.0;
+/// Sessions kept in `ReadingStats::history`, oldest dropped first.
+const SESSION_HISTORY_MAX: usize = 100;
+
+/// Totals across all tabs. Serialized to disk as part of `ProgramState`.
+#[derive(Resource, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ReadingStats {
+    pub words_read: u64,
+    /// Time spent playing, in seconds.
+    pub reading_secs: f64,
+    /// Play stretches that showed at least one word.
+    pub sessions: u32,
+    /// Exponential moving average of the measured WPM of each session.
+    pub average_wpm: f64,
+    /// Sessions long enough for the average WPM, oldest first.
+    pub history: Vec<SessionRecord>,
+    #[serde(skip)]
+    session_words: u64,
+    #[serde(skip)]
+    session_secs: f64,
+}
+impl ReadingStats {
+    pub fn record_time(&mut self, delta: Duration) {
+        self.reading_secs += delta.as_secs_f64();
+        self.session_secs += delta.as_secs_f64();
+    }
+
+    pub fn record_words(&mut self, count: usize) {
+        self.words_read += count as u64;
+        self.session_words += count as u64;
+    }
+
+    /// Counts the session that just ended on `date` and, if it was long enough,
+    /// folds its speed into the average and the history.
+    fn finish_session(&mut self, date: &str) {
+        let words = std::mem::take(&mut self.session_words);
+        let secs = std::mem::take(&mut self.session_secs);
+        if words == 0 { return; }
+        self.sessions += 1;
+        if secs < AVERAGE_WPM_MIN_SESSION_SECS { return; }
+        let session_wpm = words as f64 * 60.0 / secs;
+        self.history.push(SessionRecord { date: date.to_string(), wpm: session_wpm, words });
+        if self.history.len() > SESSION_HISTORY_MAX {
+            self.history.remove(0);
+        }
+        self.average_wpm = if self.average_wpm > 0.0 {
+            self.average_wpm + (session_wpm - self.average_wpm) * AVERAGE_WPM_WEIGHT
+        } else {
+            session_wpm
+        };
+    }
+
+    fn end_session(mut stats: ResMut<ReadingStats>) {
+        stats.finish_session(&local_date());
+    }
+}
+
+/// One reading session in `ReadingStats::history`.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct SessionRecord {
+    /// Local date the session ended, as `YYYY-MM-DD`.
+    pub date: String,
+    /// Measured speed over the session.
+    pub wpm: f64,
+    pub words: u64,
+}
+
+/// What a `DailyGoal` counts.
+#[derive(Clone, Copy, PartialEq, Eq, Default, Debug, Serialize, Deserialize)]
+pub enum GoalUnit {
+    #[default]
+    Words,
+    Minutes,
+}
+impl GoalUnit {
+    pub const ALL: [GoalUnit; 2] = [GoalUnit::Words, GoalUnit::Minutes];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            GoalUnit::Words => "words",
+            GoalUnit::Minutes => "minutes",
+        }
+    }
+}
+
+/// Daily reading target and today's tally, which starts over at local midnight.
+/// Serialized to disk as part of `ProgramState`.
+#[derive(Resource, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DailyGoal {
+    pub enabled: bool,
+    pub unit: GoalUnit,
+    pub target_words: u64,
+    pub target_minutes: u64,
+    /// Local date the tally belongs to, as `YYYY-MM-DD`.
+    date: String,
+    words: u64,
+    reading_secs: f64,
+    /// `ReadingStats` totals at the last update; the tally grows by the difference.
+    #[serde(skip)]
+    seen: Option<(u64, f64)>,
+}
+impl Default for DailyGoal {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            unit: GoalUnit::Words,
+            target_words: 2000,
+            target_minutes: 20,
+            date: String::new(),
+            words: 0,
+            reading_secs: 0.0,
+            seen: None,
+        }
+    }
+}
+impl DailyGoal {
+    pub fn target(&self) -> u64 {
+        match self.unit {
+            GoalUnit::Words => self.target_words,
+            GoalUnit::Minutes => self.target_minutes,
+        }
+    }
+
+    pub fn target_mut(&mut self) -> &mut u64 {
+        match self.unit {
+            GoalUnit::Words => &mut self.target_words,
+            GoalUnit::Minutes => &mut self.target_minutes,
+        }
+    }
+
+    /// Words or whole minutes read on `today`.
+    pub fn done(&self, today: &str) -> u64 {
+        if self.date != today { return 0; }
+        match self.unit {
+            GoalUnit::Words => self.words,
+            GoalUnit::Minutes => (self.reading_secs / 60.0) as u64,
+        }
+    }
+
+    pub fn is_met(&self, today: &str) -> bool {
+        self.target() > 0 && self.done(today) >= self.target()
+    }
+
+    /// Adds what `ReadingStats` gained since the last call to the tally for `today`.
+    /// The first call only takes the starting totals.
+    fn record(&mut self, today: &str, words_read: u64, reading_secs: f64) {
+        let Some((seen_words, seen_secs)) = self.seen.replace((words_read, reading_secs)) else { return };
+        if self.date != today {
+            self.date = today.to_string();
+            self.words = 0;
+            self.reading_secs = 0.0;
+        }
+        // Totals only drop when all data is cleared.
+        self.words += words_read.saturating_sub(seen_words);
+        self.reading_secs += (reading_secs - seen_secs).max(0.0);
+    }
+
+    fn track(mut commands: Commands, mut goal: ResMut<DailyGoal>, stats: Res<ReadingStats>) {
+        let today = local_date();
+        let was_met = goal.is_met(&today);
+        goal.record(&today, stats.words_read, stats.reading_secs);
+        if goal.enabled && !was_met && goal.is_met(&today) {
+            commands.trigger(Toast::new(ToastKind::Info, "🎉 Daily goal reached"));
+        }
+    }
+}
+
+/// Today's date in the local time zone, as `YYYY-MM-DD`.
+pub fn local_date() -> String {
+    chrono::Local::now().date_naive().to_string()
+}
+
+/// The run of playback since Play was pressed from a stop.
+#[derive(Resource, Default)]
+pub struct ReadingSession {
+    /// Time when Play was pressed.
+    started_at: Duration,
+    /// `ReadingStats::words_read` when Play was pressed.
+    words_at_start: u64,
+    /// Playback advances since Play or the last manual navigation.
+    advances: u32,
+}
+impl ReadingSession {
+    /// Words read and time elapsed since Play was pressed. Zero words when the
+    /// stats were reset mid-session.
+    pub fn totals(&self, stats: &ReadingStats, now: Duration) -> (u64, Duration) {
+        (stats.words_read.saturating_sub(self.words_at_start), now.saturating_sub(self.started_at))
+    }
+
+    /// True once playback moved through the text on its own. The final advance
+    /// that runs past the end counts too, so a jump to the last word (by seek,
+    /// skip, sentence or paragraph) followed by Play does not qualify.
+    pub fn has_played_through(&self) -> bool {
+        self.advances > 1
+    }
+
+    fn begin(mut session: ResMut<ReadingSession>, time: Res<Time>, stats: Res<ReadingStats>) {
+        *session = ReadingSession { started_at: time.elapsed(), words_at_start: stats.words_read, advances: 0 };
+    }
+
+    fn on_navigate(trigger: On<ContentNavigate>, mut session: ResMut<ReadingSession>) {
+        match trigger.event() {
+            ContentNavigate::Advance => session.advances += 1,
+            _ => session.advances = 0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sessions_feed_a_rolling_average_wpm() {
+        let mut stats = ReadingStats::default();
+
+        stats.finish_session("2026-03-01");
+        assert_eq!(stats.sessions, 0);
+
+        stats.record_words(300);
+        stats.record_time(Duration::from_secs(60));
+        stats.finish_session("2026-03-01");
+        assert_eq!(stats.average_wpm, 300.0);
+
+        stats.record_words(50);
+        stats.record_time(Duration::from_secs(5));
+        stats.finish_session("2026-03-01");
+        assert_eq!(stats.average_wpm, 300.0);
+
+        stats.record_words(400);
+        stats.record_time(Duration::from_secs(60));
+        stats.finish_session("2026-03-01");
+        assert_eq!(stats.average_wpm, 320.0);
+        assert_eq!((stats.sessions, stats.words_read), (3, 750));
+        let history: Vec<_> = stats.history.iter().map(|record| (record.wpm, record.words)).collect();
+        assert_eq!(history, vec![(300.0, 300), (400.0, 400)]);
+    }
+
+    #[test]
+    fn daily_goal_tallies_today_and_starts_over_each_day() {
+        let mut goal = DailyGoal { target_words: 500, ..default() };
+
+        goal.record("2026-03-01", 10_000, 3600.0);
+        assert_eq!(goal.done("2026-03-01"), 0);
+
+        goal.record("2026-03-01", 10_300, 3660.0);
+        goal.record("2026-03-01", 10_520, 3720.0);
+        assert_eq!(goal.done("2026-03-01"), 520);
+        assert!(goal.is_met("2026-03-01"));
+        assert_eq!(goal.done("2026-03-02"), 0);
+
+        goal.unit = GoalUnit::Minutes;
+        assert_eq!(goal.done("2026-03-01"), 2);
+
+        goal.unit = GoalUnit::Words;
+        goal.record("2026-03-02", 10_600, 3740.0);
+        assert_eq!(goal.done("2026-03-02"), 80);
+        assert!(!goal.is_met("2026-03-02"));
+    }
+
+    #[test]
+    fn session_totals_survive_stats_reset_mid_session() {
+        let session = ReadingSession { started_at: Duration::from_secs(10), words_at_start: 500, advances: 0 };
+        let stats = ReadingStats { words_read: 20, ..default() };
+
+        assert_eq!(session.totals(&stats, Duration::from_secs(70)), (0, Duration::from_secs(60)));
+    }
+
+    #[test]
+    fn manual_navigation_restarts_the_played_through_count() {
+        let mut app = App::new();
+        app
+            .init_resource::<ReadingSession>()
+            .add_observer(ReadingSession::on_navigate)
+            ;
+
+        app.world_mut().trigger(ContentNavigate::Advance);
+        app.world_mut().trigger(ContentNavigate::Advance);
+        assert!(app.world().resource::<ReadingSession>().has_played_through());
+
+        for navigate in [ContentNavigate::Seek(41), ContentNavigate::SkipForward(10), ContentNavigate::NextParagraph] {
+            app.world_mut().trigger(ContentNavigate::Advance);
+            app.world_mut().trigger(ContentNavigate::Advance);
+            app.world_mut().trigger(navigate);
+            app.world_mut().trigger(ContentNavigate::Advance);
+            assert!(!app.world().resource::<ReadingSession>().has_played_through());
+        }
+    }
+}
@@ -2,32 +2,56 @@
 //!
 //! Provides tab components, bundles, entity events, and observers for reactive tab management.
 
+use std::collections::VecDeque;
+use std::ops::Range;
+use std::time::Duration;
 use bevy::prelude::*;
 use serde::{Deserialize, Serialize};
 
+use crate::bookmarks::{Bookmark, Bookmarks};
 use crate::fonts::{FontData, FontsStore};
-use crate::persistence::ProgramState;
-use crate::reader::{ContentNavigate, FONT_SIZE_DEFAULT, ReadingState, WordChanged, WPM_DEFAULT};
-use crate::text::Word;
+use crate::persistence::{ProgramState, SavedTab, SavedTabQuery, WordCache};
+use crate::reader::{ContentFinished, ContentNavigate, CHUNK_SIZE_MAX, FONT_SIZE_DEFAULT, HIGHLIGHT_COLOR_DEFAULT, ReadingState, WordChanged, WPM_DEFAULT};
+use crate::text::{BookMeta, ParseOptions, Section, TextStats, WordRef, WordStore};
+use crate::ui::{Toast, ToastKind};
 
 pub struct TabsPlugin;
 impl Plugin for TabsPlugin {
     fn build(&self, app: &mut App) {
         app
             .init_resource::<TabOrder>()
+            .init_resource::<TabHistory>()
+            .init_resource::<ClosedTabHistory>()
             .init_resource::<DefaultTabSettings>()
+            .init_resource::<WpmMemory>()
+            .init_resource::<TabCloseConfirm>()
+            .init_resource::<TabTitleWidth>()
             .add_systems(Startup, HomepageTab::spawn)
+            .add_systems(Update, WpmMemory::record)
             .add_observer(TabSelect::on_trigger)
             .add_observer(TabClose::on_trigger)
+            .add_observer(TabRename::on_trigger)
+            .add_observer(TabCloseOthers::on_trigger)
+            .add_observer(TabDuplicate::on_trigger)
+            .add_observer(ReopenClosedTab::on_trigger)
+            .add_observer(TabSwitch::on_trigger)
             .add_observer(TabCreateRequest::on_trigger)
             .add_observer(ApplyDefaultsToAll::on_trigger)
             .add_observer(TabOrder::on_tab_added)
             .add_observer(TabOrder::on_tab_removed)
+            .add_observer(TabHistory::on_tab_removed)
             .add_observer(Content::on_navigate)
             ;
     }
 }
 
+/// Closed tabs kept for `ReopenClosedTab`, oldest dropped first.
+const CLOSED_TAB_HISTORY_MAX: usize = 10;
+/// Files whose WPM `WpmMemory` keeps, least recently used dropped first.
+const WPM_MEMORY_MAX: usize = 200;
+pub const TAB_TITLE_WIDTH_MIN: usize = 12;
+pub const TAB_TITLE_WIDTH_MAX: usize = 60;
+
 // ============================================================================
 // Resources
 // ============================================================================
@@ -69,6 +93,11 @@ impl TabOrder {
             .filter(|&&e| e != target)
             .copied()
     }
+    /// Moves `entity` to `index`, or to the end when `index` is past it.
+    fn move_to(&mut self, entity: Entity, index: usize) {
+        self.0.retain(|&e| e != entity);
+        self.0.insert(index.min(self.0.len()), entity);
+    }
     fn on_tab_added(trigger: On<Add, TabMarker>, mut order: ResMut<TabOrder>) {
         order.0.push(trigger.event_target());
     }
@@ -77,6 +106,114 @@ impl TabOrder {
     }
 }
 
+/// Most-recently-used stack of tab entities, most recent last.
+/// Pushed by `TabSelect`, pruned via a Remove observer on `TabMarker`.
+#[derive(Resource, Default)]
+pub struct TabHistory(Vec<Entity>);
+impl TabHistory {
+    fn touch(&mut self, entity: Entity) {
+        self.0.retain(|&e| e != entity);
+        self.0.push(entity);
+    }
+    /// Returns the most recently used tab other than `target`.
+    pub fn previous(&self, target: Entity) -> Option<Entity> {
+        self.0.iter().rev().find(|&&e| e != target).copied()
+    }
+    fn on_tab_removed(trigger: On<Remove, TabMarker>, mut history: ResMut<TabHistory>) {
+        history.0.retain(|&e| e != trigger.event_target());
+    }
+}
+
+/// Recently closed reader tabs, most recent last. Their word caches stay on disk
+/// until they drop out of the history; ones left at exit are removed as orphans
+/// on the next start.
+#[derive(Resource, Default)]
+pub struct ClosedTabHistory(VecDeque<ClosedTab>);
+impl ClosedTabHistory {
+    /// Records a tab closed at `order_index` in `TabOrder`. Returns the cache id of
+    /// the tab dropped to make room, if any.
+    pub fn push(&mut self, order_index: usize, tab: SavedTab) -> Option<String> {
+        self.0.push_back(ClosedTab { order_index, tab });
+        if self.0.len() <= CLOSED_TAB_HISTORY_MAX { return None; }
+        self.0.pop_front().map(|dropped| dropped.tab.content_cache_id().to_string())
+    }
+    pub fn cache_ids(&self) -> impl Iterator<Item = &str> {
+        self.0.iter().map(|closed| closed.tab.content_cache_id())
+    }
+    pub fn clear(&mut self) {
+        self.0.clear();
+    }
+}
+
+/// A closed tab and where it sat in `TabOrder`.
+pub struct ClosedTab {
+    order_index: usize,
+    tab: SavedTab,
+}
+impl ClosedTab {
+    /// Rebuilds the tab, active and back at its old place in `TabOrder`.
+    fn into_request(self, cache: WordCache) -> TabCreateRequest {
+        self.tab.into_request(cache.words, cache.sections)
+            .with_active(true)
+            .with_order_index(self.order_index)
+    }
+}
+
+/// Whether closing a reader tab past its first word asks first.
+/// Serialized to disk as part of `ProgramState`.
+#[derive(Resource, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TabCloseConfirm {
+    pub enabled: bool,
+}
+impl Default for TabCloseConfirm {
+    fn default() -> Self {
+        Self { enabled: true }
+    }
+}
+
+/// Longest tab name, in characters, shown in the tab bar and wherever tabs are
+/// listed before its middle is ellipsized. Serialized to disk as part of `ProgramState`.
+#[derive(Resource, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TabTitleWidth {
+    pub max_chars: usize,
+}
+impl Default for TabTitleWidth {
+    fn default() -> Self {
+        Self { max_chars: 28 }
+    }
+}
+
+/// Last WPM read at per file path or URL, so a file or page opened again starts
+/// at that speed. Most recently used last. Serialized to disk as part of `ProgramState`.
+#[derive(Resource, Clone, Default, Serialize, Deserialize)]
+pub struct WpmMemory(Vec<(String, u32)>);
+impl WpmMemory {
+    pub fn get(&self, file_path: &str) -> Option<u32> {
+        self.0.iter().find(|(path, _)| path == file_path).map(|&(_, wpm)| wpm)
+    }
+    pub fn remember(&mut self, file_path: &str, wpm: u32) {
+        self.0.retain(|(path, _)| path != file_path);
+        self.0.push((file_path.to_string(), wpm));
+        if self.0.len() > WPM_MEMORY_MAX {
+            self.0.remove(0);
+        }
+    }
+    #[allow(clippy::type_complexity)]
+    fn record(
+        mut memory: ResMut<WpmMemory>,
+        changed_tabs: Query<(Option<&TabFilePath>, Option<&TabSourceUrl>, &TabWpm), Changed<TabWpm>>,
+    ) {
+        for (file_path, source_url, wpm) in changed_tabs.iter() {
+            let Some(key) = file_path.map(|fp| &fp.0).or(source_url.map(|url| &url.0)) else { continue };
+            if memory.get(key) != Some(wpm.0) {
+                memory.remember(key, wpm.0);
+            }
+        }
+    }
+}
+
 // ============================================================================
 // Components
 // ============================================================================
@@ -107,50 +244,99 @@ impl HomepageTab {
 #[derive(Component)]
 pub struct ReaderTab;
 
+/// Reader tab that lives only for the current session: its words are never
+/// cached and it is skipped when persisting `ProgramState`.
+#[derive(Component)]
+pub struct EphemeralTab;
+
 /// Per-tab font configuration. Inserting this component on the active tab
 /// triggers the ORP font update observer in `orp.rs`.
-#[derive(Component)]
+#[derive(Component, Clone)]
 pub struct TabFontSettings {
     pub font: FontData,
     pub font_size: f32,
+    /// sRGB color of the ORP letter.
+    pub highlight_color: [f32; 3],
 }
 impl TabFontSettings {
     pub fn from_font(font: &FontData, size: f32) -> Self {
         Self {
             font: font.clone(),
             font_size: size,
+            highlight_color: HIGHLIGHT_COLOR_DEFAULT,
         }
     }
+    pub fn with_highlight_color(mut self, color: [f32; 3]) -> Self {
+        self.highlight_color = color;
+        self
+    }
 }
 
 #[derive(Component)]
 pub struct TabWpm(pub u32);
 
+/// Words shown per flash; 1 is classic single-word reading.
+#[derive(Component)]
+pub struct TabChunkSize(pub usize);
+
 #[derive(Component)]
 pub struct TabFilePath(pub String);
 
+/// Address a tab's page was downloaded from.
+#[derive(Component)]
+pub struct TabSourceUrl(pub String);
+
+/// Options the tab's content was parsed with; reused by reload and re-parse.
+#[derive(Component, Clone, Default)]
+pub struct TabParseOptions(pub ParseOptions);
+
+/// Length of the tab's text, known without loading its word cache.
+#[derive(Component, Clone, Copy)]
+pub struct TabTextStats(pub TextStats);
+
+/// Title and author from the document's metadata. Only on tabs whose source had any.
+#[derive(Component, Clone)]
+pub struct TabBookMeta(pub BookMeta);
+
+/// Time spent playing this tab. Paused and idle time is not counted.
+#[derive(Component, Clone, Copy, Default)]
+pub struct ReadTime(pub Duration);
+
 #[derive(Component, Clone)]
 pub struct Content {
     pub content_cache_id: String,
-    pub words: Vec<Word>,
+    pub words: WordStore,
+    /// Chapters or pages, ordered by `start_index`. Empty for unstructured text.
+    pub sections: Vec<Section>,
     pub current_index: usize,
+    /// Set when no word cache backs the words. A tab created from such content
+    /// is an `EphemeralTab`, so it is never saved.
+    pub is_ephemeral: bool,
 }
 impl Content {
     /// Creates new content and writes the word cache to disk immediately.
-    pub fn new(words: Vec<Word>) -> Self {
+    pub fn new(words: WordStore, sections: Vec<Section>) -> Self {
         let content_cache_id = ProgramState::generate_cache_id();
-        ProgramState::write_word_cache(&content_cache_id, &words);
-        Self { content_cache_id, words, current_index: 0 }
+        ProgramState::write_word_cache(&content_cache_id, &words, &sections);
+        Self { content_cache_id, words, sections, current_index: 0, is_ephemeral: false }
+    }
+    /// Creates new content without writing a word cache; its tab is an `EphemeralTab`.
+    pub fn new_ephemeral(words: WordStore, sections: Vec<Section>) -> Self {
+        Self { content_cache_id: ProgramState::generate_cache_id(), words, sections, current_index: 0, is_ephemeral: true }
     }
     /// Restores content from an existing cache (skips cache write).
-    pub fn new_from_loaded(content_cache_id: String, words: Vec<Word>, current_index: usize) -> Self {
-        Self { content_cache_id, words, current_index }
+    pub fn new_from_loaded(content_cache_id: String, words: WordStore, sections: Vec<Section>, current_index: usize) -> Self {
+        Self { content_cache_id, words, sections, current_index, is_ephemeral: false }
     }
     pub fn has_words(&self) -> bool {
         !self.words.is_empty()
     }
-    pub fn current_word(&self) -> Option<&Word> {
-        self.words.get(self.current_index)
+    /// Index in `sections` of the section containing the current word.
+    pub fn current_section(&self) -> Option<usize> {
+        self.sections.partition_point(|section| section.start_index <= self.current_index).checked_sub(1)
+    }
+    pub fn is_section_start(&self, index: usize) -> bool {
+        self.sections.binary_search_by_key(&index, |section| section.start_index).is_ok()
     }
     pub fn progress(&self) -> (usize, usize) {
         (self.current_index, self.words.len())
@@ -161,32 +347,120 @@ impl Content {
     pub fn skip_backward(&mut self, amount: usize) {
         self.seek(self.current_index.saturating_sub(amount));
     }
+    /// Moves to the word after the next sentence end at or after the current word.
+    /// Paragraph ends count as sentence ends, so unpunctuated headings are stops too.
+    pub fn next_sentence(&mut self) {
+        self.seek_next_start(Self::ends_sentence);
+    }
+    /// Moves to the start of the current sentence, or of the previous one when
+    /// already at a start.
+    pub fn previous_sentence(&mut self) {
+        self.seek_previous_start(Self::ends_sentence);
+    }
+    /// Moves to the word after the next paragraph end at or after the current word.
+    pub fn next_paragraph(&mut self) {
+        self.seek_next_start(|word| word.is_paragraph_end);
+    }
+    /// Moves to the start of the current paragraph, or of the previous one when
+    /// already at a start.
+    pub fn previous_paragraph(&mut self) {
+        self.seek_previous_start(|word| word.is_paragraph_end);
+    }
+    /// Indices of up to `count` words after the current flash of `chunk_size` words.
+    pub fn upcoming(&self, chunk_size: usize, count: usize) -> Range<usize> {
+        let start = self.current_index.saturating_add(chunk_size.max(1)).min(self.words.len());
+        start..start.saturating_add(count).min(self.words.len())
+    }
+    /// Indices of the sentence holding the current word. Run-on text is cut to
+    /// about `max_words`, centered on the current word.
+    pub fn current_sentence(&self, max_words: usize) -> Range<usize> {
+        let is_end = |index: usize| self.words.get(index).is_some_and(|word| Self::ends_sentence(&word));
+        let half = max_words / 2;
+        let lower = self.current_index.saturating_sub(half);
+        let upper = self.current_index.saturating_add(half + 1).min(self.words.len());
+        let start = (lower..self.current_index).rev().find(|&index| is_end(index)).map_or(lower, |end| end + 1);
+        let end = (self.current_index..upper).find(|&index| is_end(index)).map_or(upper, |end| end + 1);
+        start..end
+    }
+    /// Indices of the words where a case-insensitive match of `query` starts.
+    /// Searches the words joined by single spaces, so a query can span several
+    /// words; the original file's formatting is not searched.
+    pub fn find(&self, query: &str) -> Vec<usize> {
+        let query = query.split_whitespace().collect::<Vec<_>>().join(" ").to_lowercase();
+        if query.is_empty() { return Vec::new(); }
+        let mut text = String::new();
+        let mut word_starts = Vec::with_capacity(self.words.len());
+        for (index, word) in self.words.iter().enumerate() {
+            if index > 0 { text.push(' '); }
+            word_starts.push(text.len());
+            text.push_str(&word.text.to_lowercase());
+        }
+        let mut matches: Vec<usize> = text.match_indices(&query)
+            .map(|(offset, _)| word_starts.partition_point(|&start| start <= offset) - 1)
+            .collect();
+        matches.dedup();
+        matches
+    }
+    fn ends_sentence(word: &WordRef) -> bool {
+        word.is_sentence_end() || word.is_paragraph_end
+    }
+    fn seek_next_start(&mut self, is_end: impl Fn(&WordRef) -> bool) {
+        let next_start = self.words.iter().enumerate()
+            .skip(self.current_index)
+            .find(|(_, word)| is_end(word))
+            .map(|(end, _)| end + 1);
+        if let Some(index) = next_start.filter(|&index| index < self.words.len()) {
+            self.current_index = index;
+        }
+    }
+    fn seek_previous_start(&mut self, is_end: impl Fn(&WordRef) -> bool) {
+        let start_of = |index: usize| self.words.iter().enumerate()
+            .take(index)
+            .rev()
+            .find(|(_, word)| is_end(word))
+            .map_or(0, |(end, _)| end + 1);
+        let start = start_of(self.current_index);
+        self.current_index = if start < self.current_index { start } else { start_of(start.saturating_sub(1)) };
+    }
+    pub fn restart(&mut self) {
+        self.current_index = 0;
+    }
     pub fn seek(&mut self, index: usize) {
         self.current_index = index.min(self.words.len().saturating_sub(1));
     }
+    /// The words shown together from the current one on, at most `chunk_size`.
+    pub fn current_chunk(&self, chunk_size: usize) -> Vec<WordRef<'_>> {
+        let end = self.current_index.saturating_add(chunk_size.max(1)).min(self.words.len());
+        (self.current_index..end).filter_map(|index| self.words.get(index)).collect()
+    }
     pub fn is_at_end(&self) -> bool {
         self.current_index + 1 >= self.words.len()
     }
-    /// Advances to next word. Returns true if advanced, false if at end.
-    pub fn advance(&mut self) -> bool {
-        if !self.is_at_end() {
-            self.current_index += 1;
+    /// Advances by `step` words. Returns true if advanced, false if at end.
+    /// At the end it rests on the last word, so `is_at_end` holds even when the
+    /// final chunk started before it.
+    pub fn advance(&mut self, step: usize) -> bool {
+        if self.current_index + step < self.words.len() {
+            self.current_index += step;
             true
         } else {
+            self.current_index = self.words.len().saturating_sub(1);
             false
         }
     }
+    #[allow(clippy::type_complexity)]
     pub fn on_navigate(
         trigger: On<ContentNavigate>,
         mut commands: Commands,
         mut next_state: ResMut<NextState<ReadingState>>,
-        active_tab: Single<&mut Content, (With<ActiveTab>, With<ReaderTab>)>,
+        active_tab: Single<(Entity, &mut Content, &TabChunkSize), (With<ActiveTab>, With<ReaderTab>)>,
     ) {
-        let mut content = active_tab.into_inner();
+        let (entity, mut content, chunk_size) = active_tab.into_inner();
         match trigger.event() {
             ContentNavigate::Advance => {
-                if !content.advance() {
+                if !content.advance(chunk_size.0.max(1)) {
                     next_state.set(ReadingState::Idle);
+                    commands.trigger(ContentFinished { entity });
                 }
             }
             ContentNavigate::Seek(index) => {
@@ -198,6 +472,18 @@ impl Content {
             ContentNavigate::SkipBackward(amount) => {
                 content.skip_backward(*amount);
             }
+            ContentNavigate::NextSentence => {
+                content.next_sentence();
+            }
+            ContentNavigate::PreviousSentence => {
+                content.previous_sentence();
+            }
+            ContentNavigate::NextParagraph => {
+                content.next_paragraph();
+            }
+            ContentNavigate::PreviousParagraph => {
+                content.previous_paragraph();
+            }
         }
         commands.trigger(WordChanged);
     }
@@ -219,10 +505,12 @@ impl TabSelect {
         trigger: On<TabSelect>,
         mut commands: Commands,
         mut next_state: ResMut<NextState<ReadingState>>,
+        mut history: ResMut<TabHistory>,
         active_tab: Option<Single<Entity, With<ActiveTab>>>,
     ) {
         let target = trigger.entity;
         next_state.set(ReadingState::Idle);
+        history.touch(target);
         
         if let Some(current_active) = active_tab {
             commands.entity(current_active.into_inner()).remove::<ActiveTab>();
@@ -243,22 +531,37 @@ pub struct TabClose {
     pub entity: Entity,
 }
 impl TabClose {
-    /// Despawns the tab, cleans up its word cache, and auto-selects
-    /// an adjacent tab if the closed tab was active.
+    /// Despawns the tab, records it in `ClosedTabHistory` (ephemeral tabs are gone
+    /// for good), and if the closed tab was active, selects the previously used tab
+    /// (or an adjacent one).
+    #[allow(clippy::collapsible_if, clippy::type_complexity)]
     fn on_trigger(
         trigger: On<TabClose>,
         mut commands: Commands,
         tab_order: Res<TabOrder>,
-        tabs: Query<(Has<ActiveTab>, &Content), (With<TabMarker>, With<ReaderTab>)>,
+        history: Res<TabHistory>,
+        mut closed_tabs: ResMut<ClosedTabHistory>,
+        tabs: Query<(Has<ActiveTab>, Has<EphemeralTab>, &Content), (With<TabMarker>, With<ReaderTab>)>,
+        saved_tabs: Query<SavedTabQuery>,
     ) {
         let target = trigger.entity;
-        let Ok((was_active, content)) = tabs.get(target) else { return; };
+        let Ok((was_active, is_ephemeral, content)) = tabs.get(target) else { return; };
 
-        ProgramState::delete_word_cache(&content.content_cache_id);
+        if !is_ephemeral {
+            let order_index = tab_order.entities().iter().position(|&e| e == target).unwrap_or(tab_order.entities().len());
+            let dropped_cache_id = match saved_tabs.get(target) {
+                Ok(tab) => closed_tabs.push(order_index, SavedTab::snapshot(&tab)),
+                Err(_) => Some(content.content_cache_id.clone()),
+            };
+            if let Some(cache_id) = dropped_cache_id {
+                ProgramState::delete_word_cache(&cache_id);
+            }
+        }
         commands.entity(target).despawn();
         
+        let next_active = history.previous(target).or_else(|| tab_order.find_adjacent(target));
         if was_active {
-            if let Some(entity) = tab_order.find_adjacent(target) {
+            if let Some(entity) = next_active {
                 commands.trigger(TabSelect { entity });
             }
         }
@@ -270,6 +573,159 @@ impl From<Entity> for TabClose {
     }
 }
 
+/// Closes every reader tab except the target, which becomes the active tab.
+#[derive(EntityEvent)]
+pub struct TabCloseOthers {
+    pub entity: Entity,
+}
+impl TabCloseOthers {
+    fn on_trigger(
+        trigger: On<TabCloseOthers>,
+        mut commands: Commands,
+        tab_order: Res<TabOrder>,
+        reader_tabs: Query<(), With<ReaderTab>>,
+    ) {
+        let target = trigger.entity;
+        // Selected first, so closing the others never hands focus to one about to close.
+        commands.trigger(TabSelect::from(target));
+        for &entity in tab_order.entities() {
+            if entity != target && reader_tabs.contains(entity) {
+                commands.trigger(TabClose::from(entity));
+            }
+        }
+    }
+}
+impl From<Entity> for TabCloseOthers {
+    fn from(entity: Entity) -> Self {
+        Self { entity }
+    }
+}
+
+/// Opens a copy of a reader tab with its settings, position and bookmarks, and
+/// a word cache of its own. Reading time starts over.
+#[derive(EntityEvent)]
+pub struct TabDuplicate {
+    pub entity: Entity,
+}
+impl TabDuplicate {
+    #[allow(clippy::type_complexity)]
+    fn on_trigger(
+        trigger: On<TabDuplicate>,
+        mut commands: Commands,
+        reader_tabs: Query<(
+            &Name, &Content, &TabFontSettings, &TabWpm, &TabChunkSize, &TabTextStats, &TabParseOptions, &Bookmarks,
+            Option<&TabFilePath>, Option<&TabSourceUrl>, Option<&TabBookMeta>, Has<EphemeralTab>,
+        ), With<ReaderTab>>,
+    ) {
+        let Ok((name, content, font_settings, wpm, chunk_size, stats, parse_options, bookmarks, file_path, source_url, book_meta, is_ephemeral))
+            = reader_tabs.get(trigger.entity) else { return };
+        let (words, sections) = (content.words.clone(), content.sections.clone());
+        let mut copy = if is_ephemeral {
+            Content::new_ephemeral(words, sections)
+        } else {
+            Content::new(words, sections)
+        };
+        copy.seek(content.current_index);
+
+        let mut request = TabCreateRequest::new(format!("{} (copy)", name), copy)
+            .with_font(font_settings.font.name.clone(), font_settings.font_size)
+            .with_highlight_color(font_settings.highlight_color)
+            .with_wpm(wpm.0)
+            .with_chunk_size(chunk_size.0)
+            .with_stats(stats.0)
+            .with_parse_options(parse_options.0.clone())
+            .with_bookmarks(bookmarks.0.clone());
+        if let Some(book_meta) = book_meta {
+            request = request.with_book_meta(book_meta.0.clone());
+        }
+        if let Some(file_path) = file_path {
+            request = request.with_file_path(file_path.0.clone());
+        }
+        if let Some(url) = source_url {
+            request = request.with_source_url(url.0.clone());
+        }
+        commands.trigger(request);
+    }
+}
+impl From<Entity> for TabDuplicate {
+    fn from(entity: Entity) -> Self {
+        Self { entity }
+    }
+}
+
+/// Renames a reader tab. A blank name keeps the current one.
+#[derive(EntityEvent)]
+pub struct TabRename {
+    pub entity: Entity,
+    pub name: String,
+}
+impl TabRename {
+    fn on_trigger(trigger: On<TabRename>, mut reader_tabs: Query<&mut Name, With<ReaderTab>>) {
+        let Ok(mut name) = reader_tabs.get_mut(trigger.entity) else { return };
+        let trimmed = trigger.name.trim();
+        if trimmed.is_empty() || trimmed == name.as_str() { return; }
+        name.set(trimmed.to_string());
+    }
+}
+
+/// Selects another tab by its place in `TabOrder`. The homepage is skipped while
+/// reader tabs are open; cycling wraps around.
+#[derive(Event)]
+pub enum TabSwitch {
+    Next,
+    Previous,
+    /// Zero-based position among the tabs that can be switched to.
+    Position(usize),
+}
+impl TabSwitch {
+    fn on_trigger(
+        trigger: On<TabSwitch>,
+        mut commands: Commands,
+        tab_order: Res<TabOrder>,
+        tabs: Query<Has<HomepageTab>, With<TabMarker>>,
+        active_tab: Option<Single<Entity, With<ActiveTab>>>,
+    ) {
+        let has_reader_tabs = tabs.iter().any(|is_homepage| !is_homepage);
+        let candidates: Vec<Entity> = tab_order.entities().iter()
+            .copied()
+            .filter(|&entity| !has_reader_tabs || tabs.get(entity).is_ok_and(|is_homepage| !is_homepage))
+            .collect();
+        if candidates.is_empty() { return; }
+
+        let active = active_tab.map(|active| *active);
+        let current = active.and_then(|active| candidates.iter().position(|&entity| entity == active));
+        let target = match (trigger.event(), current) {
+            (TabSwitch::Next, Some(position)) => candidates[(position + 1) % candidates.len()],
+            (TabSwitch::Previous, Some(position)) => candidates[(position + candidates.len() - 1) % candidates.len()],
+            (TabSwitch::Next, None) => candidates[0],
+            (TabSwitch::Previous, None) => candidates[candidates.len() - 1],
+            (TabSwitch::Position(position), _) => {
+                let Some(&entity) = candidates.get(*position) else { return };
+                entity
+            }
+        };
+        if active != Some(target) {
+            commands.trigger(TabSelect { entity: target });
+        }
+    }
+}
+
+/// Reopens the most recently closed tab from `ClosedTabHistory` at its old place
+/// and selects it.
+#[derive(Event)]
+pub struct ReopenClosedTab;
+impl ReopenClosedTab {
+    fn on_trigger(_trigger: On<ReopenClosedTab>, mut commands: Commands, mut closed_tabs: ResMut<ClosedTabHistory>) {
+        let Some(closed) = closed_tabs.0.pop_back() else { return };
+        let Some(cache) = ProgramState::load_word_cache(closed.tab.content_cache_id()) else {
+            warn!("Cache miss for closed tab ({}), not reopened", closed.tab.content_cache_id());
+            commands.trigger(Toast::new(ToastKind::Warning, "The closed tab's text is no longer stored"));
+            return;
+        };
+        commands.trigger(closed.into_request(cache));
+    }
+}
+
 /// Builder-pattern event for creating reader tabs. Optional fields fall back
 /// to `DefaultTabSettings`. The observer spawns the entity and optionally
 /// triggers `TabSelect` to make it active.
@@ -278,10 +734,20 @@ pub struct TabCreateRequest {
     pub name: String,
     pub content: Content,
     pub file_path: Option<String>,
+    pub source_url: Option<String>,
     pub font_name: Option<String>,
     pub font_size: Option<f32>,
+    pub highlight_color: Option<[f32; 3]>,
     pub wpm: Option<u32>,
+    pub chunk_size: usize,
     pub is_active: bool,
+    pub stats: Option<TextStats>,
+    pub book_meta: BookMeta,
+    pub parse_options: ParseOptions,
+    pub bookmarks: Vec<Bookmark>,
+    pub read_time: Duration,
+    /// Place in `TabOrder`; new tabs go last when unset.
+    pub order_index: Option<usize>,
 }
 impl TabCreateRequest {
     pub fn new(name: String, content: Content) -> Self {
@@ -289,60 +755,136 @@ impl TabCreateRequest {
             name,
             content,
             file_path: None,
+            source_url: None,
             font_name: None,
             font_size: None,
+            highlight_color: None,
             wpm: None,
+            chunk_size: 1,
             is_active: true,
+            stats: None,
+            book_meta: BookMeta::default(),
+            parse_options: ParseOptions::default(),
+            bookmarks: Vec::new(),
+            read_time: Duration::ZERO,
+            order_index: None,
         }
     }
     pub fn with_file_path(mut self, name: impl Into<String>) -> Self {
         self.file_path = Some(name.into());
         self
     }
+    pub fn with_source_url(mut self, url: impl Into<String>) -> Self {
+        self.source_url = Some(url.into());
+        self
+    }
     pub fn with_font(mut self, name: String, size: f32) -> Self {
         self.font_name = Some(name);
         self.font_size = Some(size);
         self
     }
+    pub fn with_highlight_color(mut self, color: [f32; 3]) -> Self {
+        self.highlight_color = Some(color);
+        self
+    }
     pub fn with_wpm(mut self, wpm: u32) -> Self {
         self.wpm = Some(wpm);
         self
     }
+    pub fn with_chunk_size(mut self, chunk_size: usize) -> Self {
+        self.chunk_size = chunk_size;
+        self
+    }
     pub fn with_active(mut self, active: bool) -> Self {
         self.is_active = active;
         self
     }
+    /// Stats reported by the parser or saved with the tab. Computed from the
+    /// content's words when not supplied.
+    pub fn with_stats(mut self, stats: TextStats) -> Self {
+        self.stats = Some(stats);
+        self
+    }
+    pub fn with_book_meta(mut self, book_meta: BookMeta) -> Self {
+        self.book_meta = book_meta;
+        self
+    }
+    pub fn with_parse_options(mut self, options: ParseOptions) -> Self {
+        self.parse_options = options;
+        self
+    }
+    pub fn with_read_time(mut self, read_time: Duration) -> Self {
+        self.read_time = read_time;
+        self
+    }
+    /// Bookmarks past the end of the content are dropped.
+    pub fn with_order_index(mut self, index: usize) -> Self {
+        self.order_index = Some(index);
+        self
+    }
+    pub fn with_bookmarks(mut self, bookmarks: Vec<Bookmark>) -> Self {
+        self.bookmarks = bookmarks;
+        self
+    }
     fn on_trigger(
         trigger: On<TabCreateRequest>,
         mut commands: Commands,
         fonts: Res<FontsStore>,
         defaults: Res<DefaultTabSettings>,
+        wpm_memory: Res<WpmMemory>,
     ) {
         let font = fonts.resolve(trigger.font_name.as_deref().unwrap_or(&defaults.font_name));
         let font_size = trigger.font_size.unwrap_or(defaults.font_size);
-        let wpm = trigger.wpm.unwrap_or(defaults.wpm);
+        let wpm = trigger.wpm
+            .or_else(|| trigger.file_path.as_deref().or(trigger.source_url.as_deref()).and_then(|key| wpm_memory.get(key)))
+            .unwrap_or(defaults.wpm);
+        let stats = trigger.stats.unwrap_or_else(|| TextStats::from_words(&trigger.content.words));
         
         let mut entity_commands = commands.spawn((
             TabMarker,
             ReaderTab,
             Name::new(trigger.name.clone()),
-            TabFontSettings::from_font(font, font_size),
+            TabFontSettings::from_font(font, font_size)
+                .with_highlight_color(trigger.highlight_color.unwrap_or(HIGHLIGHT_COLOR_DEFAULT)),
             TabWpm(wpm),
+            TabChunkSize(trigger.chunk_size.clamp(1, CHUNK_SIZE_MAX)),
+            TabTextStats(stats),
+            TabParseOptions(trigger.parse_options.clone()),
+            Bookmarks::new(trigger.bookmarks.clone(), trigger.content.words.len()),
+            ReadTime(trigger.read_time),
             trigger.content.clone(),
         ));
         
         if let Some(path) = &trigger.file_path {
             entity_commands.insert(TabFilePath(path.clone()));
         }
+
+        if let Some(url) = &trigger.source_url {
+            entity_commands.insert(TabSourceUrl(url.clone()));
+        }
+
+        if !trigger.book_meta.is_empty() {
+            entity_commands.insert(TabBookMeta(trigger.book_meta.clone()));
+        }
+
+        if trigger.content.is_ephemeral {
+            entity_commands.insert(EphemeralTab);
+        }
+
+        let entity = entity_commands.id();
+        if let Some(index) = trigger.order_index {
+            // Queued after the spawn, so `TabOrder::on_tab_added` has placed it last already.
+            commands.queue(move |world: &mut World| world.resource_mut::<TabOrder>().move_to(entity, index));
+        }
         
         if trigger.is_active {
-            let entity = entity_commands.id();
             commands.trigger(TabSelect { entity });
         }
     }
 }
 
 /// Overwrites font and WPM on every reader tab with current `DefaultTabSettings`.
+/// Each tab keeps its own highlight color.
 #[derive(Event)]
 pub struct ApplyDefaultsToAll;
 impl ApplyDefaultsToAll {
@@ -351,13 +893,14 @@ impl ApplyDefaultsToAll {
         mut commands: Commands,
         defaults: Res<DefaultTabSettings>,
         fonts: Res<FontsStore>,
-        reader_tabs: Query<Entity, With<ReaderTab>>,
+        reader_tabs: Query<(Entity, &TabFontSettings), With<ReaderTab>>,
     ) {
         let font = fonts.resolve(&defaults.font_name);
 
-        for entity in reader_tabs.iter() {
+        for (entity, font_settings) in reader_tabs.iter() {
             commands.entity(entity).insert((
-                TabFontSettings::from_font(font, defaults.font_size),
+                TabFontSettings::from_font(font, defaults.font_size)
+                    .with_highlight_color(font_settings.highlight_color),
                 TabWpm(defaults.wpm),
             ));
         }
@@ -370,17 +913,17 @@ impl ApplyDefaultsToAll {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::persistence::TestDataDir;
+    use crate::text::Word;
 
     fn make_content(word_count: usize, current_index: usize) -> Content {
         let words = (0..word_count)
             .map(|index| Word::new(format!("w{}", index)))
-            .collect();
+            .collect::<Vec<_>>()
+            .try_into()
+            .unwrap();
 
-        Content {
-            content_cache_id: "test-cache".into(),
-            words,
-            current_index,
-        }
+        Content::new_from_loaded("test-cache".into(), words, Vec::new(), current_index)
     }
 
     #[test]
@@ -401,11 +944,11 @@ mod tests {
     fn content_advance_and_seek_follow_contract() {
         let mut content = make_content(2, 0);
 
-        assert!(content.advance());
+        assert!(content.advance(1));
         assert_eq!(content.current_index, 1);
         assert!(content.is_at_end());
 
-        assert!(!content.advance());
+        assert!(!content.advance(1));
         assert_eq!(content.current_index, 1);
 
         content.seek(0);
@@ -413,13 +956,141 @@ mod tests {
         assert!(!content.is_at_end());
     }
 
+    #[test]
+    fn content_advances_by_chunk_and_rests_on_last_word() {
+        let mut content = make_content(5, 0);
+
+        assert_eq!(content.current_chunk(3).len(), 3);
+        assert!(content.advance(3));
+        assert_eq!(content.current_index, 3);
+        assert_eq!(content.current_chunk(3).len(), 2);
+
+        assert!(!content.advance(3));
+        assert_eq!(content.current_index, 4);
+        assert!(content.is_at_end());
+    }
+
+    #[test]
+    fn sentence_navigation_stops_at_sentence_boundaries() {
+        let words: Vec<Word> = "Hi there. Mr. Smith came. Bye!".split(' ').map(Word::new).collect();
+        let mut content = Content::new_from_loaded("test-cache".into(), words.try_into().unwrap(), Vec::new(), 3);
+
+        content.next_sentence();
+        assert_eq!(content.current_index, 5);
+        content.next_sentence();
+        assert_eq!(content.current_index, 5);
+
+        content.previous_sentence();
+        assert_eq!(content.current_index, 2);
+        content.current_index = 4;
+        content.previous_sentence();
+        assert_eq!(content.current_index, 2);
+        content.previous_sentence();
+        assert_eq!(content.current_index, 0);
+        content.previous_sentence();
+        assert_eq!(content.current_index, 0);
+    }
+
+    #[test]
+    fn current_sentence_spans_boundaries_and_caps_run_on_text() {
+        let words: Vec<Word> = "Hi there. Mr. Smith came. Bye!".split(' ').map(Word::new).collect();
+        let mut content = Content::new_from_loaded("test-cache".into(), words.try_into().unwrap(), Vec::new(), 3);
+
+        assert_eq!(content.current_sentence(50), 2..5);
+        content.current_index = 0;
+        assert_eq!(content.current_sentence(50), 0..2);
+        content.current_index = 5;
+        assert_eq!(content.current_sentence(50), 5..6);
+
+        let mut run_on = make_content(100, 50);
+        assert_eq!(run_on.current_sentence(10), 45..56);
+        run_on.current_index = 2;
+        assert_eq!(run_on.current_sentence(10), 0..8);
+    }
+
+    #[test]
+    fn upcoming_skips_the_flash_and_stops_at_the_end() {
+        let mut content = make_content(20, 3);
+
+        assert_eq!(content.upcoming(1, 10), 4..14);
+        assert_eq!(content.upcoming(3, 10), 6..16);
+        content.current_index = 15;
+        assert_eq!(content.upcoming(2, 10), 17..20);
+        content.current_index = 19;
+        assert_eq!(content.upcoming(1, 10), 20..20);
+    }
+
+    #[test]
+    fn find_matches_case_insensitively_across_words() {
+        let words: Vec<Word> = "The cat sat. The Cathedral, the CAT!".split(' ').map(Word::new).collect();
+        let content = Content::new_from_loaded("test-cache".into(), words.try_into().unwrap(), Vec::new(), 0);
+
+        assert_eq!(content.find("cat"), vec![1, 4, 6]);
+        assert_eq!(content.find("  the   cat "), vec![0, 3, 5]);
+        assert_eq!(content.find("sat. the"), vec![2]);
+        assert_eq!(content.find("dog"), Vec::<usize>::new());
+        assert_eq!(content.find(" "), Vec::<usize>::new());
+    }
+
+    #[test]
+    fn paragraph_navigation_uses_paragraph_end_flags() {
+        let words: Vec<Word> = (0..9)
+            .map(|index| {
+                let mut word = Word::new(format!("w{index}."));
+                word.is_paragraph_end = index == 2 || index == 5;
+                word
+            })
+            .collect();
+        let mut content = Content::new_from_loaded("test-cache".into(), words.try_into().unwrap(), Vec::new(), 0);
+
+        content.next_paragraph();
+        assert_eq!(content.current_index, 3);
+        content.next_paragraph();
+        assert_eq!(content.current_index, 6);
+        content.next_paragraph();
+        assert_eq!(content.current_index, 6);
+
+        content.current_index = 7;
+        content.previous_paragraph();
+        assert_eq!(content.current_index, 6);
+        content.previous_paragraph();
+        assert_eq!(content.current_index, 3);
+        content.previous_paragraph();
+        assert_eq!(content.current_index, 0);
+    }
+
+    #[test]
+    fn current_section_is_the_last_one_started() {
+        let mut content = make_content(10, 0);
+        content.sections = vec![
+            Section { title: "One".into(), start_index: 2 },
+            Section { title: "Two".into(), start_index: 6 },
+        ];
+
+        assert_eq!(content.current_section(), None);
+        content.current_index = 2;
+        assert_eq!(content.current_section(), Some(0));
+        content.current_index = 9;
+        assert_eq!(content.current_section(), Some(1));
+        assert!(content.is_section_start(6));
+        assert!(!content.is_section_start(7));
+    }
+
     #[test]
     fn content_empty_is_treated_as_end() {
         let content = make_content(0, 0);
 
         assert!(!content.has_words());
         assert!(content.is_at_end());
-        assert!(content.current_word().is_none());
+        assert!(content.current_chunk(1).is_empty());
+    }
+
+    #[test]
+    fn ephemeral_content_writes_no_word_cache() {
+        let _data_dir = TestDataDir::acquire();
+        let content = Content::new_ephemeral(vec![Word::new("secret")].try_into().unwrap(), Vec::new());
+
+        assert!(ProgramState::load_word_cache(&content.content_cache_id).is_none());
     }
 
     #[test]
@@ -447,4 +1118,239 @@ mod tests {
         let multi = TabOrder(vec![only]);
         assert_eq!(multi.find_adjacent(missing), None);
     }
+
+    /// The returned `TestDataDir` must be kept alive for the whole test.
+    fn make_tabs_app() -> (App, Entity, TestDataDir) {
+        let data_dir = TestDataDir::acquire();
+        let mut app = App::new();
+        app
+            .add_plugins(MinimalPlugins)
+            .add_plugins(bevy::state::app::StatesPlugin)
+            .init_state::<ReadingState>()
+            .init_resource::<TabOrder>()
+            .init_resource::<TabHistory>()
+            .init_resource::<ClosedTabHistory>()
+            .add_observer(TabSelect::on_trigger)
+            .add_observer(TabClose::on_trigger)
+            .add_observer(TabRename::on_trigger)
+            .add_observer(TabCloseOthers::on_trigger)
+            .add_observer(TabSwitch::on_trigger)
+            .add_observer(TabOrder::on_tab_added)
+            .add_observer(TabOrder::on_tab_removed)
+            .add_observer(TabHistory::on_tab_removed)
+            ;
+        let homepage = app.world_mut().spawn((TabMarker, HomepageTab, ActiveTab)).id();
+        (app, homepage, data_dir)
+    }
+
+    fn spawn_reader_tab(app: &mut App) -> Entity {
+        app.world_mut().spawn((TabMarker, ReaderTab, make_content(1, 0))).id()
+    }
+
+    fn select(app: &mut App, entity: Entity) {
+        app.world_mut().trigger(TabSelect { entity });
+        app.update();
+    }
+
+    fn close(app: &mut App, entity: Entity) {
+        app.world_mut().trigger(TabClose { entity });
+        app.update();
+    }
+
+    fn active_tab(app: &mut App) -> Entity {
+        app.world_mut().query_filtered::<Entity, With<ActiveTab>>().single(app.world()).unwrap()
+    }
+
+    #[test]
+    fn closing_active_tab_returns_to_previously_used_tab() {
+        let (mut app, _, _data_dir) = make_tabs_app();
+        let first = spawn_reader_tab(&mut app);
+        let second = spawn_reader_tab(&mut app);
+        let third = spawn_reader_tab(&mut app);
+
+        select(&mut app, first);
+        select(&mut app, third);
+        select(&mut app, second);
+        close(&mut app, second);
+
+        assert_eq!(active_tab(&mut app), third);
+        assert_eq!(app.world().resource::<TabHistory>().0, vec![first, third]);
+    }
+
+    #[test]
+    fn closing_inactive_tab_keeps_active_tab_and_prunes_history() {
+        let (mut app, _, _data_dir) = make_tabs_app();
+        let first = spawn_reader_tab(&mut app);
+        let second = spawn_reader_tab(&mut app);
+
+        select(&mut app, first);
+        select(&mut app, second);
+        close(&mut app, first);
+
+        assert_eq!(active_tab(&mut app), second);
+        assert_eq!(app.world().resource::<TabHistory>().0, vec![second]);
+    }
+
+    #[test]
+    fn reopened_tab_returns_to_its_place_and_position() {
+        let (mut app, homepage, _data_dir) = make_tabs_app();
+        app
+            .insert_resource(FontsStore::from_fonts(vec![FontData { name: "Test".into(), handle: Handle::default() }]))
+            .init_resource::<DefaultTabSettings>()
+            .init_resource::<WpmMemory>()
+            .add_observer(TabCreateRequest::on_trigger)
+            ;
+        for current_index in 0..3 {
+            app.world_mut().trigger(TabCreateRequest::new(format!("Tab {}", current_index), make_content(5, current_index)));
+            app.update();
+        }
+        let second = app.world().resource::<TabOrder>().entities()[2];
+
+        close(&mut app, second);
+        let closed = app.world_mut().resource_mut::<ClosedTabHistory>().0.pop_back().unwrap();
+        let words = make_content(5, 0).words;
+        app.world_mut().trigger(closed.into_request(WordCache { words, sections: Vec::new() }));
+        app.update();
+
+        let order = app.world().resource::<TabOrder>().entities().to_vec();
+        let reopened = order[2];
+        assert_eq!(order.len(), 4);
+        assert_ne!(reopened, second);
+        assert_ne!(reopened, homepage);
+        assert_eq!(active_tab(&mut app), reopened);
+        assert_eq!(app.world().get::<Content>(reopened).unwrap().current_index, 1);
+        assert_eq!(app.world().get::<Name>(reopened).unwrap().as_str(), "Tab 1");
+    }
+
+    #[test]
+    fn closing_others_keeps_the_target_active_and_the_homepage_open() {
+        let (mut app, homepage, _data_dir) = make_tabs_app();
+        let first = spawn_reader_tab(&mut app);
+        let second = spawn_reader_tab(&mut app);
+        let third = spawn_reader_tab(&mut app);
+
+        select(&mut app, third);
+        app.world_mut().trigger(TabCloseOthers { entity: second });
+        app.update();
+
+        assert_eq!(active_tab(&mut app), second);
+        assert_eq!(app.world().resource::<TabOrder>().entities(), &[homepage, second]);
+        assert!(app.world().get_entity(first).is_err());
+    }
+
+    #[test]
+    fn ephemeral_content_opens_an_ephemeral_tab_that_closes_without_trace() {
+        let (mut app, homepage, _data_dir) = make_tabs_app();
+        app
+            .insert_resource(FontsStore::from_fonts(vec![FontData { name: "Test".into(), handle: Handle::default() }]))
+            .init_resource::<DefaultTabSettings>()
+            .init_resource::<WpmMemory>()
+            .add_observer(TabCreateRequest::on_trigger)
+            ;
+        let content = Content::new_ephemeral(vec![Word::new("secret")].try_into().unwrap(), Vec::new());
+        let cache_id = content.content_cache_id.clone();
+        app.world_mut().trigger(TabCreateRequest::new("Secret".into(), content));
+        app.update();
+        let reader = active_tab(&mut app);
+        let is_ephemeral_tab = app.world().get::<EphemeralTab>(reader).is_some();
+
+        close(&mut app, reader);
+
+        assert!(is_ephemeral_tab);
+        assert_eq!(active_tab(&mut app), homepage);
+        assert!(app.world().resource::<ClosedTabHistory>().0.is_empty());
+        assert!(ProgramState::load_word_cache(&cache_id).is_none());
+    }
+
+    #[test]
+    fn switching_cycles_reader_tabs_and_skips_the_homepage() {
+        let (mut app, homepage, _data_dir) = make_tabs_app();
+        let first = spawn_reader_tab(&mut app);
+        let second = spawn_reader_tab(&mut app);
+        let third = spawn_reader_tab(&mut app);
+        let switch = |app: &mut App, event: TabSwitch| {
+            app.world_mut().trigger(event);
+            app.update();
+            active_tab(app)
+        };
+
+        assert_eq!(switch(&mut app, TabSwitch::Next), first);
+        assert_eq!(switch(&mut app, TabSwitch::Previous), third);
+        assert_eq!(switch(&mut app, TabSwitch::Next), first);
+        assert_eq!(switch(&mut app, TabSwitch::Position(1)), second);
+        assert_eq!(switch(&mut app, TabSwitch::Position(8)), second);
+
+        for reader in [first, second, third] {
+            close(&mut app, reader);
+        }
+        assert_eq!(switch(&mut app, TabSwitch::Next), homepage);
+    }
+
+    #[test]
+    fn renaming_trims_and_ignores_blank_names() {
+        let (mut app, _, _data_dir) = make_tabs_app();
+        let reader = app.world_mut().spawn((TabMarker, ReaderTab, Name::new("Text 1"), make_content(1, 0))).id();
+        let name = |app: &App| app.world().entity(reader).get::<Name>().unwrap().to_string();
+
+        app.world_mut().trigger(TabRename { entity: reader, name: "  Moby Dick ".into() });
+        assert_eq!(name(&app), "Moby Dick");
+
+        app.world_mut().trigger(TabRename { entity: reader, name: "   ".into() });
+        assert_eq!(name(&app), "Moby Dick");
+    }
+
+    #[test]
+    fn wpm_memory_follows_tabs_opened_from_a_url() {
+        let url = "https://example.com/story.html";
+        let mut app = App::new();
+        let mut memory = WpmMemory::default();
+        memory.remember(url, 480);
+        app
+            .add_plugins(MinimalPlugins)
+            .insert_resource(FontsStore::from_fonts(vec![FontData { name: "Test".into(), handle: Handle::default() }]))
+            .init_resource::<DefaultTabSettings>()
+            .insert_resource(memory)
+            .add_observer(TabCreateRequest::on_trigger)
+            .add_systems(Update, WpmMemory::record)
+            ;
+
+        app.world_mut().trigger(TabCreateRequest::new("Story".into(), make_content(3, 0)).with_source_url(url));
+        app.update();
+        let mut tabs = app.world_mut().query_filtered::<&mut TabWpm, With<TabSourceUrl>>();
+        assert_eq!(tabs.single(app.world()).unwrap().0, 480);
+
+        tabs.single_mut(app.world_mut()).unwrap().0 = 550;
+        app.update();
+        assert_eq!(app.world().resource::<WpmMemory>().get(url), Some(550));
+    }
+
+    #[test]
+    fn wpm_memory_keeps_latest_wpm_per_file() {
+        let mut memory = WpmMemory::default();
+        memory.remember("/books/a.epub", 300);
+        memory.remember("/books/b.txt", 450);
+        memory.remember("/books/a.epub", 520);
+
+        assert_eq!(memory.get("/books/a.epub"), Some(520));
+        assert_eq!(memory.get("/books/b.txt"), Some(450));
+        assert_eq!(memory.get("/books/c.md"), None);
+
+        for index in 0..WPM_MEMORY_MAX {
+            memory.remember(&format!("/books/{}.txt", index), 400);
+        }
+        assert_eq!(memory.get("/books/b.txt"), None);
+        assert_eq!(memory.0.len(), WPM_MEMORY_MAX);
+    }
+
+    #[test]
+    fn closing_only_reader_tab_activates_homepage() {
+        let (mut app, homepage, _data_dir) = make_tabs_app();
+        let reader = spawn_reader_tab(&mut app);
+
+        select(&mut app, reader);
+        close(&mut app, reader);
+
+        assert_eq!(active_tab(&mut app), homepage);
+        assert!(app.world().get_entity(reader).is_err());
+    }
 }
\ No newline at end of file
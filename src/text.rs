@@ -2,18 +2,32 @@
 //!
 //! `FileParsers` resource maps file extensions to `TextParser` implementations.
 
-use std::collections::HashMap;
-use std::io::Cursor;
+use std::collections::{HashMap, HashSet};
+use std::io::{Cursor, Read};
 use std::path::Path;
-use std::sync::Arc;
+use std::sync::{Arc, LazyLock};
+use std::time::Duration;
 
 use bevy::prelude::*;
-use quick_xml::events::Event;
+use pulldown_cmark::{
+    Event as MarkdownEvent, Options as MarkdownOptions, Parser as MarkdownReader,
+    Tag as MarkdownTag, TagEnd as MarkdownTagEnd,
+};
+use quick_xml::escape::resolve_html5_entity;
+use quick_xml::events::{BytesRef, Event};
 use quick_xml::reader::Reader as XmlReader;
 use rbook::Epub;
 use rbook::ebook::Ebook;
+use rbook::ebook::manifest::ManifestEntry;
+use rbook::ebook::metadata::{MetaEntry, Metadata};
+use rbook::ebook::toc::{Toc, TocChildren, TocEntry};
 use rbook::reader::{Reader as EbookReader, ReaderContent};
 use serde::{Deserialize, Serialize};
+use unicode_bidi::{bidi_class, BidiClass};
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::orp::OrpConfig;
+use crate::reader::{TimingConfig, STOP_WORD_MULTIPLIER_MAX, STOP_WORD_MULTIPLIER_MIN};
 
 pub struct TextPlugin;
 impl Plugin for TextPlugin {
@@ -25,12 +39,13 @@ impl Plugin for TextPlugin {
 }
 
 /// Maps file extensions to their `TextParser` implementations.
-#[derive(Resource)]
+/// Cheap to clone into background parse tasks.
+#[derive(Resource, Clone)]
 pub struct FileParsers {
     parsers: HashMap<String, Arc<dyn TextParser>>,
 }
 impl FileParsers {
-    fn new() -> Self {
+    pub fn new() -> Self {
         let mut parsers: HashMap<String, Arc<dyn TextParser>> = HashMap::new();
 
         let txt = Arc::new(TxtParser) as Arc<dyn TextParser>;
@@ -39,6 +54,20 @@ impl FileParsers {
         let epub = Arc::new(EpubParser) as Arc<dyn TextParser>;
         parsers.insert("epub".into(), epub);
 
+        let docx = Arc::new(DocxParser) as Arc<dyn TextParser>;
+        parsers.insert("docx".into(), docx);
+
+        let html = Arc::new(HtmlParser) as Arc<dyn TextParser>;
+        parsers.insert("html".into(), html.clone());
+        parsers.insert("htm".into(), html);
+
+        let pdf = Arc::new(PdfParser) as Arc<dyn TextParser>;
+        parsers.insert("pdf".into(), pdf);
+
+        let markdown = Arc::new(MarkdownParser) as Arc<dyn TextParser>;
+        parsers.insert("md".into(), markdown.clone());
+        parsers.insert("markdown".into(), markdown);
+
         Self { parsers }
     }
 
@@ -51,171 +80,837 @@ impl FileParsers {
         self.get_for_extension(ext)
     }
 
+    /// Shared handle to the parser for `ext`, for use from async tasks.
+    pub fn get_arc_for_extension(&self, ext: &str) -> Option<Arc<dyn TextParser>> {
+        self.parsers.get(&ext.to_ascii_lowercase()).cloned()
+    }
+
+    pub fn get_arc_for_path(&self, path: &Path) -> Option<Arc<dyn TextParser>> {
+        let ext = path.extension()?.to_str()?;
+        self.get_arc_for_extension(ext)
+    }
+
     pub fn supported_extensions(&self) -> Vec<String> {
         self.parsers.keys().cloned().collect()
     }
 }
-/// Single display unit for the reader. Each word is shown for a duration
-/// based on WPM and punctuation/length multipliers.
-#[derive(Clone, Serialize, Deserialize)]
+/// Single display unit for the reader, as produced by parsers and stored in the
+/// word cache. Tabs keep their words in a `WordStore` and read them as `WordRef`s.
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
 pub struct Word {
     pub text: String,
     /// When true, an extra pause is applied after this word (set on the
     /// last word before a blank line, not the first word after).
     pub is_paragraph_end: bool,
+    /// Last word of a line, set only when `ParseOptions::preserve_line_breaks` is on.
+    #[serde(default)]
+    pub is_line_end: bool,
 }
 
 impl Word {
     pub fn new(text: impl Into<String>) -> Self {
-        Self { text: text.into(), is_paragraph_end: false }
+        Self { text: text.into(), is_paragraph_end: false, is_line_end: false }
     }
 
-    /// Returns the character index the eye should fixate on (slightly left-of-center).
-    /// Based on RSVP research: longer words need the fixation point further in.
-    pub fn orp_index(&self) -> usize {
-        match self.text.chars().count() {
-            0 => 0,
-            1 => 0,
-            2..=5 => 1,
-            6..=9 => 2,
-            10..=13 => 3,
-            _ => 4,
-        }
+    pub fn view(&self) -> WordRef<'_> {
+        WordRef { text: &self.text, is_paragraph_end: self.is_paragraph_end, is_line_end: self.is_line_end }
+    }
+}
+
+/// Borrowed view of one word. Each word is shown for a duration based on WPM
+/// and punctuation/length multipliers. Serializes exactly like `Word`.
+#[derive(Clone, Copy, PartialEq, Debug, Serialize)]
+pub struct WordRef<'a> {
+    pub text: &'a str,
+    pub is_paragraph_end: bool,
+    pub is_line_end: bool,
+}
+impl<'a> WordRef<'a> {
+    pub fn to_word(self) -> Word {
+        Word { text: self.text.to_string(), is_paragraph_end: self.is_paragraph_end, is_line_end: self.is_line_end }
+    }
+
+    /// Visible characters (grapheme clusters): an emoji sequence, flag, or letter
+    /// with combining accents counts as one.
+    pub fn grapheme_count(&self) -> usize {
+        self.text.graphemes(true).count()
+    }
+
+    /// Whether the first strongly directional character is right-to-left (Arabic,
+    /// Hebrew, ...). Words with no strong character count as left-to-right.
+    pub fn is_rtl(&self) -> bool {
+        self.text.chars()
+            .map(bidi_class)
+            .find(|class| matches!(class, BidiClass::L | BidiClass::R | BidiClass::AL))
+            .is_some_and(|class| class != BidiClass::L)
+    }
+
+    /// Whether the word contains Chinese, Japanese or Korean characters, which
+    /// Latin fonts usually lack.
+    pub fn has_cjk(&self) -> bool {
+        self.text.chars().any(is_cjk)
+    }
+
+    /// Returns the grapheme index the eye should fixate on (slightly left-of-center).
+    pub fn orp_index(&self, orp_config: &OrpConfig) -> usize {
+        orp_config.pivot_index(self.grapheme_count())
     }
     
+    /// Splits the text into the part before the fixation grapheme, the grapheme
+    /// itself, and the rest.
+    pub fn orp_split(&self, orp_config: &OrpConfig) -> (&'a str, &'a str, &'a str) {
+        let text = self.text;
+        let mut boundaries = text.grapheme_indices(true).map(|(index, _)| index).skip(self.orp_index(orp_config));
+        let center_start = boundaries.next().unwrap_or(text.len());
+        let center_end = boundaries.next().unwrap_or(text.len());
+        (&text[..center_start], &text[center_start..center_end], &text[center_end..])
+    }
+
     /// Uses max-wins strategy for multipliers (not cumulative), so a sentence-ending
     /// long word gets the sentence-end pause, not sentence-end × long-word.
-    pub fn display_duration_ms(&self, wpm: u32) -> u64 {
+    /// Stop words are shortened only when no pause applies to them.
+    /// Never shorter than `TimingConfig::min_word_ms`.
+    pub fn display_duration_ms(&self, wpm: u32, timing: &TimingConfig) -> u64 {
         let base_ms = 60_000.0 / wpm as f64;
         let mut multiplier = 1.0f64;
         
-        if self.text.chars().count() > 10 {
-            multiplier = multiplier.max(1.3);
+        if self.text.chars().count() > timing.long_word_threshold {
+            multiplier = multiplier.max(timing.long_word_multiplier as f64);
+        }
+        if timing.rare_word_enabled && self.is_rare() {
+            multiplier = multiplier.max(timing.rare_word_multiplier as f64);
+        }
+        let punctuated = self.punctuated_text();
+        if punctuated.ends_with(',') || punctuated.ends_with(';') {
+            multiplier = multiplier.max(timing.comma_multiplier as f64);
         }
-        if self.text.ends_with(',') || self.text.ends_with(';') {
-            multiplier = multiplier.max(2.0);
+        if self.is_line_end {
+            multiplier = multiplier.max(timing.line_end_multiplier as f64);
         }
-        if self.text.ends_with('.') || self.text.ends_with('?') || self.text.ends_with('!') {
-            multiplier = multiplier.max(3.0);
+        if self.is_sentence_end() {
+            multiplier = multiplier.max(timing.sentence_end_multiplier as f64);
         }
         if self.is_paragraph_end {
-            multiplier = multiplier.max(4.0);
+            multiplier = multiplier.max(timing.paragraph_end_multiplier as f64);
+        }
+        if multiplier == 1.0 && timing.stop_word_enabled && self.is_stop_word() {
+            multiplier = timing.stop_word_multiplier.clamp(STOP_WORD_MULTIPLIER_MIN, STOP_WORD_MULTIPLIER_MAX) as f64;
         }
         
-        (base_ms * multiplier) as u64
+        ((base_ms * multiplier).round() as u64).max(timing.min_word_ms)
+    }
+
+    /// A word of more than `RARE_WORD_MIN_LETTERS` letters that is not in
+    /// `COMMON_WORDS`, directly or after dropping a common inflection ("-s", "-ed",
+    /// "-ing", "-ly"). Inflections of short stems ("changes", "quickly") and words
+    /// with digits or other symbols inside are never rare.
+    pub fn is_rare(&self) -> bool {
+        let word = self.text.trim_matches(|c: char| !c.is_alphabetic());
+        if word.chars().count() <= RARE_WORD_MIN_LETTERS || !word.chars().all(char::is_alphabetic) {
+            return false;
+        }
+        // Runs for every word shown, so the lookups use stack buffers instead of allocating.
+        let mut lowercase_buffer = [0u8; COMMON_WORD_MAX_BYTES];
+        let word = ascii_lowercase_in(word, &mut lowercase_buffer);
+        let is_common = |stem: &str| COMMON_WORDS.contains(stem);
+        let is_common_with_e = |stem: &str| {
+            let mut buffer = [0u8; COMMON_WORD_MAX_BYTES];
+            let Some(with_e) = buffer.get_mut(..stem.len() + 1) else { return false };
+            with_e[..stem.len()].copy_from_slice(stem.as_bytes());
+            with_e[stem.len()] = b'e';
+            std::str::from_utf8(with_e).is_ok_and(is_common)
+        };
+        if is_common(word) { return false; }
+        let is_inflection_of_common = INFLECTION_SUFFIXES.iter().any(|suffix| {
+            word.strip_suffix(suffix).is_some_and(|stem| {
+                stem.chars().count() <= RARE_WORD_MIN_LETTERS || is_common(stem) || is_common_with_e(stem)
+            })
+        });
+        !is_inflection_of_common
+    }
+
+    /// One of the `STOP_WORDS`, ignoring case and surrounding quotes or brackets.
+    pub fn is_stop_word(&self) -> bool {
+        let word = self.punctuated_text().trim_start_matches(OPENING_MARKS);
+        STOP_WORDS.iter().any(|stop_word| word.eq_ignore_ascii_case(stop_word))
+    }
+
+    /// Text without trailing closing quotes and brackets, so `world."` ends in '.'.
+    fn punctuated_text(&self) -> &str {
+        self.text.trim_end_matches(CLOSING_MARKS)
+    }
+
+    /// Ends in '.', '?' or '!', except abbreviations ("Mr.", "e.g."), initials ("J.")
+    /// and dotted numbers ("3.14.", "192.168.1.1."). A whole number ("42.") does end one.
+    pub fn is_sentence_end(&self) -> bool {
+        let text = self.punctuated_text().trim_start_matches(OPENING_MARKS);
+        if text.ends_with('?') || text.ends_with('!') { return true; }
+        let Some(stem) = text.strip_suffix('.') else { return false };
+        let is_number = stem.chars().any(|c| c.is_ascii_digit())
+            && stem.chars().all(|c| c.is_ascii_digit() || c == '.' || c == ',');
+        if is_number { return !stem.contains('.'); }
+        let is_initial = stem.chars().count() == 1 && stem.chars().all(char::is_alphabetic);
+        !is_initial && !ABBREVIATIONS.iter().any(|abbreviation| text.eq_ignore_ascii_case(abbreviation))
+    }
+}
+
+/// Closing quotes and brackets that may follow a word's punctuation.
+const CLOSING_MARKS: &[char] = &['"', '\'', '”', '’', ')', ']', '»'];
+/// Opening counterparts, ignored when matching abbreviations like "(Mr.".
+const OPENING_MARKS: &[char] = &['"', '\'', '“', '‘', '(', '[', '«'];
+
+/// Abbreviations whose period does not end a sentence, matched case-insensitively.
+/// Ones that often close a sentence ("etc.", "No.") are left out.
+const ABBREVIATIONS: &[&str] = &[
+    "mr.", "mrs.", "ms.", "dr.", "prof.", "sr.", "jr.", "st.", "vs.", "cf.",
+    "e.g.", "i.e.", "u.s.", "u.k.", "fig.", "approx.",
+];
+
+/// Function words short enough to take in at a glance.
+const STOP_WORDS: &[&str] = &[
+    "a", "an", "the", "of", "to", "in", "on", "at", "by", "for", "from", "with",
+    "and", "or", "but", "as", "is", "are", "was", "were", "be", "it", "its",
+    "that", "this", "if", "so", "than", "then", "not", "no",
+];
+
+/// Words with at most this many letters are never treated as rare.
+const RARE_WORD_MIN_LETTERS: usize = 6;
+/// Suffixes dropped when looking a word up in `COMMON_WORDS`. A dropped final
+/// 'e' ("changed" → "chang" → "change") is tried too.
+const INFLECTION_SUFFIXES: &[&str] = &["s", "es", "d", "ed", "ing", "ly", "er", "est"];
+/// Longer than any entry in `COMMON_WORDS`, so longer words need no lookup.
+const COMMON_WORD_MAX_BYTES: usize = 32;
+/// Common English words longer than `RARE_WORD_MIN_LETTERS`, one per line,
+/// lowercase ASCII.
+static COMMON_WORDS: LazyLock<HashSet<&'static str>> = LazyLock::new(|| {
+    include_str!("../assets/common_words.txt").lines().collect()
+});
+
+/// `text` lowercased into `buffer` when it is ASCII and fits. Otherwise `text`
+/// itself, which cannot match lowercase ASCII entries of `COMMON_WORDS` either way.
+fn ascii_lowercase_in<'a>(text: &'a str, buffer: &'a mut [u8]) -> &'a str {
+    let Some(lowercase) = buffer.get_mut(..text.len()).filter(|_| text.is_ascii()) else { return text };
+    lowercase.copy_from_slice(text.as_bytes());
+    lowercase.make_ascii_lowercase();
+    std::str::from_utf8(lowercase).unwrap_or(text)
+}
+
+/// CJK punctuation, kana, Hangul and the Han ideograph blocks.
+fn is_cjk(c: char) -> bool {
+    matches!(c,
+        '\u{1100}'..='\u{11FF}'      // Hangul Jamo
+        | '\u{3000}'..='\u{30FF}'    // CJK punctuation, Hiragana, Katakana
+        | '\u{3130}'..='\u{318F}'    // Hangul Compatibility Jamo
+        | '\u{3400}'..='\u{4DBF}'    // CJK Extension A
+        | '\u{4E00}'..='\u{9FFF}'    // CJK Unified Ideographs
+        | '\u{AC00}'..='\u{D7AF}'    // Hangul Syllables
+        | '\u{F900}'..='\u{FAFF}'    // CJK Compatibility Ideographs
+        | '\u{FF00}'..='\u{FFEF}'    // Halfwidth and Fullwidth Forms
+        | '\u{20000}'..='\u{2FA1F}'  // CJK Extensions B+ and supplements
+    )
+}
+
+/// Compact word list: every word's text lives in one shared buffer, indexed by
+/// small fixed-size entries, instead of one heap allocation per word.
+#[derive(Clone, Default, PartialEq, Debug)]
+pub struct WordStore {
+    text: String,
+    entries: Vec<WordEntry>,
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+struct WordEntry {
+    start: u32,
+    len: u32,
+    flags: u8,
+}
+
+const FLAG_PARAGRAPH_END: u8 = 1;
+const FLAG_LINE_END: u8 = 1 << 1;
+
+impl WordStore {
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+    pub fn get(&self, index: usize) -> Option<WordRef<'_>> {
+        self.entries.get(index).map(|entry| self.view(entry))
+    }
+    pub fn last(&self) -> Option<WordRef<'_>> {
+        self.entries.last().map(|entry| self.view(entry))
+    }
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = WordRef<'_>> + ExactSizeIterator + '_ {
+        self.entries.iter().map(|entry| self.view(entry))
+    }
+    /// Fails once the shared buffer would outgrow the `u32` offsets.
+    pub fn push(&mut self, word: WordRef) -> Result<(), String> {
+        let too_large = || format!("Text over {} bytes is too large to open", u32::MAX);
+        let start = u32::try_from(self.text.len()).map_err(|_| too_large())?;
+        let len = u32::try_from(word.text.len()).ok()
+            .filter(|&len| start.checked_add(len).is_some())
+            .ok_or_else(too_large)?;
+        self.text.push_str(word.text);
+        let mut flags = 0;
+        if word.is_paragraph_end { flags |= FLAG_PARAGRAPH_END; }
+        if word.is_line_end { flags |= FLAG_LINE_END; }
+        self.entries.push(WordEntry { start, len, flags });
+        Ok(())
+    }
+    fn view(&self, entry: &WordEntry) -> WordRef<'_> {
+        let start = entry.start as usize;
+        WordRef {
+            text: &self.text[start..start + entry.len as usize],
+            is_paragraph_end: entry.flags & FLAG_PARAGRAPH_END != 0,
+            is_line_end: entry.flags & FLAG_LINE_END != 0,
+        }
+    }
+}
+impl TryFrom<Vec<Word>> for WordStore {
+    type Error = String;
+
+    fn try_from(words: Vec<Word>) -> Result<Self, String> {
+        let mut store = WordStore {
+            text: String::with_capacity(words.iter().map(|word| word.text.len()).sum()),
+            entries: Vec::with_capacity(words.len()),
+        };
+        for word in &words {
+            store.push(word.view())?;
+        }
+        Ok(store)
+    }
+}
+/// Serialized as a plain list of `Word`s, so word caches keep their format.
+impl Serialize for WordStore {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter())
+    }
+}
+impl<'de> Deserialize<'de> for WordStore {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct WordsVisitor;
+        impl<'de> serde::de::Visitor<'de> for WordsVisitor {
+            type Value = WordStore;
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                formatter.write_str("a list of words")
+            }
+            fn visit_seq<A: serde::de::SeqAccess<'de>>(self, mut seq: A) -> Result<WordStore, A::Error> {
+                let mut store = WordStore::default();
+                while let Some(word) = seq.next_element::<Word>()? {
+                    store.push(word.view()).map_err(serde::de::Error::custom)?;
+                }
+                store.text.shrink_to_fit();
+                store.entries.shrink_to_fit();
+                Ok(store)
+            }
+        }
+        deserializer.deserialize_seq(WordsVisitor)
     }
 }
 
-/// Chapter/section bookmark for future navigation UI.
-#[allow(dead_code)]
+/// Start of a chapter, page or heading, listed in the table of contents.
+/// Stored in the word cache alongside the words.
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Section {
     pub title: String,
     pub start_index: usize,
 }
 
+/// Title and author from the document's own metadata, when it has any.
+#[derive(Clone, Default, PartialEq, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BookMeta {
+    pub title: Option<String>,
+    pub author: Option<String>,
+}
+impl BookMeta {
+    pub fn is_empty(&self) -> bool {
+        self.title.is_none() && self.author.is_none()
+    }
+
+    /// "Title by Author", or whichever of the two is known.
+    pub fn describe(&self) -> Option<String> {
+        match (&self.title, &self.author) {
+            (Some(title), Some(author)) => Some(format!("{} by {}", title, author)),
+            (Some(title), None) => Some(title.clone()),
+            (None, Some(author)) => Some(format!("by {}", author)),
+            (None, None) => None,
+        }
+    }
+}
+
+/// Size summary of a parsed text. Stored with the tab so its length is known
+/// without loading the word cache.
+#[derive(Clone, Copy, Default, PartialEq, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TextStats {
+    pub word_count: usize,
+    /// Characters across all words, excluding whitespace.
+    pub char_count: usize,
+    /// Paragraphs, counting a trailing unterminated paragraph.
+    pub paragraph_count: usize,
+}
+impl TextStats {
+    pub fn from_words(words: &WordStore) -> Self {
+        let char_count = words.iter().map(|word| word.text.chars().count()).sum();
+        let paragraph_ends = words.iter().filter(|word| word.is_paragraph_end).count();
+        let has_open_paragraph = words.last().is_some_and(|word| !word.is_paragraph_end);
+        Self {
+            word_count: words.len(),
+            char_count,
+            paragraph_count: paragraph_ends + usize::from(has_open_paragraph),
+        }
+    }
+
+    /// Rough reading time at `wpm`, ignoring punctuation pauses.
+    pub fn estimated_reading_time(&self, wpm: u32) -> Duration {
+        Duration::from_millis(self.word_count as u64 * 60_000 / wpm.max(1) as u64)
+    }
+}
+
 pub struct ParseResult {
-    pub words: Vec<Word>,
-    #[allow(dead_code)]
+    pub words: WordStore,
     pub sections: Vec<Section>,
+    pub stats: TextStats,
+    pub meta: BookMeta,
 }
 impl ParseResult {
-    pub fn words_only(words: Vec<Word>) -> Self {
-        Self { words, sections: Vec::new() }
+    /// Fails when the text is too large for a `WordStore`.
+    pub fn words_only(words: Vec<Word>) -> Result<Self, String> {
+        let words = WordStore::try_from(words)?;
+        let stats = TextStats::from_words(&words);
+        Ok(Self { words, sections: Vec::new(), stats, meta: BookMeta::default() })
+    }
+
+    /// Name for a tab showing this text: the metadata title, or `fallback`
+    /// (usually the file name) when there is none.
+    pub fn tab_name(&self, fallback: &str) -> String {
+        self.meta.title.clone().unwrap_or_else(|| fallback.to_string())
+    }
+
+    /// Joins consecutive text parts (pages, chapters). Every part ends a paragraph;
+    /// a titled part that has words starts a `Section` at its first word.
+    fn from_parts<S: AsRef<str>>(parts: impl IntoIterator<Item = (Option<String>, S)>, options: &ParseOptions) -> Result<Self, String> {
+        let mut words = Vec::new();
+        let mut sections = Vec::new();
+        for (title, text) in parts {
+            let part_words = words_from_text(text.as_ref(), options);
+            if part_words.is_empty() { continue; }
+            if let Some(title) = title {
+                sections.push(Section { title, start_index: words.len() });
+            }
+            words.extend(part_words);
+            if let Some(last) = words.last_mut() {
+                last.is_paragraph_end = true;
+            }
+        }
+        Ok(Self { sections, ..Self::words_only(words)? })
+    }
+}
+
+/// User-selectable tokenization tweaks, applied by every parser through `words_from_text`.
+/// Stored per tab so reloads and re-parses reuse them.
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+#[serde(default = "ParseOptions::saved_default")]
+pub struct ParseOptions {
+    /// Splits "input/output"-style compounds into two display words.
+    pub split_compounds: bool,
+    /// Marks the last word of every line as `is_line_end` (poetry mode).
+    pub preserve_line_breaks: bool,
+    /// Drops the Project Gutenberg license text around the book in plain text
+    /// files. Files without the markers are left untouched.
+    pub trim_gutenberg: bool,
+    /// Drops page-number lines ("42", "- 7 -", "[12]") standing alone between blank lines.
+    pub skip_page_numbers: bool,
+    /// Breaks tokens longer than `max_word_graphemes` (URLs, identifiers) into
+    /// several display words.
+    pub split_long_words: bool,
+    pub max_word_graphemes: usize,
+}
+impl Default for ParseOptions {
+    fn default() -> Self {
+        Self {
+            split_compounds: false,
+            preserve_line_breaks: false,
+            trim_gutenberg: true,
+            skip_page_numbers: false,
+            split_long_words: true,
+            max_word_graphemes: 20,
+        }
+    }
+}
+impl ParseOptions {
+    /// Options for tabs saved before a field existed. Tweaks that default to on
+    /// for new tabs stay off here, so reloading an old tab keeps its word positions.
+    pub fn saved_default() -> Self {
+        Self {
+            trim_gutenberg: false,
+            split_long_words: false,
+            ..Self::default()
+        }
+    }
+}
+
+/// Lines shorter than this on average suggest verse.
+const VERSE_MAX_AVERAGE_LINE_CHARS: usize = 45;
+const VERSE_MIN_LINES: usize = 4;
+
+/// Heuristic for suggesting `ParseOptions::preserve_line_breaks`: several
+/// non-blank lines with a short average length.
+pub fn looks_like_verse(text: &str) -> bool {
+    let (line_count, char_count) = text.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .fold((0, 0), |(lines, chars), line| (lines + 1, chars + line.chars().count()));
+    line_count >= VERSE_MIN_LINES && char_count / line_count < VERSE_MAX_AVERAGE_LINE_CHARS
+}
+
+/// Start of the marker lines around the book in Project Gutenberg files, e.g.
+/// "*** START OF THE PROJECT GUTENBERG EBOOK MOBY DICK ***". Older files say "THIS".
+const GUTENBERG_START_MARKER: &str = "*** START OF TH";
+const GUTENBERG_END_MARKER: &str = "*** END OF TH";
+
+/// The text between the Project Gutenberg start and end markers, or `None`
+/// when there is no start marker. A missing end marker keeps the rest of the text.
+pub fn gutenberg_body(text: &str) -> Option<&str> {
+    let mut start = None;
+    let mut offset = 0;
+    for line in text.split_inclusive('\n') {
+        match start {
+            None if is_gutenberg_marker(line, GUTENBERG_START_MARKER) => start = Some(offset + line.len()),
+            Some(start) if is_gutenberg_marker(line, GUTENBERG_END_MARKER) => return Some(&text[start..offset]),
+            _ => {}
+        }
+        offset += line.len();
     }
+    start.map(|start| &text[start..])
+}
+
+fn is_gutenberg_marker(line: &str, marker: &str) -> bool {
+    let line = line.trim().to_ascii_uppercase();
+    line.starts_with(marker) && line.contains("PROJECT GUTENBERG")
 }
 
 /// Trait for parsing file content into words.
 pub trait TextParser: Send + Sync {
     /// Parse raw file bytes into words with optional section metadata.
-    fn parse(&self, data: &[u8]) -> Result<ParseResult, String>;
+    fn parse(&self, data: &[u8], options: &ParseOptions) -> Result<ParseResult, String>;
 }
 
+const COMPOUND_JOINERS: &[char] = &['/', '-', '–', '—'];
+const COMPOUND_MIN_LETTERS: usize = 4;
+pub const MAX_WORD_GRAPHEMES_MIN: usize = 8;
+pub const MAX_WORD_GRAPHEMES_MAX: usize = 60;
+/// Characters a long token may be broken after, besides `COMPOUND_JOINERS`.
+const LONG_WORD_BREAKS: &[char] = &['.', '?', '&', '=', '_', '\\', ':', '#'];
+/// Zero-width space, word joiner, byte order mark and soft hyphen: invisible
+/// characters that would otherwise end up inside words.
+const INVISIBLE_CHARS: &[char] = &['\u{200B}', '\u{2060}', '\u{FEFF}', '\u{00AD}'];
+/// Tokens made only of these are decoration (bullets, scene breaks), not words.
+const DECORATION_CHARS: &[char] = &['*', '•', '·', '◦', '▪'];
+/// Characters allowed around the number on a page-number line.
+const PAGE_NUMBER_FLANKS: &[char] = &['-', '–', '—', '[', ']', '(', ')', '|', ' '];
+const PAGE_NUMBER_MAX_DIGITS: usize = 4;
+/// First words of common hyphenated compounds. A word ending in one of these plus
+/// a hyphen keeps the hyphen when joined with the next word ("well-" "known").
+/// Stems that also start longer words ("high-" "est", "part-" "icular") are left out.
+const COMPOUND_FIRST_WORDS: &[&str] = &[
+    "well", "self", "half", "non", "much", "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+];
+/// Continuations that only extend the word before them, so even a compound
+/// stem drops its hyphen: "well-" "ness" becomes "wellness".
+const WORD_SUFFIXES: &[&str] = &["ness", "ish", "less", "ful", "ly", "er", "est", "ed", "ing", "s", "y"];
+
 /// Splits plain text into words with paragraph detection.
-/// Blank lines mark the last word before the gap as `is_paragraph_end`.
-fn words_from_text(text: &str) -> Vec<Word> {
+/// Blank lines mark the last word before the gap as `is_paragraph_end`; with
+/// `preserve_line_breaks`, every line's last word is also marked `is_line_end`.
+/// Words hyphenated across a line break are joined (see `join_hyphenated`).
+/// Lines holding only invisible characters or decoration ("* * *") count as blank,
+/// as do page-number lines with `skip_page_numbers`.
+fn words_from_text(text: &str, options: &ParseOptions) -> Vec<Word> {
     let mut words: Vec<Word> = Vec::new();
-    
-    for line in text.lines() {
-        let trimmed_line = line.trim();
-        
+    let text = strip_invisible_chars(text);
+    let lines: Vec<&str> = text.lines().collect();
+    // Whether the previous line added words, so a hyphen at its end may continue here.
+    let mut continues_line = false;
+
+    for (index, line) in lines.iter().enumerate() {
+        let line_words: Vec<Word> = line.split_whitespace()
+            .filter(|token| !token.chars().all(|c| DECORATION_CHARS.contains(&c)))
+            .map(Word::new)
+            .collect();
+        let is_page_number = options.skip_page_numbers && is_page_number_line(&lines, index);
+
         // Blank line = paragraph break. Mark the *last* word before the gap
         // so the reading pause happens at the end of the paragraph, not the start of the next.
-        if trimmed_line.is_empty() {
+        if line_words.is_empty() || is_page_number {
             if let Some(last) = words.last_mut() {
                 last.is_paragraph_end = true;
             }
+            continues_line = false;
             continue;
         }
-        
-        words.extend(trimmed_line.split_whitespace().map(Word::new));
+
+        let mut line_words = line_words.into_iter();
+        if continues_line
+            && let Some(last) = words.last_mut()
+            && let Some(joined) = line_words.as_slice().first().and_then(|first| join_hyphenated(&last.text, &first.text))
+        {
+            last.text = joined;
+            last.is_line_end = false;
+            line_words.next();
+        }
+        words.extend(line_words);
+        continues_line = true;
+        if options.preserve_line_breaks && let Some(last) = words.last_mut() {
+            last.is_line_end = true;
+        }
     }
     
+    if options.split_compounds {
+        words = split_compound_words(words);
+    }
+    if options.split_long_words {
+        words = split_long_words(words, options.max_word_graphemes.clamp(MAX_WORD_GRAPHEMES_MIN, MAX_WORD_GRAPHEMES_MAX));
+    }
     words
 }
 
+/// Joins a word ending in a hyphen with the next word when that starts
+/// lowercase: "extraor-" "dinary" becomes "extraordinary". Used for line breaks
+/// and the "Fix OCR hyphens" cleanup. Compounds keep their hyphen ("well-" "known"
+/// becomes "well-known"): the first part is in `COMPOUND_FIRST_WORDS` or already
+/// holds a hyphen ("state-of-the-"), and the continuation is not a `WORD_SUFFIXES` entry.
+/// `None` when the words should stay apart.
+pub fn join_hyphenated(first: &str, second: &str) -> Option<String> {
+    let stem = first.strip_suffix('-')?;
+    if !stem.chars().last().is_some_and(char::is_alphabetic) || !second.chars().next().is_some_and(char::is_lowercase) {
+        return None;
+    }
+    let first_word = stem.trim_start_matches(|c: char| !c.is_alphabetic()).to_lowercase();
+    let continuation = second.trim_end_matches(|c: char| !c.is_alphabetic());
+    let is_compound = (stem.contains('-') || COMPOUND_FIRST_WORDS.contains(&first_word.as_str()))
+        && !WORD_SUFFIXES.contains(&continuation);
+    Some(if is_compound { format!("{first}{second}") } else { format!("{stem}{second}") })
+}
+
+/// A line of up to `PAGE_NUMBER_MAX_DIGITS` digits, optionally flanked by dashes
+/// or brackets, with a blank line (or the start/end of the text) on both sides.
+/// Numbers inside a paragraph, like a list of figures, are kept.
+fn is_page_number_line(lines: &[&str], index: usize) -> bool {
+    let is_blank = |index: Option<usize>| index.and_then(|index| lines.get(index)).is_none_or(|line| line.trim().is_empty());
+    let number = lines[index].trim().trim_matches(PAGE_NUMBER_FLANKS);
+    (1..=PAGE_NUMBER_MAX_DIGITS).contains(&number.len())
+        && number.chars().all(|c| c.is_ascii_digit())
+        && is_blank(index.checked_sub(1))
+        && is_blank(Some(index + 1))
+}
+
+/// Removes `INVISIBLE_CHARS` and turns non-breaking spaces into plain ones.
+/// Borrows the text unchanged when there is nothing to clean.
+fn strip_invisible_chars(text: &str) -> std::borrow::Cow<'_, str> {
+    if !text.contains(|c| INVISIBLE_CHARS.contains(&c) || c == '\u{00A0}') {
+        return text.into();
+    }
+    text.chars()
+        .filter(|c| !INVISIBLE_CHARS.contains(c))
+        .map(|c| if c == '\u{00A0}' { ' ' } else { c })
+        .collect::<String>()
+        .into()
+}
+
+/// Rebuilds plain text from words: paragraph ends become blank lines and line ends
+/// newlines. Lets text without a source file be re-parsed with other options.
+pub fn text_from_words(words: &WordStore) -> String {
+    let mut text = String::new();
+    for word in words.iter() {
+        text.push_str(word.text);
+        text.push_str(if word.is_paragraph_end {
+            "\n\n"
+        } else if word.is_line_end {
+            "\n"
+        } else {
+            " "
+        });
+    }
+    text
+}
+
+/// Splits tokens like "input/output" or "read–write" at internal slashes and dashes
+/// when both sides have at least `COMPOUND_MIN_LETTERS` letters. The joiner stays on
+/// the first part; flags of the original word move to the last part.
+fn split_compound_words(words: Vec<Word>) -> Vec<Word> {
+    let mut result = Vec::with_capacity(words.len());
+    for word in words {
+        let mut rest = word.text.as_str();
+        let mut parts = Vec::new();
+        while let Some(split_at) = compound_split_point(rest) {
+            let (first, second) = rest.split_at(split_at);
+            parts.push(first.to_string());
+            rest = second;
+        }
+        if parts.is_empty() {
+            result.push(word);
+            continue;
+        }
+        let last = rest.to_string();
+        result.extend(parts.into_iter().map(Word::new));
+        result.push(Word { text: last, ..word });
+    }
+    result
+}
+
+/// Byte index just past the first joiner that splits `text` into two long-enough halves.
+fn compound_split_point(text: &str) -> Option<usize> {
+    let letter_count = |part: &str| part.chars().filter(|c| c.is_alphabetic()).count();
+    text.char_indices()
+        .filter(|(_, c)| COMPOUND_JOINERS.contains(c))
+        .map(|(index, c)| index + c.len_utf8())
+        .find(|&split_at| {
+            split_at < text.len()
+                && letter_count(&text[..split_at]) >= COMPOUND_MIN_LETTERS
+                && letter_count(&text[split_at..]) >= COMPOUND_MIN_LETTERS
+        })
+}
+
+/// Splits words longer than `max_graphemes` into parts of at most that length.
+/// Each part ends at the last natural break that fits (after a joiner or
+/// `LONG_WORD_BREAKS` character, or between camelCase humps), or is cut hard at
+/// `max_graphemes` when there is none. Flags of the original word move to the last part.
+fn split_long_words(words: Vec<Word>, max_graphemes: usize) -> Vec<Word> {
+    let mut result = Vec::with_capacity(words.len());
+    for word in words {
+        let graphemes: Vec<(usize, &str)> = word.text.grapheme_indices(true).collect();
+        if graphemes.len() <= max_graphemes {
+            result.push(word);
+            continue;
+        }
+        let is_break_before = |index: usize| {
+            let previous = graphemes[index - 1].1.chars().last().unwrap_or(' ');
+            let next = graphemes[index].1.chars().next().unwrap_or(' ');
+            COMPOUND_JOINERS.contains(&previous)
+                || LONG_WORD_BREAKS.contains(&previous)
+                || (previous.is_lowercase() && next.is_uppercase())
+        };
+        let mut start = 0;
+        let mut parts = Vec::new();
+        while graphemes.len() - start > max_graphemes {
+            let hard_end = start + max_graphemes;
+            let end = (start + 1..=hard_end).rev().find(|&index| is_break_before(index)).unwrap_or(hard_end);
+            parts.push(word.text[graphemes[start].0..graphemes[end].0].to_string());
+            start = end;
+        }
+        let last = word.text[graphemes[start].0..].to_string();
+        result.extend(parts.into_iter().map(Word::new));
+        result.push(Word { text: last, ..word });
+    }
+    result
+}
+
 pub struct TxtParser;
 impl TextParser for TxtParser {
-    fn parse(&self, data: &[u8]) -> Result<ParseResult, String> {
+    fn parse(&self, data: &[u8], options: &ParseOptions) -> Result<ParseResult, String> {
         let content = String::from_utf8_lossy(data);
-        Ok(ParseResult::words_only(words_from_text(&content)))
+        let text = match options.trim_gutenberg {
+            true => gutenberg_body(&content).unwrap_or(&content),
+            false => &content,
+        };
+        ParseResult::words_only(words_from_text(text, options))
     }
 }
 
-pub struct EpubParser;
-impl EpubParser {
-    /// Extracts plain text from XHTML content.
+/// Tags whose whole content is skipped: scripts, styles, and page chrome.
+const HTML_SKIPPED_TAGS: &[&[u8]] = &[b"style", b"script", b"head", b"nav", b"footer"];
+/// Block tags whose start and end produce a paragraph break.
+const HTML_BLOCK_TAGS: &[&[u8]] = &[
+    b"p", b"div", b"h1", b"h2", b"h3", b"h4", b"h5", b"h6", b"li", b"blockquote", b"tr",
+];
+/// Elements whose content is raw text in HTML: a `<` inside does not start a tag.
+const HTML_RAW_TEXT_TAGS: &[&str] = &["script", "style"];
+/// HTML elements that never have a closing tag, so their start tag opens nothing.
+const HTML_VOID_TAGS: &[&[u8]] = &[
+    b"area", b"base", b"br", b"col", b"embed", b"hr", b"img", b"input",
+    b"link", b"meta", b"param", b"source", b"track", b"wbr",
+];
+
+/// Saved web pages and other HTML/XHTML documents. The same extraction reads
+/// EPUB chapters.
+pub struct HtmlParser;
+impl HtmlParser {
+    /// Extracts plain text from HTML or XHTML content.
     /// Block elements (`<p>`, `<div>`, `<br>`, headings) produce paragraph breaks.
-    /// Inline elements are ignored; their text content is captured.
-    fn extract_text_from_xhtml(xhtml: &str) -> String {
-        let mut reader = XmlReader::from_str(xhtml);
+    /// Inline elements are ignored; their text content is captured. Entities
+    /// (`&amp;`, `&nbsp;`, `&#8217;`) are decoded.
+    fn extract_text(html: &str) -> String {
+        let html = Self::escape_for_xml(html);
+        let mut reader = XmlReader::from_str(&html);
+        // HTML is not XML: tolerate unclosed and mismatched tags.
+        reader.config_mut().check_end_names = false;
+        reader.config_mut().allow_unmatched_ends = true;
         let mut text = String::new();
-        let mut skip_depth: usize = 0;
+        // Skipped element's tag and how many of that tag are open inside it. Other
+        // tags inside are not counted, so unclosed ones cannot end the skip early or late.
+        let mut skipped: Option<(Vec<u8>, usize)> = None;
 
         loop {
             match reader.read_event() {
                 Ok(Event::Start(ref e)) => {
-                    let tag = e.name();
-                    let tag_bytes = tag.as_ref();
-                    if skip_depth > 0 {
-                        skip_depth += 1;
+                    let tag = e.local_name().as_ref().to_ascii_lowercase();
+                    if let Some((skipped_tag, depth)) = &mut skipped {
+                        if *skipped_tag == tag {
+                            *depth += 1;
+                        }
                         continue;
                     }
-                    match tag_bytes {
-                        b"style" | b"script" => { skip_depth = 1; }
-                        b"p" | b"div" | b"br" | b"h1" | b"h2" | b"h3"
-                        | b"h4" | b"h5" | b"h6" | b"li" | b"blockquote" | b"tr" => {
-                            text.push_str("\n\n");
-                        }
-                        _ => {}
+                    if tag.as_slice() == b"br" {
+                        text.push_str("\n\n");
+                    }
+                    if HTML_VOID_TAGS.contains(&tag.as_slice()) { continue; }
+                    if HTML_SKIPPED_TAGS.contains(&tag.as_slice()) {
+                        skipped = Some((tag, 1));
+                    } else if HTML_BLOCK_TAGS.contains(&tag.as_slice()) {
+                        text.push_str("\n\n");
                     }
                 }
                 Ok(Event::End(ref e)) => {
-                    if skip_depth > 0 {
-                        skip_depth -= 1;
+                    let tag = e.local_name().as_ref().to_ascii_lowercase();
+                    if let Some((skipped_tag, depth)) = &mut skipped {
+                        if *skipped_tag == tag {
+                            *depth -= 1;
+                            if *depth == 0 {
+                                skipped = None;
+                            }
+                        }
                         continue;
                     }
-                    let tag_name = e.name();
-                    let tag_bytes = tag_name.as_ref();
-                    match tag_bytes {
-                        b"p" | b"div" | b"h1" | b"h2" | b"h3"
-                        | b"h4" | b"h5" | b"h6" | b"li" | b"blockquote" | b"tr" => {
-                            text.push_str("\n\n");
-                        }
-                        _ => {}
+                    if HTML_VOID_TAGS.contains(&tag.as_slice()) { continue; }
+                    if HTML_BLOCK_TAGS.contains(&tag.as_slice()) {
+                        text.push_str("\n\n");
                     }
                 }
                 Ok(Event::Empty(ref e)) => {
-                    if skip_depth > 0 { continue; }
-                    if e.name().as_ref() == b"br" {
+                    if skipped.is_some() { continue; }
+                    if e.local_name().as_ref().eq_ignore_ascii_case(b"br") {
                         text.push_str("\n\n");
                     }
                 }
                 Ok(Event::Text(e)) => {
-                    if skip_depth > 0 { continue; }
+                    if skipped.is_some() { continue; }
                     if let Ok(decoded) = e.decode() {
                         text.push_str(&decoded);
                     }
                 }
+                Ok(Event::CData(e)) => {
+                    if skipped.is_some() { continue; }
+                    if let Ok(decoded) = e.decode() {
+                        text.push_str(&decoded);
+                    }
+                }
+                Ok(Event::GeneralRef(e)) => {
+                    if skipped.is_some() { continue; }
+                    push_entity(&mut text, &e);
+                }
                 Ok(Event::Eof) => break,
                 Err(_) => break,
                 _ => {}
@@ -224,26 +919,132 @@ impl EpubParser {
 
         text
     }
+
+    /// Rewrites what HTML allows but XML does not, so quick-xml reads on: the content
+    /// of `HTML_RAW_TEXT_TAGS` is dropped up to the literal closing tag (it may hold
+    /// `<`), and a `&` that does not start an entity becomes `&amp;`.
+    fn escape_for_xml(html: &str) -> String {
+        let mut escaped = String::with_capacity(html.len());
+        let mut rest = html;
+        while let Some(position) = rest.find(['<', '&']) {
+            escaped.push_str(&rest[..position]);
+            rest = &rest[position..];
+            if rest.starts_with('&') {
+                let name_len = rest[1..].find(|c: char| !(c.is_ascii_alphanumeric() || c == '#')).unwrap_or(rest.len() - 1);
+                let is_entity = name_len > 0 && rest[1 + name_len..].starts_with(';');
+                escaped.push_str(if is_entity { "&" } else { "&amp;" });
+                rest = &rest[1..];
+                continue;
+            }
+            let raw_tag = HTML_RAW_TEXT_TAGS.iter().find(|tag| {
+                rest.get(1..1 + tag.len()).is_some_and(|name| name.eq_ignore_ascii_case(tag))
+                    && rest[1 + tag.len()..].starts_with(|c: char| c == '>' || c == '/' || c.is_ascii_whitespace())
+            });
+            let Some(tag) = raw_tag else {
+                escaped.push('<');
+                rest = &rest[1..];
+                continue;
+            };
+            let Some(open_end) = rest.find('>') else { break };
+            escaped.push_str(&rest[..=open_end]);
+            rest = &rest[open_end + 1..];
+            let closing = format!("</{}", tag);
+            let content_len = find_ascii_case_insensitive(rest, &closing).unwrap_or(rest.len());
+            rest = &rest[content_len..];
+        }
+        escaped.push_str(rest);
+        escaped
+    }
+}
+
+/// Byte position of the first `needle` in `haystack`, ignoring ASCII case.
+fn find_ascii_case_insensitive(haystack: &str, needle: &str) -> Option<usize> {
+    haystack.as_bytes()
+        .windows(needle.len())
+        .position(|window| window.eq_ignore_ascii_case(needle.as_bytes()))
+}
+
+/// Appends the character an `&name;` or `&#number;` reference stands for.
+/// Unknown references are dropped.
+fn push_entity(text: &mut String, reference: &BytesRef) {
+    if let Ok(Some(character)) = reference.resolve_char_ref() {
+        text.push(character);
+    } else if let Ok(name) = reference.decode()
+        && let Some(resolved) = resolve_html5_entity(&name)
+    {
+        text.push_str(resolved);
+    }
+}
+
+impl TextParser for HtmlParser {
+    fn parse(&self, data: &[u8], options: &ParseOptions) -> Result<ParseResult, String> {
+        let html = String::from_utf8_lossy(data);
+        let words = words_from_text(&Self::extract_text(&html), options);
+        if words.is_empty() {
+            return Err("No readable text found in HTML".to_string());
+        }
+
+        ParseResult::words_only(words)
+    }
+}
+
+pub struct EpubParser;
+impl EpubParser {
+    /// Converts each chapter's XHTML to text, in spine order. On native the work
+    /// is split over scoped threads of its own, sized to the machine rather than
+    /// taken from Bevy's frame compute pool, so a large book neither waits on nor
+    /// stalls frame systems.
+    fn chapter_texts(chapter_sources: &[(Option<String>, String)]) -> Vec<String> {
+        let convert = |sources: &[(Option<String>, String)]| {
+            sources.iter().map(|(_, xhtml)| HtmlParser::extract_text(xhtml)).collect::<Vec<_>>()
+        };
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let threads = std::thread::available_parallelism().map_or(1, usize::from);
+            let chunk_size = chapter_sources.len().div_ceil(threads).max(1);
+            std::thread::scope(|scope| {
+                let handles: Vec<_> = chapter_sources.chunks(chunk_size)
+                    .map(|chunk| scope.spawn(move || convert(chunk)))
+                    .collect();
+                handles.into_iter()
+                    .flat_map(|handle| handle.join().unwrap_or_else(|panic| std::panic::resume_unwind(panic)))
+                    .collect()
+            })
+        }
+        #[cfg(target_arch = "wasm32")]
+        convert(chapter_sources)
+    }
 }
 impl TextParser for EpubParser {
-    fn parse(&self, data: &[u8]) -> Result<ParseResult, String> {
+    fn parse(&self, data: &[u8], options: &ParseOptions) -> Result<ParseResult, String> {
         let cursor = Cursor::new(data.to_vec());
         let epub = Epub::options()
             .strict(false)
             .read(cursor)
             .map_err(|e| format!("Failed to open EPUB: {}", e))?;
 
-        let mut full_text = String::new();
-        let mut reader = epub.reader();
+        // Navigation label per manifest entry. Nested entries pointing into the
+        // same file keep the outermost label.
+        let mut toc_labels: HashMap<String, String> = HashMap::new();
+        if let Some(root) = epub.toc().contents() {
+            for entry in root.children().flatten() {
+                let label = entry.label().trim();
+                if label.is_empty() { continue; }
+                if let Some(key) = entry.manifest_entry().and_then(|manifest_entry| manifest_entry.key()) {
+                    toc_labels.entry(key.to_string()).or_insert_with(|| label.to_string());
+                }
+            }
+        }
 
+        // Reading the archive is sequential; the XHTML of each chapter is then
+        // converted to text in parallel and reassembled in spine order.
+        let mut chapter_sources = Vec::new();
+        let mut reader = epub.reader();
         while let Some(result) = reader.read_next() {
             match result {
                 Ok(content) => {
-                    let chapter_text = Self::extract_text_from_xhtml(content.content());
-                    if !chapter_text.trim().is_empty() {
-                        full_text.push_str(&chapter_text);
-                        full_text.push_str("\n\n");
-                    }
+                    let label = content.manifest_entry().key().and_then(|key| toc_labels.get(key).cloned());
+                    chapter_sources.push((label, content.content().to_string()));
                 }
                 Err(e) => {
                     bevy::log::warn!("Skipping malformed EPUB chapter: {}", e);
@@ -251,12 +1052,163 @@ impl TextParser for EpubParser {
             }
         }
 
-        let words = words_from_text(&full_text);
-        if words.is_empty() {
+        let chapter_texts = Self::chapter_texts(&chapter_sources);
+        let mut chapters = Vec::new();
+        for ((label, _), chapter_text) in chapter_sources.into_iter().zip(chapter_texts) {
+            if chapter_text.trim().is_empty() { continue; }
+            let title = label.unwrap_or_else(|| format!("Chapter {}", chapters.len() + 1));
+            chapters.push((Some(title), chapter_text));
+        }
+
+        let parsed = ParseResult::from_parts(chapters, options)?;
+        if parsed.words.is_empty() {
             return Err("No readable text found in EPUB".to_string());
         }
 
-        Ok(ParseResult::words_only(words))
+        let non_blank = |value: &str| Some(value.split_whitespace().collect::<Vec<_>>().join(" ")).filter(|value| !value.is_empty());
+        let metadata = epub.metadata();
+        let meta = BookMeta {
+            title: metadata.title().and_then(|title| non_blank(title.value())),
+            author: metadata.creators().next().and_then(|creator| non_blank(creator.value())),
+        };
+        Ok(ParseResult { meta, ..parsed })
+    }
+}
+
+/// Word documents: a ZIP archive whose `word/document.xml` holds the body.
+/// Each `<w:p>` is a paragraph made of its `<w:t>` text runs.
+pub struct DocxParser;
+impl DocxParser {
+    fn extract_text(document_xml: &str) -> String {
+        let mut reader = XmlReader::from_str(document_xml);
+        let mut text = String::new();
+        let mut is_in_text_run = false;
+
+        loop {
+            match reader.read_event() {
+                Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"t" => is_in_text_run = true,
+                Ok(Event::End(ref e)) => match e.local_name().as_ref() {
+                    b"t" => is_in_text_run = false,
+                    b"p" => text.push_str("\n\n"),
+                    _ => {}
+                },
+                Ok(Event::Empty(ref e)) => match e.local_name().as_ref() {
+                    b"tab" => text.push(' '),
+                    b"br" | b"cr" => text.push('\n'),
+                    b"p" => text.push_str("\n\n"),
+                    _ => {}
+                },
+                Ok(Event::Text(e)) if is_in_text_run => {
+                    if let Ok(decoded) = e.decode() {
+                        text.push_str(&decoded);
+                    }
+                }
+                Ok(Event::GeneralRef(e)) if is_in_text_run => push_entity(&mut text, &e),
+                Ok(Event::Eof) => break,
+                Err(_) => break,
+                _ => {}
+            }
+        }
+
+        text
+    }
+}
+impl TextParser for DocxParser {
+    fn parse(&self, data: &[u8], options: &ParseOptions) -> Result<ParseResult, String> {
+        let mut archive = zip::ZipArchive::new(Cursor::new(data))
+            .map_err(|e| format!("Failed to open DOCX: {}", e))?;
+        let mut document_xml = String::new();
+        archive.by_name("word/document.xml")
+            .map_err(|e| format!("Failed to open DOCX: {}", e))?
+            .read_to_string(&mut document_xml)
+            .map_err(|e| format!("Failed to read DOCX: {}", e))?;
+
+        let words = words_from_text(&Self::extract_text(&document_xml), options);
+        if words.is_empty() {
+            return Err("No readable text found in DOCX".to_string());
+        }
+
+        ParseResult::words_only(words)
+    }
+}
+
+/// Extracts text page by page. Layout is not reconstructed: multi-column pages
+/// read in extraction order. Every page ends a paragraph and starts a `Section`.
+/// Native only: the web build rejects PDFs.
+pub struct PdfParser;
+impl TextParser for PdfParser {
+    #[cfg(target_arch = "wasm32")]
+    fn parse(&self, _data: &[u8], _options: &ParseOptions) -> Result<ParseResult, String> {
+        // pdf-extract panics on some files, and a panic cannot be caught on wasm.
+        Err("PDF files can only be opened in the desktop app".to_string())
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn parse(&self, data: &[u8], options: &ParseOptions) -> Result<ParseResult, String> {
+        // pdf-extract panics on some malformed or unusual files instead of returning an
+        // error. The closure only reads `data` and keeps no state past a panic, so
+        // nothing can be observed half-updated afterwards.
+        let extract = std::panic::AssertUnwindSafe(|| pdf_extract::extract_text_from_mem_by_pages(data));
+        let pages = std::panic::catch_unwind(extract)
+            .map_err(|_| "Failed to read PDF: unsupported or malformed file".to_string())?
+            .map_err(|e| format!("Failed to open PDF: {}", e))?;
+
+        let parts = pages.iter()
+            .enumerate()
+            .map(|(page_index, page_text)| (Some(format!("Page {}", page_index + 1)), page_text));
+        let parsed = ParseResult::from_parts(parts, options)?;
+        if parsed.words.is_empty() {
+            return Err("No readable text found in PDF (scanned pages need OCR)".to_string());
+        }
+
+        Ok(parsed)
+    }
+}
+
+/// Strips Markdown syntax (headings, emphasis, link targets, code fences, list
+/// bullets) and keeps the readable text. Each heading starts a `Section`.
+pub struct MarkdownParser;
+impl MarkdownParser {
+    /// Plain text split at headings, each part titled by its heading. Text before
+    /// the first heading forms an untitled part.
+    fn extract_parts(markdown: &str) -> Vec<(Option<String>, String)> {
+        let options = MarkdownOptions::ENABLE_TABLES | MarkdownOptions::ENABLE_STRIKETHROUGH;
+        let mut parts: Vec<(Option<String>, String)> = vec![(None, String::new())];
+        let mut heading: Option<String> = None;
+
+        for event in MarkdownReader::new_ext(markdown, options) {
+            let text = match &mut heading {
+                Some(heading_text) => heading_text,
+                None => &mut parts.last_mut().expect("parts start non-empty").1,
+            };
+            match event {
+                MarkdownEvent::Start(MarkdownTag::Heading { .. }) => {
+                    heading = Some(String::new());
+                }
+                MarkdownEvent::End(MarkdownTagEnd::Heading(_)) => {
+                    let title = heading.take().unwrap_or_default();
+                    let title = title.split_whitespace().collect::<Vec<_>>().join(" ");
+                    let body = format!("{}\n\n", title);
+                    parts.push((Some(title), body));
+                }
+                MarkdownEvent::Text(content) | MarkdownEvent::Code(content) => text.push_str(&content),
+                MarkdownEvent::SoftBreak | MarkdownEvent::HardBreak => text.push('\n'),
+                MarkdownEvent::End(MarkdownTagEnd::TableCell) => text.push(' '),
+                MarkdownEvent::End(
+                    MarkdownTagEnd::Paragraph | MarkdownTagEnd::Item | MarkdownTagEnd::CodeBlock
+                    | MarkdownTagEnd::BlockQuote(_) | MarkdownTagEnd::TableHead | MarkdownTagEnd::TableRow
+                )
+                | MarkdownEvent::Rule => text.push_str("\n\n"),
+                _ => {}
+            }
+        }
+        parts
+    }
+}
+impl TextParser for MarkdownParser {
+    fn parse(&self, data: &[u8], options: &ParseOptions) -> Result<ParseResult, String> {
+        let markdown = String::from_utf8_lossy(data);
+        ParseResult::from_parts(Self::extract_parts(&markdown), options)
     }
 }
 
@@ -267,19 +1219,267 @@ mod tests {
     #[test]
     fn display_duration_uses_max_wins_precedence() {
         let wpm = 600;
+        let timing = TimingConfig::default();
 
-        assert_eq!(Word::new("abcdefghijk").display_duration_ms(wpm), 130);
-        assert_eq!(Word::new("abcdefghijk,").display_duration_ms(wpm), 200);
-        assert_eq!(Word::new("abcdefghijk.").display_duration_ms(wpm), 300);
+        assert_eq!(Word::new("abcdefghijk").view().display_duration_ms(wpm, &timing), 130);
+        assert_eq!(Word::new("abcdefghijk,").view().display_duration_ms(wpm, &timing), 200);
+        assert_eq!(Word::new("abcdefghijk.").view().display_duration_ms(wpm, &timing), 300);
 
         let mut paragraph_end_word = Word::new("abcdefghijk.");
         paragraph_end_word.is_paragraph_end = true;
-        assert_eq!(paragraph_end_word.display_duration_ms(wpm), 400);
+        assert_eq!(paragraph_end_word.view().display_duration_ms(wpm, &timing), 400);
+    }
+
+    #[test]
+    fn rare_words_are_long_uncommon_words() {
+        let is_rare = |text: &str| Word::new(text).view().is_rare();
+
+        assert!(is_rare("perspicacious"));
+        assert!(is_rare("(Ephemeral),"));
+        assert!(!is_rare("important"));
+        assert!(!is_rare("Questions?"));
+        assert!(!is_rare("changed"));
+        assert!(!is_rare("quickly"));
+        assert!(!is_rare("zephyr"));
+        assert!(!is_rare("COVID-19"));
+        assert!(!is_rare("IMPORTANT"));
+        assert!(!is_rare("Changed,"));
+        assert!(is_rare("Perspicacité"));
+        assert!(is_rare("pneumonoultramicroscopicsilicovolcanoconiosis"));
+
+        let timing = TimingConfig { rare_word_enabled: true, rare_word_multiplier: 2.0, ..default() };
+        assert_eq!(Word::new("perspicacious").view().display_duration_ms(600, &timing), 200);
+        assert_eq!(Word::new("important").view().display_duration_ms(600, &TimingConfig::default()), 100);
+        assert_eq!(Word::new("perspicacious").view().display_duration_ms(600, &TimingConfig::default()), 130);
+    }
+
+    #[test]
+    fn stop_words_flash_faster_down_to_the_floor() {
+        let timing = TimingConfig { stop_word_enabled: true, stop_word_multiplier: 0.5, min_word_ms: 0, ..default() };
+        let duration = |text: &str, timing: &TimingConfig| Word::new(text).view().display_duration_ms(300, timing);
+
+        assert_eq!(duration("the", &timing), 100);
+        assert_eq!(duration("(The", &timing), 100);
+        assert_eq!(duration("cat", &timing), 200);
+        assert_eq!(duration("it.", &timing), 600);
+        assert_eq!(duration("the", &TimingConfig::default()), 200);
+
+        let floored = TimingConfig { min_word_ms: 150, ..timing.clone() };
+        assert_eq!(duration("of", &floored), 150);
+    }
+
+    #[test]
+    fn display_duration_follows_timing_config() {
+        let timing = TimingConfig {
+            long_word_threshold: 4,
+            long_word_multiplier: 1.5,
+            comma_multiplier: 1.2,
+            sentence_end_multiplier: 5.0,
+            ..default()
+        };
+        let duration = |text: &str| Word::new(text).view().display_duration_ms(600, &timing);
+
+        assert_eq!(duration("word"), 100);
+        assert_eq!(duration("words"), 150);
+        assert_eq!(duration("words,"), 150);
+        assert_eq!(duration("end."), 500);
+    }
+
+    #[test]
+    fn display_duration_respects_minimum_floor() {
+        let timing = TimingConfig { min_word_ms: 80, ..default() };
+
+        assert_eq!(Word::new("a").view().display_duration_ms(1000, &timing), 80);
+        assert_eq!(Word::new("go.").view().display_duration_ms(1000, &timing), 180);
+    }
+
+    #[test]
+    fn orp_split_keeps_grapheme_clusters_whole() {
+        assert_eq!(Word::new("cafe\u{301}").view().orp_split(&OrpConfig::default()), ("c", "a", "fe\u{301}"));
+        assert_eq!(Word::new("ae\u{301}").view().orp_split(&OrpConfig::default()), ("a", "e\u{301}", ""));
+        assert_eq!(Word::new("👍🏽word").view().orp_split(&OrpConfig::default()), ("👍🏽", "w", "ord"));
+        assert_eq!(Word::new("🇵🇱🇩🇪").view().orp_split(&OrpConfig::default()), ("🇵🇱", "🇩🇪", ""));
+        assert_eq!(Word::new("").view().orp_split(&OrpConfig::default()), ("", "", ""));
+    }
+
+    #[test]
+    fn is_rtl_follows_first_strong_character() {
+        assert!(Word::new("שלום").view().is_rtl());
+        assert!(Word::new("«مرحبا»").view().is_rtl());
+        assert!(Word::new("123עברית").view().is_rtl());
+        assert!(!Word::new("hello").view().is_rtl());
+        assert!(!Word::new("abcשלום").view().is_rtl());
+        assert!(!Word::new("42.").view().is_rtl());
+    }
+
+    #[test]
+    fn has_cjk_detects_han_kana_and_hangul() {
+        assert!(Word::new("漢字").view().has_cjk());
+        assert!(Word::new("「ひらがな」").view().has_cjk());
+        assert!(Word::new("カタカナ").view().has_cjk());
+        assert!(Word::new("한국어").view().has_cjk());
+        assert!(Word::new("Tokyo東京").view().has_cjk());
+        assert!(!Word::new("hello").view().has_cjk());
+        assert!(!Word::new("«مرحبا»").view().has_cjk());
+    }
+
+    #[test]
+    fn abbreviations_and_initials_do_not_end_sentences() {
+        let timing = TimingConfig::default();
+        let duration = |text: &str| Word::new(text).view().display_duration_ms(600, &timing);
+
+        assert_eq!(duration("Mr."), 100);
+        assert_eq!(duration("E.G."), 100);
+        assert_eq!(duration("e.g.,"), 200);
+        assert_eq!(duration("J."), 100);
+        assert_eq!(duration("end."), 300);
+        assert_eq!(duration("abbreviations."), 300);
+    }
+
+    #[test]
+    fn punctuation_inside_closing_quotes_and_brackets_counts() {
+        let timing = TimingConfig::default();
+        let duration = |text: &str| Word::new(text).view().display_duration_ms(600, &timing);
+
+        assert_eq!(duration("world.\""), 300);
+        assert_eq!(duration("(aside.)"), 300);
+        assert_eq!(duration("done!)"), 300);
+        assert_eq!(duration("yes”"), 100);
+        assert_eq!(duration("\"stop,\""), 200);
+        assert_eq!(duration("(Mr.)"), 100);
+    }
+
+    #[test]
+    fn decimal_numbers_do_not_end_sentences() {
+        let timing = TimingConfig::default();
+        let duration = |text: &str| Word::new(text).view().display_duration_ms(600, &timing);
+
+        assert_eq!(duration("3.14"), 100);
+        assert_eq!(duration("v2.0"), 100);
+        assert_eq!(duration("192.168.1.1"), 130);
+        assert_eq!(duration("3.14."), 100);
+        assert_eq!(duration("42."), 300);
+        assert_eq!(duration("1,000."), 300);
+    }
+
+    #[test]
+    fn line_end_pause_sits_between_comma_and_sentence_end() {
+        let timing = TimingConfig { line_end_multiplier: 2.5, ..default() };
+        let mut line_end_word = Word::new("moon");
+        line_end_word.is_line_end = true;
+        assert_eq!(line_end_word.view().display_duration_ms(600, &timing), 250);
+
+        line_end_word.text = "moon.".into();
+        assert_eq!(line_end_word.view().display_duration_ms(600, &timing), 300);
+    }
+
+    #[test]
+    fn hyphen_at_line_break_rejoins_split_words() {
+        let texts = |text| words_from_text(text, &ParseOptions::default()).into_iter().map(|word| word.text).collect::<Vec<_>>();
+
+        assert_eq!(texts("an extraor-\ndinary day"), vec!["an", "extraordinary", "day"]);
+        assert_eq!(texts("“Incom-\nprehensible,” he said"), vec!["“Incomprehensible,”", "he", "said"]);
+        let options = ParseOptions { preserve_line_breaks: true, ..default() };
+        let poem = words_from_text("a wonder-\nful\nnight", &options);
+        assert_eq!(poem[1].text, "wonderful");
+        assert!(poem[1].is_line_end);
+    }
+
+    #[test]
+    fn hyphen_at_line_break_keeps_compounds_and_names() {
+        let texts = |text| words_from_text(text, &ParseOptions::default()).into_iter().map(|word| word.text).collect::<Vec<_>>();
+
+        assert_eq!(texts("a well-known fact"), vec!["a", "well-known", "fact"]);
+        assert_eq!(texts("a well-\nknown fact"), vec!["a", "well-known", "fact"]);
+        assert_eq!(texts("a state-of-the-\nart lab"), vec!["a", "state-of-the-art", "lab"]);
+        assert_eq!(texts("the Anglo-\nSaxon age"), vec!["the", "Anglo-", "Saxon", "age"]);
+        assert_eq!(texts("wait -\nno"), vec!["wait", "-", "no"]);
+        assert_eq!(texts("a para-\n\ngraph"), vec!["a", "para-", "graph"]);
+    }
+
+    #[test]
+    fn hyphen_at_line_break_joins_suffixes_without_hyphen() {
+        let texts = |text| words_from_text(text, &ParseOptions::default()).into_iter().map(|word| word.text).collect::<Vec<_>>();
+
+        assert_eq!(texts("the high-\nest peak"), vec!["the", "highest", "peak"]);
+        assert_eq!(texts("in part-\nicular"), vec!["in", "particular"]);
+        assert_eq!(texts("their well-\nness."), vec!["their", "wellness."]);
+        assert_eq!(texts("a self-\nmade man"), vec!["a", "self-made", "man"]);
+    }
+
+    #[test]
+    fn skip_page_numbers_drops_isolated_number_lines_only() {
+        let options = ParseOptions { skip_page_numbers: true, ..default() };
+        let texts = |text| words_from_text(text, &options).into_iter().map(|word| word.text).collect::<Vec<_>>();
+
+        let text = "the end of a page\n\n42\n\nnext page\n\n- 7 -\n\nand\n\n— 34 —\n\nmore";
+        assert_eq!(texts(text), vec!["the", "end", "of", "a", "page", "next", "page", "and", "more"]);
+        assert_eq!(texts("I agree.\n\nYes, 42.\n\nDone"), vec!["I", "agree.", "Yes,", "42.", "Done"]);
+        assert_eq!(texts("Totals:\n12\n30\n\nDone"), vec!["Totals:", "12", "30", "Done"]);
+        assert_eq!(texts("Chapter\n\n12345\n\nPart 7"), vec!["Chapter", "12345", "Part", "7"]);
+
+        let kept = words_from_text(text, &ParseOptions::default());
+        assert!(kept.iter().any(|word| word.text == "42"));
+        let page_end = words_from_text("a page\n\n42\n\nnext", &options);
+        assert!(page_end[1].is_paragraph_end);
+    }
+
+    #[test]
+    fn preserve_line_breaks_marks_line_ends_and_keeps_stanza_breaks() {
+        let options = ParseOptions { preserve_line_breaks: true, ..default() };
+        let words = words_from_text("The fog comes\non little cat feet.\n\nIt sits looking", &options);
+
+        let line_ends: Vec<&str> = words.iter().filter(|word| word.is_line_end).map(|word| word.text.as_str()).collect();
+        let paragraph_ends: Vec<&str> = words.iter().filter(|word| word.is_paragraph_end).map(|word| word.text.as_str()).collect();
+        assert_eq!(line_ends, vec!["comes", "feet.", "looking"]);
+        assert_eq!(paragraph_ends, vec!["feet."]);
+
+        let prose = words_from_text("The fog comes\non little cat feet.", &ParseOptions::default());
+        assert!(prose.iter().all(|word| !word.is_line_end));
+    }
+
+    #[test]
+    fn word_without_line_end_field_still_deserializes() {
+        let words: Vec<Word> = ron::from_str(r#"[(text: "old", is_paragraph_end: true)]"#)
+            .expect("Old caches should load");
+
+        assert!(words[0].is_paragraph_end);
+        assert!(!words[0].is_line_end);
+    }
+
+    #[test]
+    fn word_store_keeps_the_word_cache_format() {
+        let mut words = vec![Word::new("Café"), Word::new("ends."), Word::new("")];
+        words[1].is_paragraph_end = true;
+        words[2].is_line_end = true;
+        let store = WordStore::try_from(words.clone()).unwrap();
+
+        let serialized = ron::to_string(&store).expect("Store should serialize");
+        assert_eq!(serialized, ron::to_string(&words).expect("Words should serialize"));
+
+        let restored: WordStore = ron::from_str(&serialized).expect("Store should deserialize");
+        assert_eq!(restored, store);
+        assert_eq!(restored.get(1), Some(words[1].view()));
+        assert_eq!(restored.iter().map(WordRef::to_word).collect::<Vec<_>>(), words);
+    }
+
+    #[test]
+    fn estimated_reading_time_scales_with_wpm() {
+        let stats = TextStats { word_count: 900, ..default() };
+        assert_eq!(stats.estimated_reading_time(300), Duration::from_secs(180));
+        assert_eq!(stats.estimated_reading_time(600), Duration::from_secs(90));
+        assert_eq!(TextStats::default().estimated_reading_time(0), Duration::ZERO);
+    }
+
+    #[test]
+    fn looks_like_verse_detects_short_lines() {
+        assert!(looks_like_verse("Roses are red,\nviolets are blue,\nsugar is sweet,\nand so are you."));
+        assert!(!looks_like_verse("A single long line of prose that goes on and on without any line breaks at all."));
     }
 
     #[test]
     fn words_from_text_marks_last_word_before_blank_line() {
-        let words = words_from_text("alpha beta\n\n gamma\n\n\n delta");
+        let words = words_from_text("alpha beta\n\n gamma\n\n\n delta", &ParseOptions::default());
 
         let texts: Vec<&str> = words.iter().map(|word| word.text.as_str()).collect();
         let paragraph_end_flags: Vec<bool> = words.iter().map(|word| word.is_paragraph_end).collect();
@@ -288,21 +1488,413 @@ mod tests {
         assert_eq!(paragraph_end_flags, vec![false, true, true, false]);
     }
 
+    #[test]
+    fn words_from_text_drops_invisible_chars_and_decoration() {
+        let text = "\u{FEFF}Hello\u{00A0}world, in\u{00AD}visible\u{200B}\n* * *\n\u{200B}\nThe end";
+        let words = words_from_text(text, &ParseOptions::default());
+
+        let texts: Vec<&str> = words.iter().map(|word| word.text.as_str()).collect();
+        let paragraph_end_flags: Vec<bool> = words.iter().map(|word| word.is_paragraph_end).collect();
+
+        assert_eq!(texts, vec!["Hello", "world,", "invisible", "The", "end"]);
+        assert_eq!(paragraph_end_flags, vec![false, false, true, false, false]);
+    }
+
+    #[test]
+    fn text_from_words_round_trips_through_the_parser() {
+        let options = ParseOptions { preserve_line_breaks: true, ..default() };
+        let words = words_from_text("Roses are red,\nviolets are blue.\n\nThe end.", &options);
+
+        let rebuilt = words_from_text(&text_from_words(&WordStore::try_from(words.clone()).unwrap()), &options);
+
+        assert_eq!(rebuilt, words);
+    }
+
     #[test]
     fn words_from_text_handles_leading_and_trailing_blank_lines() {
-        let words = words_from_text("\n\nalpha\n\n");
+        let words = words_from_text("\n\nalpha\n\n", &ParseOptions::default());
 
         assert_eq!(words.len(), 1);
         assert_eq!(words[0].text, "alpha");
         assert!(words[0].is_paragraph_end);
     }
 
+    #[test]
+    fn split_compounds_splits_long_halves_and_keeps_joiner() {
+        let options = ParseOptions { split_compounds: true, ..default() };
+        let words = words_from_text("input/output read–write cost-benefit\n\n", &options);
+
+        let texts: Vec<&str> = words.iter().map(|word| word.text.as_str()).collect();
+        let paragraph_end_flags: Vec<bool> = words.iter().map(|word| word.is_paragraph_end).collect();
+
+        assert_eq!(texts, vec!["input/", "output", "read–", "write", "cost-", "benefit"]);
+        assert_eq!(paragraph_end_flags, vec![false, false, false, false, false, true]);
+    }
+
+    #[test]
+    fn split_compounds_leaves_short_halves_and_disabled_option_alone() {
+        let options = ParseOptions { split_compounds: true, ..default() };
+        let words = words_from_text("e-mail and/or well- input/output", &options);
+        let texts: Vec<&str> = words.iter().map(|word| word.text.as_str()).collect();
+        assert_eq!(texts, vec!["e-mail", "and/or", "well-", "input/", "output"]);
+
+        let words = words_from_text("input/output", &ParseOptions::default());
+        assert_eq!(words.len(), 1);
+    }
+
+    #[test]
+    fn split_long_words_breaks_urls_at_natural_boundaries() {
+        let url = "https://example.com/articles/speed-reading-techniques?utm_source=newsletter";
+        let words = words_from_text(&format!("See {url}\n\nThen getElementByIdentifierName."), &ParseOptions::default());
+
+        let texts: Vec<&str> = words.iter().map(|word| word.text.as_str()).collect();
+        assert_eq!(texts, vec![
+            "See", "https://example.com/", "articles/speed-", "reading-techniques?", "utm_source=", "newsletter",
+            "Then", "getElementBy", "IdentifierName.",
+        ]);
+        assert!(words[5].is_paragraph_end);
+        assert!(texts.iter().all(|text| text.graphemes(true).count() <= 20));
+
+        let unbroken = words_from_text(&"x".repeat(45), &ParseOptions::default());
+        let lengths: Vec<usize> = unbroken.iter().map(|word| word.text.len()).collect();
+        assert_eq!(lengths, vec![20, 20, 5]);
+
+        let disabled = ParseOptions { split_long_words: false, ..default() };
+        assert_eq!(words_from_text(url, &disabled).len(), 1);
+    }
+
+    fn build_epub(chapters: &[&str]) -> Vec<u8> {
+        build_epub_with_toc(chapters, &[])
+    }
+
+    /// Builds a minimal EPUB 3 archive with one XHTML document per chapter body,
+    /// and a navigation document labelling the `(chapter index, label)` pairs.
+    fn build_epub_with_toc(chapters: &[&str], toc: &[(usize, &str)]) -> Vec<u8> {
+        use std::io::Write;
+        use zip::write::SimpleFileOptions;
+
+        let mut zip = zip::ZipWriter::new(Cursor::new(Vec::new()));
+        let options = SimpleFileOptions::default().compression_method(zip::CompressionMethod::Stored);
+        let mut add = |name: &str, contents: &str| {
+            zip.start_file(name, options).unwrap();
+            zip.write_all(contents.as_bytes()).unwrap();
+        };
+
+        add("mimetype", "application/epub+zip");
+        add("META-INF/container.xml", r#"<?xml version="1.0"?>
+<container version="1.0" xmlns="urn:oasis:names:tc:opendocument:xmlns:container">
+  <rootfiles><rootfile full-path="OEBPS/content.opf" media-type="application/oebps-package+xml"/></rootfiles>
+</container>"#);
+        let manifest: String = (0..chapters.len())
+            .map(|index| format!(r#"<item id="c{0}" href="c{0}.xhtml" media-type="application/xhtml+xml"/>"#, index))
+            .chain(std::iter::once(r#"<item id="nav" href="nav.xhtml" media-type="application/xhtml+xml" properties="nav"/>"#.to_string()))
+            .collect();
+        let spine: String = (0..chapters.len())
+            .map(|index| format!(r#"<itemref idref="c{}"/>"#, index))
+            .collect();
+        add("OEBPS/content.opf", &format!(r#"<?xml version="1.0"?>
+<package xmlns="http://www.idpf.org/2007/opf" version="3.0" unique-identifier="id">
+  <metadata xmlns:dc="http://purl.org/dc/elements/1.1/">
+    <dc:identifier id="id">test-book</dc:identifier><dc:title>Test Book</dc:title><dc:creator>Jane  Doe</dc:creator><dc:language>en</dc:language>
+  </metadata>
+  <manifest>{}</manifest>
+  <spine>{}</spine>
+</package>"#, manifest, spine));
+        let toc_items: String = toc.iter()
+            .map(|(index, label)| format!(r#"<li><a href="c{}.xhtml">{}</a></li>"#, index, label))
+            .collect();
+        add("OEBPS/nav.xhtml", &format!(
+            r#"<?xml version="1.0"?><html xmlns="http://www.w3.org/1999/xhtml" xmlns:epub="http://www.idpf.org/2007/ops"><body><nav epub:type="toc"><ol>{}</ol></nav></body></html>"#,
+            toc_items,
+        ));
+        for (index, body) in chapters.iter().enumerate() {
+            add(&format!("OEBPS/c{}.xhtml", index), &format!(
+                r#"<?xml version="1.0"?><html xmlns="http://www.w3.org/1999/xhtml"><body>{}</body></html>"#,
+                body,
+            ));
+        }
+        zip.finish().unwrap().into_inner()
+    }
+
+    fn build_docx(document_xml: &str) -> Vec<u8> {
+        use std::io::Write;
+        use zip::write::SimpleFileOptions;
+
+        let mut zip = zip::ZipWriter::new(Cursor::new(Vec::new()));
+        zip.start_file("word/document.xml", SimpleFileOptions::default()).unwrap();
+        zip.write_all(document_xml.as_bytes()).unwrap();
+        zip.finish().unwrap().into_inner()
+    }
+
+    #[test]
+    fn docx_parser_joins_runs_into_paragraphs() {
+        let docx = build_docx(r#"<?xml version="1.0" encoding="UTF-8"?>
+<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main"><w:body>
+  <w:p><w:pPr><w:pStyle w:val="Title"/></w:pPr><w:r><w:t>Ti</w:t></w:r><w:r><w:rPr><w:b/></w:rPr><w:t>tle</w:t></w:r></w:p>
+  <w:p/>
+  <w:p><w:r><w:t xml:space="preserve">Fish &amp; </w:t></w:r><w:r><w:t>chips.</w:t><w:tab/><w:t>Done</w:t></w:r></w:p>
+</w:body></w:document>"#);
+
+        let parsed = DocxParser.parse(&docx, &ParseOptions::default()).unwrap();
+
+        let texts: Vec<&str> = parsed.words.iter().map(|word| word.text).collect();
+        assert_eq!(texts, vec!["Title", "Fish", "&", "chips.", "Done"]);
+        assert!(parsed.words.get(0).unwrap().is_paragraph_end);
+        assert_eq!(parsed.stats.paragraph_count, 2);
+    }
+
+    #[test]
+    fn docx_parser_rejects_empty_bodies_and_non_docx_archives() {
+        let empty = build_docx(r#"<w:document xmlns:w="urn:w"><w:body><w:p/></w:body></w:document>"#);
+        let error = DocxParser.parse(&empty, &ParseOptions::default()).err();
+        assert_eq!(error.as_deref(), Some("No readable text found in DOCX"));
+
+        let error = DocxParser.parse(b"plain text", &ParseOptions::default()).err();
+        assert!(error.is_some_and(|error| error.starts_with("Failed to open DOCX")));
+    }
+
+    #[test]
+    fn txt_parser_trims_gutenberg_boilerplate_when_enabled() {
+        let file = "The Project Gutenberg eBook of Tale\r\nLicense text.\r\n\r\n\
+            *** START OF THE PROJECT GUTENBERG EBOOK TALE ***\r\nOnce upon a time.\r\n\
+            *** END OF THE PROJECT GUTENBERG EBOOK TALE ***\r\nMore license text.\r\n";
+        let parse = |options: &ParseOptions| TxtParser.parse(file.as_bytes(), options).unwrap().words;
+
+        let trimmed = parse(&ParseOptions::default());
+        assert_eq!(trimmed.iter().map(|word| word.text).collect::<Vec<_>>(), ["Once", "upon", "a", "time."]);
+        let untrimmed = parse(&ParseOptions { trim_gutenberg: false, ..default() });
+        assert_eq!(untrimmed.get(0).map(|word| word.text), Some("The"));
+
+        assert_eq!(gutenberg_body("*** start of this project gutenberg ebook x ***\nBody"), Some("Body"));
+        assert_eq!(gutenberg_body("*** START OF THE BOOK ***\nBody"), None);
+    }
+
+    #[test]
+    fn saved_parse_options_without_new_fields_keep_them_off() {
+        let saved: ParseOptions = ron::from_str("(split_compounds: true)").unwrap();
+
+        assert!(saved.split_compounds);
+        assert!(!saved.trim_gutenberg);
+        assert!(!saved.split_long_words);
+        assert!(ParseOptions::default().trim_gutenberg);
+        assert!(ParseOptions::default().split_long_words);
+    }
+
+    #[test]
+    fn txt_parser_stats_match_words() {
+        let parsed = TxtParser.parse("One two three.\n\nFour five\nsix.\n\n\nSeven".as_bytes(), &ParseOptions::default()).unwrap();
+
+        assert_eq!(parsed.stats, TextStats::from_words(&parsed.words));
+        assert_eq!(parsed.stats.word_count, 7);
+        assert_eq!(parsed.stats.char_count, 29);
+        assert_eq!(parsed.stats.paragraph_count, 3);
+    }
+
+    #[test]
+    fn epub_parser_stats_match_words() {
+        let epub = build_epub(&["<h1>Chapter One</h1><p>It was a dark night.</p>", "<p>The end.</p>"]);
+        let parsed = EpubParser.parse(&epub, &ParseOptions::default()).unwrap();
+
+        let texts: Vec<&str> = parsed.words.iter().map(|word| word.text).collect();
+        assert_eq!(texts, vec!["Chapter", "One", "It", "was", "a", "dark", "night.", "The", "end."]);
+        assert_eq!(parsed.stats, TextStats::from_words(&parsed.words));
+        assert_eq!(parsed.stats.word_count, 9);
+        assert_eq!(parsed.stats.paragraph_count, 3);
+    }
+
+    #[test]
+    fn epub_parser_reads_title_and_author() {
+        let parsed = EpubParser.parse(&build_epub(&["<p>Hello.</p>"]), &ParseOptions::default()).unwrap();
+
+        assert_eq!(parsed.meta, BookMeta { title: Some("Test Book".to_string()), author: Some("Jane Doe".to_string()) });
+        assert_eq!(parsed.tab_name("urn-uuid-1234"), "Test Book");
+        assert_eq!(parsed.meta.describe().as_deref(), Some("Test Book by Jane Doe"));
+
+        let plain = TxtParser.parse(b"Hello.", &ParseOptions::default()).unwrap();
+        assert!(plain.meta.is_empty());
+        assert_eq!(plain.tab_name("notes"), "notes");
+    }
+
+    #[test]
+    fn epub_parser_starts_a_section_per_chapter() {
+        let epub = build_epub_with_toc(&["<p>One two.</p>", "<p>Three.</p>"], &[(0, "Opening")]);
+        let parsed = EpubParser.parse(&epub, &ParseOptions::default()).unwrap();
+
+        let sections: Vec<(&str, usize)> = parsed.sections.iter()
+            .map(|section| (section.title.as_str(), section.start_index))
+            .collect();
+        assert_eq!(sections, vec![("Opening", 0), ("Chapter 2", 2)]);
+    }
+
+    /// Minimal PDF with one Helvetica text line per page.
+    fn build_pdf(pages: &[&str]) -> Vec<u8> {
+        use pdf_extract::content::{Content, Operation};
+        use pdf_extract::{dictionary, Document, Object, Stream};
+
+        let mut doc = Document::with_version("1.5");
+        let pages_id = doc.new_object_id();
+        let font_id = doc.add_object(dictionary! {
+            "Type" => "Font",
+            "Subtype" => "Type1",
+            "BaseFont" => "Helvetica",
+            "Encoding" => "WinAnsiEncoding",
+        });
+        let resources_id = doc.add_object(dictionary! {
+            "Font" => dictionary! { "F1" => font_id },
+        });
+        let page_ids: Vec<Object> = pages.iter().map(|&line| {
+            let content = Content {
+                operations: vec![
+                    Operation::new("BT", vec![]),
+                    Operation::new("Tf", vec!["F1".into(), 12.into()]),
+                    Operation::new("Td", vec![72.into(), 700.into()]),
+                    Operation::new("Tj", vec![Object::string_literal(line)]),
+                    Operation::new("ET", vec![]),
+                ],
+            };
+            let content_id = doc.add_object(Stream::new(dictionary! {}, content.encode().unwrap()));
+            doc.add_object(dictionary! {
+                "Type" => "Page",
+                "Parent" => pages_id,
+                "Contents" => content_id,
+            }).into()
+        }).collect();
+        doc.objects.insert(pages_id, Object::Dictionary(dictionary! {
+            "Type" => "Pages",
+            "Count" => page_ids.len() as i64,
+            "Kids" => page_ids,
+            "Resources" => resources_id,
+            "MediaBox" => vec![0.into(), 0.into(), 595.into(), 842.into()],
+        }));
+        let catalog_id = doc.add_object(dictionary! {
+            "Type" => "Catalog",
+            "Pages" => pages_id,
+        });
+        doc.trailer.set("Root", catalog_id);
+
+        let mut bytes = Vec::new();
+        doc.save_to(&mut bytes).unwrap();
+        bytes
+    }
+
+    #[test]
+    fn pdf_parser_breaks_paragraphs_and_sections_per_page() {
+        let pdf = build_pdf(&["First page text.", "", "Second page"]);
+
+        let parsed = PdfParser.parse(&pdf, &ParseOptions::default()).unwrap();
+
+        let texts: Vec<&str> = parsed.words.iter().map(|word| word.text).collect();
+        assert_eq!(texts, vec!["First", "page", "text.", "Second", "page"]);
+        assert!(parsed.words.get(2).unwrap().is_paragraph_end);
+        let sections: Vec<(&str, usize)> = parsed.sections.iter()
+            .map(|section| (section.title.as_str(), section.start_index))
+            .collect();
+        assert_eq!(sections, vec![("Page 1", 0), ("Page 3", 3)]);
+    }
+
+    #[test]
+    fn pdf_parser_rejects_invalid_and_empty_files() {
+        let invalid = PdfParser.parse(b"not a pdf", &ParseOptions::default()).err();
+        assert!(invalid.is_some_and(|error| error.starts_with("Failed")));
+
+        let blank = PdfParser.parse(&build_pdf(&[""]), &ParseOptions::default()).err();
+        assert!(blank.is_some_and(|error| error.contains("No readable text")));
+    }
+
+    #[test]
+    fn html_parser_decodes_entities_and_skips_page_chrome() {
+        let html = "<!DOCTYPE html><html><HEAD><meta charset=\"utf-8\"><title>Site</title></HEAD><body>\
+            <nav><a href=\"/\">Home</a></nav>\
+            <p>AT&amp;T&nbsp;said &#8220;hi&#x201D;<br>bye</p>\
+            <footer>Copyright</footer></body></html>";
+
+        let parsed = HtmlParser.parse(html.as_bytes(), &ParseOptions::default()).unwrap();
+
+        let texts: Vec<&str> = parsed.words.iter().map(|word| word.text).collect();
+        assert_eq!(texts, vec!["AT&T", "said", "\u{201C}hi\u{201D}", "bye"]);
+        assert!(parsed.words.get(2).unwrap().is_paragraph_end);
+    }
+
+    #[test]
+    fn html_parser_skips_head_with_unclosed_void_tags() {
+        let html = "<head><meta charset=utf-8><link rel=stylesheet href=a.css></head><body>text</body>";
+
+        let parsed = HtmlParser.parse(html.as_bytes(), &ParseOptions::default()).unwrap();
+
+        let texts: Vec<&str> = parsed.words.iter().map(|word| word.text).collect();
+        assert_eq!(texts, vec!["text"]);
+    }
+
+    #[test]
+    fn html_parser_reads_scripts_as_raw_text() {
+        let html = "<script>for(i=0;i<n;i++){x()}</script><STYLE>a<b{}</Style ><p>Story one</p>";
+
+        let parsed = HtmlParser.parse(html.as_bytes(), &ParseOptions::default()).unwrap();
+
+        let texts: Vec<&str> = parsed.words.iter().map(|word| word.text).collect();
+        assert_eq!(texts, vec!["Story", "one"]);
+    }
+
+    #[test]
+    fn html_parser_keeps_bare_ampersands_as_text() {
+        let html = "<p>Tom & Jerry &amp; R&D</p><p>Chapter two&hellip;</p>";
+
+        let parsed = HtmlParser.parse(html.as_bytes(), &ParseOptions::default()).unwrap();
+
+        let texts: Vec<&str> = parsed.words.iter().map(|word| word.text).collect();
+        assert_eq!(texts, vec!["Tom", "&", "Jerry", "&", "R&D", "Chapter", "two\u{2026}"]);
+    }
+
+    #[test]
+    fn html_parser_ends_the_skip_at_the_skipped_tag_only() {
+        let html = "<nav><ul><li>Home<li>About</ul><nav>Inner</nav></nav><p>Story</p><footer><p>Copyright</footer>";
+
+        let parsed = HtmlParser.parse(html.as_bytes(), &ParseOptions::default()).unwrap();
+
+        let texts: Vec<&str> = parsed.words.iter().map(|word| word.text).collect();
+        assert_eq!(texts, vec!["Story"]);
+    }
+
+    #[test]
+    fn markdown_parser_strips_syntax_and_keeps_text() {
+        let markdown = "Intro *text* with a [link](https://example.com) and `code()`.\n\n\
+            - first **item**\n- second\n\n\
+            ```rust\nlet x = 1;\n```\n";
+
+        let parsed = MarkdownParser.parse(markdown.as_bytes(), &ParseOptions::default()).unwrap();
+
+        let texts: Vec<&str> = parsed.words.iter().map(|word| word.text).collect();
+        assert_eq!(texts, vec![
+            "Intro", "text", "with", "a", "link", "and", "code().",
+            "first", "item", "second",
+            "let", "x", "=", "1;",
+        ]);
+        assert!(parsed.words.get(6).unwrap().is_paragraph_end);
+        assert!(parsed.words.get(8).unwrap().is_paragraph_end);
+        assert!(parsed.sections.is_empty());
+    }
+
+    #[test]
+    fn markdown_headings_start_sections() {
+        let markdown = "Preface.\n\n# Chapter *One*\n\nIt begins.\n\n## Part 2\nMore.";
+
+        let parsed = MarkdownParser.parse(markdown.as_bytes(), &ParseOptions::default()).unwrap();
+
+        let sections: Vec<(&str, usize)> = parsed.sections.iter()
+            .map(|section| (section.title.as_str(), section.start_index))
+            .collect();
+        assert_eq!(sections, vec![("Chapter One", 1), ("Part 2", 5)]);
+        assert_eq!(parsed.words.get(1).unwrap().text, "Chapter");
+        assert!(parsed.words.get(2).unwrap().is_paragraph_end);
+    }
+
     #[test]
     fn file_parsers_lookup_is_case_insensitive() {
         let parsers = FileParsers::new();
 
         assert!(parsers.get_for_extension("TXT").is_some());
         assert!(parsers.get_for_path(Path::new("book.EPUB")).is_some());
-        assert!(parsers.get_for_extension("pdf").is_none());
+        assert!(parsers.get_for_path(Path::new("paper.Pdf")).is_some());
+        assert!(parsers.get_for_extension("xyz").is_none());
     }
 }
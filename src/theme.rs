@@ -0,0 +1,187 @@
+//! Light/dark color theme and the reader background.
+//!
+//! `Theme` is the user's choice, persisted in `ProgramState`. egui resolves it
+//! every frame (following the OS for `Theme::System`), and the result is kept in
+//! `AppliedTheme`, which drives the window clear color and the ORP text color.
+//! While a reader tab is active, `ReaderBackground` can replace and dim the
+//! clear color; the word text then picks whichever text color contrasts with it.
+
+use bevy::prelude::*;
+use bevy_egui::{EguiContexts, EguiPrimaryContextPass, egui};
+use serde::{Deserialize, Serialize};
+
+use crate::tabs::{ActiveTab, ReaderTab};
+
+pub struct ThemePlugin;
+impl Plugin for ThemePlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<Theme>()
+            .init_resource::<AppliedTheme>()
+            .init_resource::<ReaderBackground>()
+            .add_systems(EguiPrimaryContextPass, Theme::apply)
+            .add_systems(Update, AppliedTheme::follow_reader_background)
+            .add_systems(Update, AppliedTheme::set_clear_color.run_if(resource_changed::<AppliedTheme>))
+            ;
+    }
+}
+
+/// Bevy's default clear color, kept for the dark theme.
+const CLEAR_COLOR_DARK: Color = Color::srgb_u8(43, 44, 47);
+const CLEAR_COLOR_LIGHT: Color = Color::srgb_u8(245, 245, 240);
+const TEXT_COLOR_DARK: Color = Color::WHITE;
+const TEXT_COLOR_LIGHT: Color = Color::srgb_u8(30, 30, 30);
+pub const READER_DIM_MAX: f32 = 0.9;
+/// Linear luminance above which a background takes dark text.
+const LIGHT_BACKGROUND_LUMINANCE: f32 = 0.18;
+
+/// Color scheme picked by the user. Serialized to disk as part of `ProgramState`.
+#[derive(Resource, Clone, Copy, PartialEq, Eq, Default, Debug, Serialize, Deserialize)]
+pub enum Theme {
+    #[default]
+    Dark,
+    Light,
+    /// Follows the operating system's preference.
+    System,
+}
+impl Theme {
+    pub const ALL: [Theme; 3] = [Theme::Dark, Theme::Light, Theme::System];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Theme::Dark => "Dark",
+            Theme::Light => "Light",
+            Theme::System => "System",
+        }
+    }
+
+    fn preference(self) -> egui::ThemePreference {
+        match self {
+            Theme::Dark => egui::ThemePreference::Dark,
+            Theme::Light => egui::ThemePreference::Light,
+            Theme::System => egui::ThemePreference::System,
+        }
+    }
+
+    /// Hands the choice to egui and records what it resolved to.
+    fn apply(
+        mut contexts: EguiContexts,
+        theme: Res<Theme>,
+        mut applied_theme: ResMut<AppliedTheme>,
+    ) {
+        let Ok(ctx) = contexts.ctx_mut() else { return };
+        if theme.is_changed() {
+            ctx.set_theme(theme.preference());
+        }
+        let is_light = ctx.theme() == egui::Theme::Light;
+        if applied_theme.is_light != is_light {
+            applied_theme.is_light = is_light;
+        }
+    }
+}
+
+/// Background behind the reader, applied while a reader tab is active.
+/// Serialized to disk as part of `ProgramState`.
+#[derive(Resource, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ReaderBackground {
+    /// Use `color` instead of the theme's background.
+    pub is_custom: bool,
+    /// sRGB.
+    pub color: [f32; 3],
+    /// How much darker than the chosen color, 0.0 to `READER_DIM_MAX`.
+    pub dim: f32,
+}
+impl Default for ReaderBackground {
+    fn default() -> Self {
+        Self {
+            is_custom: false,
+            color: [0.1, 0.1, 0.12],
+            dim: 0.0,
+        }
+    }
+}
+impl ReaderBackground {
+    /// The clear color behind a reader tab, or `None` when the theme's is used unchanged.
+    fn resolve(&self, is_light: bool) -> Option<Color> {
+        let dim = self.dim.clamp(0.0, READER_DIM_MAX);
+        if !self.is_custom && dim == 0.0 { return None; }
+        let [red, green, blue] = self.color;
+        let base = if self.is_custom {
+            Srgba::new(red, green, blue, 1.0)
+        } else {
+            theme_clear_color(is_light).to_srgba()
+        };
+        let brightness = 1.0 - dim;
+        Some(Color::srgb(base.red * brightness, base.green * brightness, base.blue * brightness))
+    }
+}
+
+/// The theme `Theme` resolved to. Only changes when the colors in use do.
+#[derive(Resource, Default)]
+pub struct AppliedTheme {
+    pub is_light: bool,
+    /// Replaces the theme's clear color while a reader tab is active.
+    pub reader_background: Option<Color>,
+}
+impl AppliedTheme {
+    /// Color of the reader's word text, apart from the highlighted letter.
+    pub fn text_color(&self) -> Color {
+        let is_light_background = match self.reader_background {
+            Some(background) => is_light_color(background),
+            None => self.is_light,
+        };
+        if is_light_background { TEXT_COLOR_LIGHT } else { TEXT_COLOR_DARK }
+    }
+
+    #[allow(clippy::type_complexity)]
+    fn follow_reader_background(
+        reader_background: Res<ReaderBackground>,
+        mut applied_theme: ResMut<AppliedTheme>,
+        active_reader: Option<Single<(), (With<ActiveTab>, With<ReaderTab>)>>,
+    ) {
+        let background = active_reader.and_then(|_| reader_background.resolve(applied_theme.is_light));
+        if applied_theme.reader_background != background {
+            applied_theme.reader_background = background;
+        }
+    }
+
+    fn set_clear_color(
+        applied_theme: Res<AppliedTheme>,
+        mut clear_color: ResMut<ClearColor>,
+    ) {
+        clear_color.0 = applied_theme.reader_background.unwrap_or(theme_clear_color(applied_theme.is_light));
+    }
+}
+
+fn theme_clear_color(is_light: bool) -> Color {
+    if is_light { CLEAR_COLOR_LIGHT } else { CLEAR_COLOR_DARK }
+}
+
+fn is_light_color(color: Color) -> bool {
+    let linear = color.to_linear();
+    0.2126 * linear.red + 0.7152 * linear.green + 0.0722 * linear.blue > LIGHT_BACKGROUND_LUMINANCE
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reader_background_dims_and_keeps_text_legible() {
+        let mut background = ReaderBackground::default();
+        assert_eq!(background.resolve(false), None);
+
+        background.is_custom = true;
+        background.color = [0.95, 0.9, 0.8];
+        let sepia = AppliedTheme { is_light: false, reader_background: background.resolve(false) };
+        assert_eq!(sepia.text_color(), TEXT_COLOR_LIGHT);
+
+        background.dim = READER_DIM_MAX;
+        let dimmed = AppliedTheme { is_light: false, reader_background: background.resolve(false) };
+        assert_eq!(dimmed.text_color(), TEXT_COLOR_DARK);
+
+        let light_theme_dimmed = ReaderBackground { is_custom: false, dim: 0.5, ..default() }.resolve(true).unwrap();
+        assert!(light_theme_dimmed.to_srgba().red < CLEAR_COLOR_LIGHT.to_srgba().red);
+    }
+}
@@ -0,0 +1,62 @@
+//! Hold-to-show keyboard shortcut overlay.
+//!
+//! Dims the whole window and lists every binding from `KeyBindings`, grouped by
+//! `ActionCategory`. Visible only while the cheat sheet key is held.
+
+use bevy::prelude::*;
+use bevy_egui::{EguiContexts, egui};
+
+use crate::input::{ActionCategory, CheatSheet, KeyBindings, WordSkip};
+
+const DIM_COLOR: egui::Color32 = egui::Color32::from_black_alpha(190);
+const PANEL_COLOR: egui::Color32 = egui::Color32::from_rgb(32, 36, 46);
+const KEY_COLOR: egui::Color32 = egui::Color32::from_rgb(200, 200, 140);
+const PANEL_ROUNDING: u8 = 8;
+const PANEL_MARGIN: i8 = 20;
+
+/// Draws the overlay on the foreground layer so it covers panels, tiles and windows.
+/// Purely visual: does not consume input or touch reading state.
+pub fn cheat_sheet_system(
+    mut contexts: EguiContexts,
+    key_bindings: Res<KeyBindings>,
+    word_skip: Res<WordSkip>,
+) {
+    let Ok(ctx) = contexts.ctx_mut() else { return };
+
+    let screen = ctx.content_rect();
+    ctx.layer_painter(egui::LayerId::new(egui::Order::Foreground, egui::Id::new("cheat_sheet_dim")))
+        .rect_filled(screen, 0.0, DIM_COLOR);
+
+    egui::Area::new(egui::Id::new("cheat_sheet"))
+        .order(egui::Order::Foreground)
+        .anchor(egui::Align2::CENTER_CENTER, egui::Vec2::ZERO)
+        .interactable(false)
+        .show(ctx, |ui| {
+            egui::Frame::NONE
+                .fill(PANEL_COLOR)
+                .corner_radius(egui::CornerRadius::same(PANEL_ROUNDING))
+                .inner_margin(egui::Margin::same(PANEL_MARGIN))
+                .show(ui, |ui| {
+                    ui.heading(egui::RichText::new("Keyboard Shortcuts").color(egui::Color32::WHITE).strong());
+                    ui.add_space(8.0);
+                    for category in ActionCategory::ALL {
+                        let rows = key_bindings.describe_category(category, &word_skip);
+                        if rows.is_empty() { continue; }
+                        ui.add_space(6.0);
+                        ui.strong(category.label());
+                        egui::Grid::new(("cheat_sheet_category", category.label()))
+                            .num_columns(2)
+                            .spacing([24.0, 4.0])
+                            .show(ui, |ui| {
+                                for (chords, description) in rows {
+                                    ui.monospace(egui::RichText::new(chords).color(KEY_COLOR));
+                                    ui.label(description);
+                                    ui.end_row();
+                                }
+                            });
+                    }
+                    ui.add_space(10.0);
+                    ui.weak(format!("Release {} to close", CheatSheet::keys_label()));
+                });
+        });
+}
@@ -1,17 +1,27 @@
 //! Playback controls UI component.
 //!
-//! Renders play/pause, progress, WPM slider, and font selector.
+//! Renders play/pause, progress, bookmarking, WPM slider and presets, words per flash, auto-pause, and font selector.
 //! Emits PlaybackCommand and ContentNavigate events.
 
 use bevy::prelude::*;
 use bevy_egui::{EguiContexts, egui};
 
+use super::{ReparseDialog, StorageWarningDialog};
+use super::labels::format_time_left;
+
+use crate::bookmarks::BookmarkAdd;
+use crate::cleanup::{FixOcrHyphens, OcrHyphenUndo, UndoOcrHyphens};
 use crate::fonts::FontsStore;
 use crate::playback::PlaybackCommand;
-use crate::reader::{ContentNavigate, ReadingState, FONT_SIZE_MIN, FONT_SIZE_MAX, WPM_MIN, WPM_MAX, WPM_STEP};
-use crate::tabs::{ActiveTab, Content, ReaderTab, TabFontSettings, TabWpm};
+use crate::reader::{AutoPause, ContentNavigate, ProgressMode, ReadingState, TimeLeft, WordChanged, CHUNK_SIZE_MAX, FONT_SIZE_MIN, FONT_SIZE_MAX, WPM_MIN, WPM_MAX, WPM_STEP};
+use crate::persistence::StorageStatus;
+use crate::queue::{CancelQueueCountdown, QueueCountdown, ReadingQueue};
+use crate::reload::TabReload;
+use crate::tabs::{ActiveTab, Content, ReaderTab, TabChunkSize, TabFilePath, TabFontSettings, TabOrder, TabParseOptions, TabWpm};
 
 const MARQUEE_SPEED: f32 = 50.0;
+const WPM_PRESETS: [u32; 4] = [250, 350, 500, 700];
+const COLOR_NOT_SAVING: egui::Color32 = egui::Color32::from_rgb(235, 120, 100);
 
 const MARQUEE_TEXTS: &[&str] = &[
     "Fun fact: you just read this one word at a time",
@@ -89,6 +99,7 @@ fn marquee_pick(cycle: u64) -> usize {
     (h as usize) % MARQUEE_TEXTS.len()
 }
 
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
 pub fn controls_system(
     mut commands: Commands,
     time: Res<Time>,
@@ -96,13 +107,22 @@ pub fn controls_system(
     current_state: Res<State<ReadingState>>,
     fonts: Res<FontsStore>,
     marquee_seed: Res<MarqueeSeed>,
-    active_reader: Query<(Entity, &TabWpm, &TabFontSettings, &Content), (With<ActiveTab>, With<ReaderTab>)>,
+    mut reading_queue: ResMut<ReadingQueue>,
+    mut auto_pause: ResMut<AutoPause>,
+    (time_left, mut progress_mode): (Res<TimeLeft>, ResMut<ProgressMode>),
+    queue_countdown: Option<Res<QueueCountdown>>,
+    storage_status: Res<StorageStatus>,
+    mut storage_warning: ResMut<StorageWarningDialog>,
+    mut reparse_dialog: ResMut<ReparseDialog>,
+    tab_order: Res<TabOrder>,
+    active_reader: Query<(Entity, &TabWpm, &TabChunkSize, &TabFontSettings, &Content, &TabParseOptions, Has<TabFilePath>, Option<&OcrHyphenUndo>), (With<ActiveTab>, With<ReaderTab>)>,
+    reader_tabs: Query<(Entity, &Content), With<ReaderTab>>,
 ) {
     let Ok(ctx) = contexts.ctx_mut() else { return };
     
     egui::TopBottomPanel::bottom("controls").show(ctx, |ui| {
         ui.horizontal(|ui| {
-            let Ok((entity, tab_wpm, font_settings, content)) = active_reader.single() else {
+            let Ok((entity, tab_wpm, tab_chunk_size, font_settings, content, parse_options, has_file, hyphen_undo)) = active_reader.single() else {
                 // We are on the homepage - show scrolling marquee
                 let rect = ui.available_rect_before_wrap();
                 ui.allocate_rect(rect, egui::Sense::hover());
@@ -141,7 +161,7 @@ pub fn controls_system(
             // Size the button manually to ensure constant width over the text(otherwise it jumps when seeking the content)
             if ui.add_sized(egui::vec2(80.0, ui.spacing().interact_size.y), btn).clicked() {
                 if at_end {
-                    commands.trigger(ContentNavigate::Seek(0));
+                    commands.trigger(PlaybackCommand::Restart);
                 } else {
                     commands.trigger(PlaybackCommand::TogglePlayPause);
                 }
@@ -153,22 +173,76 @@ pub fn controls_system(
             // ilog10() + 1 = digit count of total; pad current to match so label width stays constant
             let width = total.max(1).ilog10() as usize + 1;
             ui.label(egui::RichText::new(format!("{:>width$}/{}", current + 1, total)).monospace());
+            ui.label(format_time_left(time_left.remaining()))
+                .on_hover_text("Estimated time left at the current WPM");
             let max_index = total.saturating_sub(1);
             if max_index > 0 {
-                let slider = egui::Slider::new(&mut seek_index, 0..=max_index)
-                    .show_value(false);
-                if ui.add_sized(egui::vec2(200.0, ui.spacing().interact_size.y), slider).changed() {
-                    commands.trigger(ContentNavigate::Seek(seek_index));
+                let slider_size = egui::vec2(200.0, ui.spacing().interact_size.y);
+                match *progress_mode {
+                    ProgressMode::Words => {
+                        let slider = egui::Slider::new(&mut seek_index, 0..=max_index)
+                            .show_value(false);
+                        if ui.add_sized(slider_size, slider).changed() {
+                            commands.trigger(ContentNavigate::Seek(seek_index));
+                        }
+                    }
+                    ProgressMode::Time => {
+                        let mut fraction = time_left.time_fraction();
+                        let slider = egui::Slider::new(&mut fraction, 0.0..=1.0)
+                            .show_value(false);
+                        if ui.add_sized(slider_size, slider).changed() {
+                            commands.trigger(ContentNavigate::Seek(time_left.index_at_time_fraction(fraction)));
+                        }
+                    }
                 }
             }
+            let (mode_icon, mode_hint) = match *progress_mode {
+                ProgressMode::Words => ("#", "Progress by words. Click to measure by reading time"),
+                ProgressMode::Time => ("⏱", "Progress by reading time. Click to measure by words"),
+            };
+            if ui.small_button(mode_icon).on_hover_text(mode_hint).clicked() {
+                *progress_mode = match *progress_mode {
+                    ProgressMode::Words => ProgressMode::Time,
+                    ProgressMode::Time => ProgressMode::Words,
+                };
+            }
+            if ui.small_button("🔖").on_hover_text("Bookmark the current word").clicked() {
+                commands.trigger(BookmarkAdd { entity, label: String::new() });
+            }
             
             ui.separator();
             
             // WPM slider (per-tab)
             ui.label("WPM:");
             let mut wpm = tab_wpm.0;
-            if ui.add(egui::Slider::new(&mut wpm, WPM_MIN..=WPM_MAX).step_by(WPM_STEP as f64)).changed() {
-                commands.trigger(PlaybackCommand::AdjustWpm(wpm as i32 - tab_wpm.0 as i32));
+            let slider = egui::Slider::new(&mut wpm, WPM_MIN..=WPM_MAX)
+                .step_by(WPM_STEP as f64)
+                .show_value(false);
+            if ui.add(slider).changed() {
+                commands.trigger(PlaybackCommand::SetWpm(wpm));
+            }
+            // Exact entry: click to type, committed on Enter. Unparseable input reverts.
+            let mut exact_wpm = tab_wpm.0;
+            let entry = egui::DragValue::new(&mut exact_wpm)
+                .range(WPM_MIN..=WPM_MAX)
+                .update_while_editing(false);
+            if ui.add(entry).changed() {
+                commands.trigger(PlaybackCommand::SetWpm(exact_wpm));
+            }
+            for preset in WPM_PRESETS {
+                if ui.selectable_label(tab_wpm.0 == preset, preset.to_string()).clicked() {
+                    commands.trigger(PlaybackCommand::SetWpm(preset));
+                }
+            }
+
+            // Words per flash (per-tab)
+            let mut chunk_size = tab_chunk_size.0;
+            let chunk_entry = egui::DragValue::new(&mut chunk_size)
+                .range(1..=CHUNK_SIZE_MAX)
+                .suffix(" words");
+            if ui.add(chunk_entry).on_hover_text("Words shown per flash").changed() {
+                commands.entity(entity).insert(TabChunkSize(chunk_size));
+                commands.trigger(WordChanged);
             }
             
             ui.separator();
@@ -180,7 +254,7 @@ pub fn controls_system(
                 .show_ui(ui, |ui| {
                     for font_data in fonts.iter() {
                         if ui.selectable_label(font_settings.font.name == font_data.name, &font_data.name).clicked() {
-                            commands.entity(entity).insert(TabFontSettings::from_font(font_data, font_settings.font_size));
+                            commands.entity(entity).insert(TabFontSettings { font: font_data.clone(), ..font_settings.clone() });
                         }
                     }
                 });
@@ -192,7 +266,16 @@ pub fn controls_system(
                 .speed(0.5)
                 .suffix(" px");
             if ui.add(drag).changed() {
-                commands.entity(entity).insert(TabFontSettings::from_font(&font_settings.font, font_size));
+                commands.entity(entity).insert(TabFontSettings { font_size, ..font_settings.clone() });
+            }
+
+            // ORP highlight color (per-tab)
+            let mut highlight_color = font_settings.highlight_color;
+            if egui::color_picker::color_edit_button_rgb(ui, &mut highlight_color)
+                .on_hover_text("Highlight color")
+                .changed()
+            {
+                commands.entity(entity).insert(TabFontSettings { highlight_color, ..font_settings.clone() });
             }
             
             ui.separator();
@@ -204,6 +287,71 @@ pub fn controls_system(
                 ReadingState::Paused => "Paused",
             };
             ui.label(format!("[{}]", state_text));
+
+            // Reload from disk (native only: WASM keeps just the file name)
+            if cfg!(not(target_arch = "wasm32")) && has_file
+                && ui.small_button("⟳").on_hover_text("Reload file from disk").clicked()
+            {
+                commands.trigger(TabReload { entity });
+            }
+            if ui.small_button("Re-parse…").on_hover_text("Parse this tab again with different options").clicked() {
+                reparse_dialog.open(entity, &parse_options.0);
+            }
+
+            // OCR cleanup, undoable until dismissed
+            match hyphen_undo {
+                Some(undo) => {
+                    ui.label(format!("Merged {} pairs", undo.merged_count()));
+                    if ui.small_button("Undo").clicked() {
+                        commands.trigger(UndoOcrHyphens { entity });
+                    }
+                    if ui.small_button("×").on_hover_text("Keep the merge").clicked() {
+                        commands.entity(entity).remove::<OcrHyphenUndo>();
+                    }
+                }
+                None => {
+                    if ui.small_button("Fix OCR hyphens")
+                        .on_hover_text("Join words split by a stray hyphen, e.g. \"intro- duction\"")
+                        .clicked()
+                    {
+                        commands.trigger(FixOcrHyphens { entity });
+                    }
+                }
+            }
+
+            ui.separator();
+
+            ui.checkbox(&mut auto_pause.paragraph, "Pause at ¶")
+                .on_hover_text("Pause automatically at the end of each paragraph");
+            ui.checkbox(&mut auto_pause.section, "Pause at §")
+                .on_hover_text("Pause automatically before each chapter, page or heading");
+
+            ui.separator();
+
+            // Reading queue
+            ui.checkbox(&mut reading_queue.enabled, "Queue")
+                .on_hover_text("When a tab finishes, continue with the next unfinished tab");
+            if reading_queue.enabled
+                && let Some((position, count)) = ReadingQueue::position(&tab_order, &reader_tabs, entity)
+            {
+                ui.label(format!("{} of {} in queue", position, count));
+            }
+            if let Some(countdown) = queue_countdown {
+                ui.label(format!("Starting in {}…", countdown.remaining_secs()));
+                if ui.small_button("Cancel").on_hover_text("Esc").clicked() {
+                    commands.trigger(CancelQueueCountdown);
+                }
+                ctx.request_repaint();
+            }
+
+            if let Some(error) = &storage_status.error {
+                ui.separator();
+                let indicator = ui.add(egui::Label::new(egui::RichText::new("⚠ Not saving").color(COLOR_NOT_SAVING))
+                    .sense(egui::Sense::click()));
+                if indicator.on_hover_text(format!("{}\nClick for options", error)).clicked() {
+                    storage_warning.open = true;
+                }
+            }
         });
     });
 }
@@ -1,14 +1,33 @@
-//! Dialog windows for tab creation.
+//! Dialog windows for tab creation and guided flows.
 //!
-//! Handles new tab dialog and async file loading.
+//! Handles new tab dialog, background loading and parsing of files, URLs and pasted text, the re-parse dialog,
+//! the close-tab confirmation, the storage
+//! failure warning, the end-of-document session summary, and the WPM calibration wizard.
 
 use bevy::prelude::*;
 use bevy::tasks::{block_on, poll_once, AsyncComputeTaskPool, Task};
 use bevy_egui::{EguiContexts, egui};
 use std::path::Path;
+use std::time::Duration;
 
-use crate::tabs::{Content, TabCreateRequest, TabMarker};
-use crate::text::FileParsers;
+use crate::break_reminder::{BreakReminder, BREAK_SNOOZE};
+use crate::calibration::{CalibrationHistory, CalibrationStage, CalibrationWizard, CALIBRATION_SPEEDS};
+use crate::persistence::{ProgramState, StorageStatus};
+use crate::playback::PlaybackCommand;
+use crate::queue::ReadingQueue;
+use crate::reader::ContentFinished;
+use crate::reload::TabReparse;
+use crate::stats::{ReadingSession, ReadingStats};
+use crate::tabs::{ActiveTab, Content, DefaultTabSettings, ReaderTab, TabClose, TabCloseConfirm, TabCloseOthers, TabCreateRequest, TabFilePath, TabMarker, TabTitleWidth, TabWpm};
+use crate::text::{gutenberg_body, looks_like_verse, FileParsers, ParseOptions, ParseResult, TextStats, MAX_WORD_GRAPHEMES_MAX, MAX_WORD_GRAPHEMES_MIN};
+
+use super::labels::{format_count, format_duration, strong_tab_title, tab_title};
+use super::toasts::{Toast, ToastKind};
+
+/// Quiet time after an edit in the paste field before it is copied to the `PasteDraft`.
+const PASTE_DRAFT_DEBOUNCE_SECS: f32 = 1.0;
+/// Larger pastes are not kept as a draft.
+const PASTE_DRAFT_MAX_BYTES: usize = 1024 * 1024;
 
 // ============================================================================
 // Resources
@@ -18,23 +37,56 @@ use crate::text::FileParsers;
 pub struct NewTabDialog {
     pub open: bool,
     pub text_input: String,
+    pub parse_options: ParseOptions,
+    /// Creates the tab as `EphemeralTab`: nothing is written to disk.
+    pub is_ephemeral: bool,
+    pub url_input: String,
+    /// Why the last URL could not be loaded.
+    pub url_error: Option<String>,
+    text_hints: PastedTextHints,
+}
+/// What the paste field's text looks like, worked out only when the text changes.
+#[derive(Default)]
+struct PastedTextHints {
+    is_verse_like: bool,
+    has_gutenberg_markers: bool,
+    word_count: usize,
 }
 impl NewTabDialog {
     pub fn is_open(dialog: Res<NewTabDialog>) -> bool {
         dialog.open
     }
 
+    /// Replaces the paste field's text from outside the text edit.
+    fn set_text(&mut self, text: String) {
+        self.text_input = text;
+        self.refresh_text_hints();
+    }
+
+    fn refresh_text_hints(&mut self) {
+        self.text_hints = PastedTextHints {
+            is_verse_like: looks_like_verse(&self.text_input),
+            has_gutenberg_markers: gutenberg_body(&self.text_input).is_some(),
+            word_count: self.text_input.split_whitespace().count(),
+        };
+    }
+
+    /// The dialog's choices, for a load that may finish after they change.
+    fn settings(&self) -> NewTabSettings {
+        NewTabSettings { parse_options: self.parse_options.clone(), is_ephemeral: self.is_ephemeral }
+    }
+
     pub fn update(
-        mut commands: Commands,
         mut contexts: EguiContexts,
         mut dialog: ResMut<NewTabDialog>,
         mut pending_load: ResMut<PendingFileLoad>,
         file_parsers: Res<FileParsers>,
+        defaults: Res<DefaultTabSettings>,
         tabs: Query<Entity, With<TabMarker>>,
     ) {
         let Ok(ctx) = contexts.ctx_mut() else { return };
         
-        let is_loading = pending_load.task.is_some();
+        let is_loading = pending_load.is_loading();
         
         egui::Window::new("New Tab")
             .collapsible(false)
@@ -47,6 +99,8 @@ impl NewTabDialog {
                     // Task is spawned here, polled separately in PendingFileLoad::poll.
                     if btn.clicked() {
                         let extensions = file_parsers.supported_extensions();
+                        let file_parsers = file_parsers.clone();
+                        let settings = dialog.settings();
                         let task_pool = AsyncComputeTaskPool::get();
                         let task = task_pool.spawn(async move {
                             let ext_refs: Vec<&str> = extensions.iter().map(|s| s.as_str()).collect();
@@ -56,9 +110,14 @@ impl NewTabDialog {
                                 .await?;
                             
                             let file_name = file_handle.file_name();
+                            #[cfg(not(target_arch = "wasm32"))]
+                            let file_path = file_handle.path().to_string_lossy().into_owned();
+                            #[cfg(target_arch = "wasm32")]
+                            let file_path = file_name.clone();
                             let bytes = file_handle.read().await;
                             
-                            Some(RawFileLoad { file_name, bytes })
+                            let raw = RawFileLoad { file_name, file_path: Some(file_path), source_url: None, bytes };
+                            Some(raw.tab_request(&settings, &file_parsers))
                         });
                         pending_load.task = Some(task);
                     }
@@ -68,11 +127,38 @@ impl NewTabDialog {
                         ui.label("Loading...");
                     }
                 });
+                ui.horizontal(|ui| {
+                    let field = ui.add_enabled(!is_loading, egui::TextEdit::singleline(&mut dialog.url_input)
+                        .hint_text("https://…")
+                        .desired_width(300.0));
+                    let is_submitted = field.lost_focus() && ui.input(|input| input.key_pressed(egui::Key::Enter));
+                    let can_fetch = !is_loading && !dialog.url_input.trim().is_empty();
+                    let btn = ui.add_enabled(can_fetch, egui::Button::new("🌐 Load from URL"));
+                    if btn.clicked() || (can_fetch && is_submitted) {
+                        let url = match dialog.url_input.trim() {
+                            url if url.contains("://") => url.to_string(),
+                            url => format!("https://{}", url),
+                        };
+                        dialog.url_error = None;
+                        let file_parsers = file_parsers.clone();
+                        let settings = dialog.settings();
+                        pending_load.url_task = Some(AsyncComputeTaskPool::get().spawn(async move {
+                            fetch_url(url).await?.tab_request(&settings, &file_parsers)
+                        }));
+                    }
+                });
+                if let Some(error) = &dialog.url_error {
+                    ui.colored_label(ui.visuals().error_fg_color, error);
+                }
+                
+                let PastedTextHints { is_verse_like, has_gutenberg_markers, .. } = dialog.text_hints;
+                parse_options_ui(ui, &mut dialog.parse_options, is_verse_like, has_gutenberg_markers);
+                ui.checkbox(&mut dialog.is_ephemeral, "👻 Ephemeral (never saved to disk)");
                 
                 ui.separator();
                 ui.label("Or paste text below:");
                 
-                egui::ScrollArea::vertical()
+                let text_edit = egui::ScrollArea::vertical()
                     .max_height(300.0)
                     .show(ui, |ui| {
                         ui.add_enabled(
@@ -81,85 +167,849 @@ impl NewTabDialog {
                                 .desired_width(400.0)
                                 .desired_rows(10)
                                 .hint_text("Paste your text here...")
-                        );
-                    });
+                        )
+                    })
+                    .inner;
+                if text_edit.changed() {
+                    dialog.refresh_text_hints();
+                }
+                if dialog.text_hints.word_count > 0 {
+                    let stats = TextStats { word_count: dialog.text_hints.word_count, ..default() };
+                    ui.label(egui::RichText::new(format!(
+                        "≈ {} words, ~{} at {} WPM",
+                        format_count(stats.word_count as u64),
+                        format_duration(stats.estimated_reading_time(defaults.wpm)),
+                        defaults.wpm,
+                    )).small());
+                }
                 
                 ui.horizontal(|ui| {
                     let can_create = !dialog.text_input.trim().is_empty() && !is_loading;
-                    if ui.add_enabled(can_create, egui::Button::new("Create Tab")).clicked() {
-                        if let Some(parser) = file_parsers.get_for_extension("txt") {
-                            match parser.parse(dialog.text_input.as_bytes()) {
-                                Ok(parsed) if !parsed.words.is_empty() => {
-                                    let tab_count = tabs.iter().count();
-                                    let name = format!("Text {}", tab_count + 1);
-                                    commands.trigger(TabCreateRequest::new(name, Content::new(parsed.words)));
-                                }
-                                Ok(_) => warn!("Pasted text produced no words"),
-                                Err(e) => warn!("Failed to parse pasted text: {}", e),
+                    if ui.add_enabled(can_create, egui::Button::new("Create Tab")).clicked()
+                        && let Some(parser) = file_parsers.get_arc_for_extension("txt")
+                    {
+                        let name = format!("Text {}", tabs.iter().count() + 1);
+                        let text = dialog.text_input.clone();
+                        let settings = dialog.settings();
+                        pending_load.text_task = Some(AsyncComputeTaskPool::get().spawn(async move {
+                            match parser.parse(text.as_bytes(), &settings.parse_options) {
+                                Ok(parsed) if !parsed.words.is_empty() => Ok(settings.tab_request(name, parsed)),
+                                Ok(_) => Err("Pasted text produced no words".to_string()),
+                                Err(e) => Err(format!("Failed to parse pasted text: {}", e)),
                             }
-                        }
-                        dialog.open = false;
-                        dialog.text_input.clear();
+                        }));
                     }
                     
+                    // The pasted text stays as a draft for the next time the dialog opens.
                     if ui.button("Cancel").clicked() {
                         pending_load.task = None;
+                        pending_load.url_task = None;
+                        pending_load.text_task = None;
                         dialog.open = false;
-                        dialog.text_input.clear();
+                        dialog.url_error = None;
+                    }
+                    if !dialog.text_input.is_empty() && ui.add_enabled(!is_loading, egui::Button::new("Clear text")).clicked() {
+                        dialog.set_text(String::new());
                     }
                 });
             });
     }
 }
 
-/// Holds the async file-pick task spawned by the new tab dialog.
+/// Holds the async tasks spawned by the new tab dialog: a file pick, a URL fetch or
+/// pasted text, each parsed in the background so large texts do not stall the frame.
 #[derive(Resource, Default)]
 pub struct PendingFileLoad {
-    pub task: Option<Task<Option<RawFileLoad>>>,
+    pub task: Option<Task<Option<Result<TabCreateRequest, String>>>>,
+    pub url_task: Option<Task<Result<TabCreateRequest, String>>>,
+    pub text_task: Option<Task<Result<TabCreateRequest, String>>>,
 }
 impl PendingFileLoad {
-    /// Polls the async file-pick task each frame. On completion, parses the file
-    /// and triggers `TabCreateRequest`.
+    pub fn is_loading(&self) -> bool {
+        self.task.is_some() || self.url_task.is_some() || self.text_task.is_some()
+    }
+
+    /// Polls the async tasks each frame and triggers the finished `TabCreateRequest`.
+    /// URL failures are shown in the dialog.
     pub fn poll(
         mut commands: Commands,
         mut pending_load: ResMut<PendingFileLoad>,
         mut dialog: ResMut<NewTabDialog>,
-        file_parsers: Res<FileParsers>,
+        mut paste_draft: ResMut<PasteDraft>,
     ) {
-        let Some(task) = &mut pending_load.task else { return };
-        
-        if let Some(result) = block_on(poll_once(task)) {
-            if let Some(raw) = result {
-                let path = Path::new(&raw.file_name);
-                let tab_name = path.file_stem()
-                    .and_then(|s| s.to_str())
-                    .unwrap_or("Untitled")
-                    .to_string();
-                
-                if let Some(parser) = file_parsers.get_for_path(path) {
-                    match parser.parse(&raw.bytes) {
-                        Ok(parsed) if !parsed.words.is_empty() => {
-                            commands.trigger(
-                                TabCreateRequest::new(tab_name, Content::new(parsed.words))
-                                    .with_file_path(raw.file_name)
-                            );
-                        }
-                        Ok(_) => warn!("File '{}' produced no words", raw.file_name),
-                        Err(e) => warn!("Failed to parse '{}': {}", raw.file_name, e),
-                    }
-                } else {
-                    warn!("No parser found for '{}'", raw.file_name);
+        if let Some(task) = &mut pending_load.task
+            && let Some(result) = block_on(poll_once(task))
+        {
+            pending_load.task = None;
+            if let Some(result) = result {
+                match result {
+                    Ok(request) => commands.trigger(request),
+                    Err(e) => warn!("{}", e),
                 }
                 dialog.open = false;
             }
-            pending_load.task = None;
+        }
+
+        if let Some(task) = &mut pending_load.text_task
+            && let Some(result) = block_on(poll_once(task))
+        {
+            pending_load.text_task = None;
+            match result {
+                Ok(request) => {
+                    commands.trigger(request);
+                    dialog.set_text(String::new());
+                    paste_draft.discard();
+                }
+                Err(e) => warn!("{}", e),
+            }
+            dialog.open = false;
+        }
+
+        if let Some(task) = &mut pending_load.url_task
+            && let Some(result) = block_on(poll_once(task))
+        {
+            pending_load.url_task = None;
+            match result {
+                Ok(request) => {
+                    commands.trigger(request);
+                    dialog.open = false;
+                    dialog.url_input.clear();
+                }
+                Err(e) => {
+                    warn!("{}", e);
+                    dialog.url_error = Some(e);
+                }
+            }
         }
     }
 }
 
-/// Raw bytes returned by the async file dialog, before parsing.
+/// Text pasted into the New Tab dialog, kept until a tab is created from it so
+/// closing the dialog or the app does not lose it. Follows the paste field after
+/// edits pause; text typed while "Ephemeral" is on is never kept, and neither is
+/// text over `PASTE_DRAFT_MAX_BYTES`. Stored apart from `ProgramState` and written
+/// only when it changes.
+#[derive(Resource, Default)]
+pub struct PasteDraft {
+    text: String,
+    /// The field holds more than `PASTE_DRAFT_MAX_BYTES`, so the draft is empty.
+    is_too_large: bool,
+    /// Countdown to copying the field, started by the first edit since the last copy.
+    debounce: Option<Timer>,
+}
+impl PasteDraft {
+    /// Loads the saved draft into the paste field once at startup, then keeps the
+    /// draft following the field.
+    pub fn sync(
+        mut commands: Commands,
+        time: Res<Time>,
+        mut paste_draft: ResMut<PasteDraft>,
+        mut dialog: ResMut<NewTabDialog>,
+        mut is_restored: Local<bool>,
+    ) {
+        if !std::mem::replace(&mut *is_restored, true) {
+            paste_draft.text = ProgramState::load_paste_draft();
+            dialog.set_text(paste_draft.text.clone());
+            return;
+        }
+        if !dialog.is_changed() && paste_draft.debounce.is_none() { return; }
+        let text = if dialog.is_ephemeral { "" } else { dialog.text_input.as_str() };
+        if !paste_draft.follow(text, time.delta()) { return; }
+        if paste_draft.is_too_large {
+            commands.trigger(Toast::new(ToastKind::Warning, "Pasted text over 1 MB is not kept as a draft"));
+        }
+        ProgramState::save_paste_draft(&paste_draft.text);
+    }
+
+    /// Copies `text` once `PASTE_DRAFT_DEBOUNCE_SECS` have passed since it first
+    /// differed from the draft. Edits in between share the copy. Returns whether
+    /// the draft changed.
+    fn follow(&mut self, text: &str, delta: Duration) -> bool {
+        let is_too_large = text.len() > PASTE_DRAFT_MAX_BYTES;
+        let kept = if is_too_large { "" } else { text };
+        if kept == self.text && is_too_large == self.is_too_large {
+            self.debounce = None;
+            return false;
+        }
+        let debounce = self.debounce.get_or_insert_with(|| Timer::from_seconds(PASTE_DRAFT_DEBOUNCE_SECS, TimerMode::Once));
+        if !debounce.tick(delta).is_finished() { return false; }
+        self.text = kept.to_string();
+        self.is_too_large = is_too_large;
+        self.debounce = None;
+        true
+    }
+
+    /// Empties the draft and deletes the stored copy.
+    fn discard(&mut self) {
+        *self = Self::default();
+        ProgramState::save_paste_draft("");
+    }
+}
+
+/// New Tab dialog choices captured for a background load. The default is what
+/// a file opened from outside the dialog gets.
+#[derive(Default)]
+pub struct NewTabSettings {
+    pub parse_options: ParseOptions,
+    pub is_ephemeral: bool,
+}
+impl NewTabSettings {
+    /// Also writes the word cache of non-ephemeral tabs.
+    pub fn tab_request(&self, name: String, parsed: ParseResult) -> TabCreateRequest {
+        let content = if self.is_ephemeral {
+            Content::new_ephemeral(parsed.words, parsed.sections)
+        } else {
+            Content::new(parsed.words, parsed.sections)
+        };
+        TabCreateRequest::new(name, content)
+            .with_stats(parsed.stats)
+            .with_book_meta(parsed.meta)
+            .with_parse_options(self.parse_options.clone())
+    }
+}
+
+/// Raw bytes returned by the async file dialog or a URL fetch, before parsing.
 pub struct RawFileLoad {
+    /// Picks the parser by extension; its stem names the tab.
     pub file_name: String,
+    /// Full path on native (used for reloading); the file name on WASM; none for URLs.
+    pub file_path: Option<String>,
+    /// The address a downloaded page was requested from.
+    pub source_url: Option<String>,
     pub bytes: Vec<u8>,
 }
+impl RawFileLoad {
+    pub fn tab_request(self, settings: &NewTabSettings, file_parsers: &FileParsers) -> Result<TabCreateRequest, String> {
+        let path = Path::new(&self.file_name);
+        let file_stem = path.file_stem()
+            .and_then(|s| s.to_str())
+            .unwrap_or("Untitled");
 
+        let parser = file_parsers.get_for_path(path)
+            .ok_or_else(|| format!("No parser found for '{}'", self.file_name))?;
+        let parsed = match parser.parse(&self.bytes, &settings.parse_options) {
+            Ok(parsed) if !parsed.words.is_empty() => parsed,
+            Ok(_) => return Err(format!("'{}' produced no words", self.file_name)),
+            Err(e) => return Err(format!("Failed to parse '{}': {}", self.file_name, e)),
+        };
+        let mut request = settings.tab_request(parsed.tab_name(file_stem), parsed);
+        if let Some(file_path) = self.file_path {
+            request = request.with_file_path(file_path);
+        }
+        if let Some(url) = self.source_url {
+            request = request.with_source_url(url);
+        }
+        Ok(request)
+    }
+}
+
+/// Downloads `url` for a new tab. The parser is picked by the response's
+/// Content-Type, then by the URL's extension.
+async fn fetch_url(url: String) -> Result<RawFileLoad, String> {
+    let request = ehttp::Request::get(&url);
+    #[cfg(not(target_arch = "wasm32"))]
+    let response = ehttp::fetch_blocking(&request);
+    #[cfg(target_arch = "wasm32")]
+    let response = ehttp::fetch_async(request).await;
+
+    let response = response.map_err(|e| format!("Could not load {}: {}", url, e))?;
+    if !response.ok {
+        return Err(format!("Could not load {}: {} {}", url, response.status, response.status_text));
+    }
+    let file_name = url_file_name(&response.url, response.content_type())?;
+    Ok(RawFileLoad { file_name, file_path: None, source_url: Some(url), bytes: response.bytes })
+}
+
+/// File name standing in for a downloaded page: the last path segment (or the host)
+/// with an extension matching the content type.
+fn url_file_name(url: &str, content_type: Option<&str>) -> Result<String, String> {
+    let without_scheme = url.split_once("://").map_or(url, |(_, rest)| rest);
+    let path = without_scheme.split(['?', '#']).next().unwrap_or_default();
+    let mut segments = path.split('/').filter(|segment| !segment.is_empty());
+    let host = segments.next().unwrap_or("Web page");
+    let (stem, url_extension) = match segments.next_back().map(Path::new) {
+        Some(segment) => (
+            segment.file_stem().and_then(|stem| stem.to_str()).unwrap_or(host),
+            segment.extension().and_then(|ext| ext.to_str()),
+        ),
+        None => (host, None),
+    };
+
+    let mime = content_type.unwrap_or_default()
+        .split(';')
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .to_ascii_lowercase();
+    let extension = match mime.as_str() {
+        "text/html" | "application/xhtml+xml" => "html",
+        "text/markdown" => "md",
+        "application/epub+zip" => "epub",
+        "application/pdf" => "pdf",
+        "application/vnd.openxmlformats-officedocument.wordprocessingml.document" => "docx",
+        "text/plain" => "txt",
+        _ => match url_extension {
+            Some(extension) => extension,
+            None if mime.is_empty() || mime.starts_with("text/") => "txt",
+            None => return Err(format!("Not a text document ({})", mime)),
+        },
+    };
+    Ok(format!("{}.{}", stem, extension))
+}
+
+/// Warning shown the first time saving fails. On native it offers to move
+/// all data to a user-chosen folder.
+#[derive(Resource, Default)]
+pub struct StorageWarningDialog {
+    pub open: bool,
+    was_shown: bool,
+    /// Also write the portable marker so later sessions keep the chosen folder.
+    #[cfg(not(target_arch = "wasm32"))]
+    remember: bool,
+    #[cfg(not(target_arch = "wasm32"))]
+    folder_pick: Option<Task<Option<std::path::PathBuf>>>,
+}
+impl StorageWarningDialog {
+    pub fn is_open(dialog: Res<StorageWarningDialog>) -> bool {
+        dialog.open
+    }
+
+    /// Opens the dialog once per session, on the first failed save.
+    pub fn open_on_first_failure(
+        mut dialog: ResMut<StorageWarningDialog>,
+        storage_status: Res<StorageStatus>,
+    ) {
+        if storage_status.error.is_some() && !dialog.was_shown {
+            dialog.open = true;
+            dialog.was_shown = true;
+        }
+    }
+
+    pub fn update(
+        mut contexts: EguiContexts,
+        mut dialog: ResMut<StorageWarningDialog>,
+        storage_status: Res<StorageStatus>,
+    ) {
+        let Ok(ctx) = contexts.ctx_mut() else { return };
+
+        egui::Window::new("⚠ Progress is not being saved")
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+            .show(ctx, |ui| {
+                match &storage_status.error {
+                    Some(error) => {
+                        ui.label("Your tabs and reading positions could not be written to disk.");
+                        ui.label(egui::RichText::new(error).small().weak());
+                    }
+                    None => {
+                        ui.label("Saving works again.");
+                    }
+                }
+                ui.add_space(8.0);
+                #[cfg(not(target_arch = "wasm32"))]
+                {
+                    ui.label("Choose another folder to keep your progress:");
+                    ui.checkbox(&mut dialog.remember, "Remember this folder (writes a marker next to the app)");
+                    ui.horizontal(|ui| {
+                        let is_picking = dialog.folder_pick.is_some();
+                        if ui.add_enabled(!is_picking, egui::Button::new("📁 Choose folder…")).clicked() {
+                            let task = AsyncComputeTaskPool::get().spawn(async move {
+                                rfd::AsyncFileDialog::new()
+                                    .pick_folder()
+                                    .await
+                                    .map(|folder| folder.path().to_path_buf())
+                            });
+                            dialog.folder_pick = Some(task);
+                        }
+                        if is_picking {
+                            ui.spinner();
+                        }
+                        if ui.button("Dismiss").clicked() {
+                            dialog.open = false;
+                        }
+                    });
+                }
+                #[cfg(target_arch = "wasm32")]
+                if ui.button("Dismiss").clicked() {
+                    dialog.open = false;
+                }
+            });
+    }
+
+    /// Applies the folder picked in the dialog as the new data directory.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn poll_folder_pick(
+        mut dialog: ResMut<StorageWarningDialog>,
+        mut storage_status: ResMut<StorageStatus>,
+    ) {
+        let Some(task) = &mut dialog.folder_pick else { return };
+        let Some(result) = block_on(poll_once(task)) else { return };
+        dialog.folder_pick = None;
+
+        let Some(dir) = result else { return };
+        match crate::persistence::ProgramState::relocate_data_dir(dir, dialog.remember) {
+            Ok(()) => {
+                storage_status.is_relocated = true;
+                dialog.open = false;
+            }
+            Err(e) => {
+                warn!("{}", e);
+                storage_status.error = Some(e);
+            }
+        }
+    }
+}
+
+/// Re-parse options for one reader tab. Open while `target` is set.
+#[derive(Resource, Default)]
+pub struct ReparseDialog {
+    pub target: Option<Entity>,
+    pub options: ParseOptions,
+}
+impl ReparseDialog {
+    pub fn is_open(dialog: Res<ReparseDialog>) -> bool {
+        dialog.target.is_some()
+    }
+
+    /// Opens the dialog for `entity`, starting from the options it was parsed with.
+    pub fn open(&mut self, entity: Entity, options: &ParseOptions) {
+        self.target = Some(entity);
+        self.options = options.clone();
+    }
+
+    pub fn update(
+        mut commands: Commands,
+        mut contexts: EguiContexts,
+        mut dialog: ResMut<ReparseDialog>,
+        title_width: Res<TabTitleWidth>,
+        reader_tabs: Query<(&Name, Has<TabFilePath>), With<ReaderTab>>,
+    ) {
+        let Ok(ctx) = contexts.ctx_mut() else { return };
+        let Some(entity) = dialog.target else { return };
+        let Ok((name, has_file)) = reader_tabs.get(entity) else {
+            dialog.target = None;
+            return;
+        };
+
+        egui::Window::new("Re-parse")
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+            .show(ctx, |ui| {
+                strong_tab_title(ui, name.as_str(), &title_width);
+                ui.add_space(4.0);
+                parse_options_ui(ui, &mut dialog.options, false, false);
+                let source = if TabReparse::uses_file(has_file) {
+                    "Reads the original file again."
+                } else {
+                    "Rebuilds the text from the current words; splits already made stay."
+                };
+                ui.label(egui::RichText::new(source).small().italics());
+                ui.separator();
+                ui.horizontal(|ui| {
+                    if ui.button("Re-parse").clicked() {
+                        commands.trigger(TabReparse { entity, options: dialog.options.clone() });
+                        dialog.target = None;
+                    }
+                    if ui.button("Cancel").clicked() {
+                        dialog.target = None;
+                    }
+                });
+            });
+    }
+}
+
+/// Asks before closing reader tabs the user is partway through. Open while `target` is set.
+#[derive(Resource, Default)]
+pub struct CloseTabDialog {
+    pub target: Option<CloseTarget>,
+}
+/// What the `CloseTabDialog` closes once confirmed.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum CloseTarget {
+    Tab(Entity),
+    /// Every reader tab but `keep`; `in_progress` of them are past their first word.
+    Others { keep: Entity, in_progress: usize },
+}
+impl CloseTabDialog {
+    pub fn is_open(dialog: Res<CloseTabDialog>) -> bool {
+        dialog.target.is_some()
+    }
+
+    /// Closes `entity` right away, or opens the dialog first when it is past its
+    /// first word and `TabCloseConfirm` is on.
+    pub fn request(&mut self, commands: &mut Commands, entity: Entity, current_index: usize, confirm: &TabCloseConfirm) {
+        if confirm.enabled && current_index > 0 {
+            self.target = Some(CloseTarget::Tab(entity));
+        } else {
+            commands.trigger(TabClose::from(entity));
+        }
+    }
+
+    /// Closes every reader tab but `keep` right away, or opens the dialog first
+    /// when `in_progress` of them are past their first word and `TabCloseConfirm` is on.
+    pub fn request_others(&mut self, commands: &mut Commands, keep: Entity, in_progress: usize, confirm: &TabCloseConfirm) {
+        if confirm.enabled && in_progress > 0 {
+            self.target = Some(CloseTarget::Others { keep, in_progress });
+        } else {
+            commands.trigger(TabCloseOthers::from(keep));
+        }
+    }
+
+    pub fn update(
+        mut commands: Commands,
+        mut contexts: EguiContexts,
+        mut dialog: ResMut<CloseTabDialog>,
+        title_width: Res<TabTitleWidth>,
+        reader_tabs: Query<(&Name, &Content), With<ReaderTab>>,
+    ) {
+        let Ok(ctx) = contexts.ctx_mut() else { return };
+        let Some(target) = dialog.target else { return };
+        let (title, entity) = match target {
+            CloseTarget::Tab(entity) => ("Close tab?", entity),
+            CloseTarget::Others { keep, .. } => ("Close other tabs?", keep),
+        };
+        let Ok((name, content)) = reader_tabs.get(entity) else {
+            dialog.target = None;
+            return;
+        };
+
+        egui::Window::new(title)
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+            .show(ctx, |ui| {
+                match target {
+                    CloseTarget::Tab(_) => {
+                        strong_tab_title(ui, name.as_str(), &title_width);
+                        let (current, total) = content.progress();
+                        ui.label(format!("You are at word {} of {}.", format_count(current as u64 + 1), format_count(total as u64)));
+                    }
+                    CloseTarget::Others { in_progress, .. } => {
+                        let noun = if in_progress == 1 { "tab" } else { "tabs" };
+                        ui.label(format!("Close {} {} with reading progress?", in_progress, noun));
+                        ui.label(format!("Keeps {}.", tab_title(name.as_str(), &title_width)));
+                    }
+                }
+                ui.separator();
+                ui.horizontal(|ui| {
+                    if ui.button("Close").clicked() {
+                        match target {
+                            CloseTarget::Tab(entity) => commands.trigger(TabClose::from(entity)),
+                            CloseTarget::Others { keep, .. } => commands.trigger(TabCloseOthers::from(keep)),
+                        }
+                        dialog.target = None;
+                    }
+                    if ui.button("Cancel").clicked() {
+                        dialog.target = None;
+                    }
+                });
+            });
+    }
+}
+
+/// Summary of the reading session that just played through to the end of a tab.
+/// Open while `summary` is set.
+#[derive(Resource, Default)]
+pub struct SessionSummaryDialog {
+    pub summary: Option<SessionSummary>,
+}
+pub struct SessionSummary {
+    pub entity: Entity,
+    pub words_read: u64,
+    pub elapsed: Duration,
+}
+impl SessionSummaryDialog {
+    pub fn is_open(dialog: Res<SessionSummaryDialog>) -> bool {
+        dialog.summary.is_some()
+    }
+
+    /// Opens when playback runs past the last word. Skipped after a manual seek to
+    /// the end, and while the reading queue moves on to the next tab instead.
+    pub fn open_on_finish(
+        trigger: On<ContentFinished>,
+        mut dialog: ResMut<SessionSummaryDialog>,
+        time: Res<Time>,
+        session: Res<ReadingSession>,
+        stats: Res<ReadingStats>,
+        queue: Res<ReadingQueue>,
+    ) {
+        if queue.enabled || !session.has_played_through() { return; }
+        let (words_read, elapsed) = session.totals(&stats, time.elapsed());
+        dialog.summary = Some(SessionSummary { entity: trigger.entity, words_read, elapsed });
+    }
+
+    pub fn update(
+        mut commands: Commands,
+        mut contexts: EguiContexts,
+        mut dialog: ResMut<SessionSummaryDialog>,
+        title_width: Res<TabTitleWidth>,
+        active_tab: Option<Single<(Entity, &Name), With<ActiveTab>>>,
+    ) {
+        let Ok(ctx) = contexts.ctx_mut() else { return };
+        let Some(summary) = &dialog.summary else { return };
+        let Some((entity, name)) = active_tab.map(|active| active.into_inner()).filter(|(entity, _)| *entity == summary.entity) else {
+            dialog.summary = None;
+            return;
+        };
+
+        let minutes = summary.elapsed.as_secs_f64() / 60.0;
+        let average_wpm = if minutes > 0.0 { (summary.words_read as f64 / minutes).round() as u64 } else { 0 };
+        let mut is_open = true;
+        let mut is_done = false;
+        egui::Window::new("🏁 Finished")
+            .open(&mut is_open)
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+            .show(ctx, |ui| {
+                strong_tab_title(ui, name.as_str(), &title_width);
+                ui.add_space(4.0);
+                egui::Grid::new("session_summary").num_columns(2).show(ui, |ui| {
+                    ui.label("Words read");
+                    ui.label(format_count(summary.words_read));
+                    ui.end_row();
+                    ui.label("Elapsed");
+                    ui.label(format_duration(summary.elapsed));
+                    ui.end_row();
+                    ui.label("Average speed");
+                    ui.label(format!("{} WPM", average_wpm));
+                    ui.end_row();
+                });
+                ui.separator();
+                ui.horizontal(|ui| {
+                    if ui.button("⏮ Restart").clicked() {
+                        commands.trigger(PlaybackCommand::Restart);
+                        is_done = true;
+                    }
+                    if ui.button("Close tab").clicked() {
+                        commands.trigger(TabClose { entity });
+                        is_done = true;
+                    }
+                });
+            });
+        if !is_open || is_done {
+            dialog.summary = None;
+        }
+    }
+}
+
+/// Checkboxes for every `ParseOptions` field. `is_verse_like` and
+/// `has_gutenberg_markers` add a hint next to their option while it is off.
+fn parse_options_ui(ui: &mut egui::Ui, options: &mut ParseOptions, is_verse_like: bool, has_gutenberg_markers: bool) {
+    ui.checkbox(&mut options.split_compounds, "Split slash/dash compounds (input/output)");
+    ui.horizontal(|ui| {
+        ui.checkbox(&mut options.preserve_line_breaks, "Preserve line breaks (poetry)");
+        if !options.preserve_line_breaks && is_verse_like {
+            ui.label(egui::RichText::new("← short lines, looks like verse").small().italics());
+        }
+    });
+    ui.horizontal(|ui| {
+        ui.checkbox(&mut options.split_long_words, "Split words longer than");
+        ui.add_enabled(options.split_long_words, egui::DragValue::new(&mut options.max_word_graphemes)
+            .range(MAX_WORD_GRAPHEMES_MIN..=MAX_WORD_GRAPHEMES_MAX)
+            .suffix(" chars"));
+    }).response.on_hover_text("Shows long URLs and identifiers in pieces, broken at slashes, dots and camelCase where possible");
+    ui.horizontal(|ui| {
+        ui.checkbox(&mut options.trim_gutenberg, "Trim Gutenberg boilerplate");
+        if !options.trim_gutenberg && has_gutenberg_markers {
+            ui.label(egui::RichText::new("← Project Gutenberg markers found").small().italics());
+        }
+    });
+    ui.checkbox(&mut options.skip_page_numbers, "Skip page-number lines")
+        .on_hover_text("Drops lines holding only a number, like \"42\" or \"- 7 -\", between blank lines");
+}
+
+/// Suggests a break once `BreakReminder` is due. Playback is already paused.
+pub fn break_reminder_system(
+    mut contexts: EguiContexts,
+    mut reminder: ResMut<BreakReminder>,
+) {
+    let Ok(ctx) = contexts.ctx_mut() else { return };
+    egui::Window::new("☕ Time for a break")
+        .collapsible(false)
+        .resizable(false)
+        .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+        .show(ctx, |ui| {
+            ui.label(format!("You have been reading for {}.", format_duration(reminder.reading_time())));
+            ui.label("Rest your eyes for a few minutes, ideally looking at something far away.");
+            ui.add_space(4.0);
+            ui.horizontal(|ui| {
+                if ui.button(format!("Snooze {} min", BREAK_SNOOZE.as_secs() / 60)).clicked() {
+                    reminder.snooze();
+                }
+                if ui.button("Dismiss").clicked() {
+                    reminder.dismiss();
+                }
+            });
+        });
+}
+
+/// Window guiding the user through the `CalibrationWizard` rounds.
+/// Anchored to the side so the reader display stays visible.
+pub fn calibration_wizard_system(
+    mut commands: Commands,
+    mut contexts: EguiContexts,
+    mut wizard: ResMut<CalibrationWizard>,
+    mut defaults: ResMut<DefaultTabSettings>,
+    mut history: ResMut<CalibrationHistory>,
+    reader_tabs: Query<(Entity, &Content), With<ReaderTab>>,
+) {
+    let Ok(ctx) = contexts.ctx_mut() else { return };
+
+    egui::Window::new("WPM Calibration")
+        .collapsible(false)
+        .resizable(false)
+        .anchor(egui::Align2::RIGHT_TOP, [-16.0, 48.0])
+        .show(ctx, |ui| {
+            let round_count = CALIBRATION_SPEEDS.len();
+            match wizard.stage.clone() {
+                CalibrationStage::Inactive => {}
+                CalibrationStage::Intro => {
+                    ui.label("A short passage will play at increasing speeds.");
+                    ui.label("After each round, tell us whether the pace felt comfortable.");
+                    ui.add_space(8.0);
+                    if ui.button("▶ Start").clicked() {
+                        wizard.start(&mut commands);
+                    }
+                }
+                CalibrationStage::Reading { speed_index, .. } => {
+                    ui.label(format!("Round {}/{}: {} WPM", speed_index + 1, round_count, CALIBRATION_SPEEDS[speed_index]));
+                    ui.label("Keep your eyes on the red letter.");
+                }
+                CalibrationStage::Prompt { speed_index } => {
+                    ui.label(format!("How did {} WPM feel?", CALIBRATION_SPEEDS[speed_index]));
+                    ui.add_space(8.0);
+                    let mut answer = None;
+                    ui.horizontal(|ui| {
+                        if ui.button("👍 Comfortable").clicked() {
+                            answer = Some(true);
+                        }
+                        if ui.button("🐢 Too fast").clicked() {
+                            answer = Some(false);
+                        }
+                    });
+                    if let Some(is_comfortable) = answer
+                        && let Some(recommended_wpm) = wizard.answer(is_comfortable)
+                    {
+                        defaults.wpm = recommended_wpm;
+                        history.record_now();
+                        wizard.close_tab(&mut commands, &reader_tabs);
+                    }
+                }
+                CalibrationStage::Finished { recommended_wpm } => {
+                    ui.label(format!("Recommended speed: {} WPM", recommended_wpm));
+                    ui.label("Saved as the default for new tabs.");
+                    ui.add_space(8.0);
+                    ui.horizontal(|ui| {
+                        if ui.button("Apply to open tabs").clicked() {
+                            for (entity, _) in reader_tabs.iter() {
+                                commands.entity(entity).insert(TabWpm(recommended_wpm));
+                            }
+                            wizard.close(&mut commands, &reader_tabs);
+                        }
+                        if ui.button("Done").clicked() {
+                            wizard.close(&mut commands, &reader_tabs);
+                        }
+                    });
+                    return;
+                }
+            }
+            ui.add_space(8.0);
+            if ui.button("Cancel").clicked() {
+                wizard.close(&mut commands, &reader_tabs);
+            }
+        });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn url_file_name_prefers_content_type_then_url_extension() {
+        assert_eq!(url_file_name("https://example.com/posts/speed-reading?ref=feed", Some("text/html; charset=utf-8")).unwrap(), "speed-reading.html");
+        assert_eq!(url_file_name("https://example.com/books/moby.epub", Some("application/octet-stream")).unwrap(), "moby.epub");
+        assert_eq!(url_file_name("https://example.com/", Some("text/plain")).unwrap(), "example.com.txt");
+        assert_eq!(url_file_name("https://example.com/notes", None).unwrap(), "notes.txt");
+        assert!(url_file_name("https://example.com/cat", Some("image/png")).is_err());
+    }
+
+    #[test]
+    fn paste_draft_follows_text_after_edits_pause() {
+        let mut draft = PasteDraft::default();
+        let step = Duration::from_secs_f32(PASTE_DRAFT_DEBOUNCE_SECS / 2.0);
+
+        assert!(!draft.follow("Call me", step));
+        assert_eq!(draft.text, "");
+        assert!(draft.follow("Call me Ishmael.", step));
+        assert_eq!(draft.text, "Call me Ishmael.");
+
+        assert!(!draft.follow("", step));
+        assert!(!draft.follow("Call me Ishmael.", step));
+        assert_eq!(draft.text, "Call me Ishmael.");
+        assert!(draft.debounce.is_none());
+    }
+
+    #[test]
+    fn paste_draft_drops_text_over_the_size_cap() {
+        let mut draft = PasteDraft::default();
+        let step = Duration::from_secs_f32(PASTE_DRAFT_DEBOUNCE_SECS);
+        draft.follow("Call me Ishmael.", step);
+
+        assert!(draft.follow(&"a".repeat(PASTE_DRAFT_MAX_BYTES + 1), step));
+        assert_eq!((draft.text.as_str(), draft.is_too_large), ("", true));
+        assert!(!draft.follow(&"b".repeat(PASTE_DRAFT_MAX_BYTES + 1), step));
+
+        assert!(draft.follow("Call me Ishmael.", step));
+        assert_eq!((draft.text.as_str(), draft.is_too_large), ("Call me Ishmael.", false));
+    }
+
+    #[test]
+    fn close_request_asks_only_for_tabs_past_the_first_word() {
+        let mut app = App::new();
+        app.add_observer(|trigger: On<TabClose>, mut commands: Commands| commands.entity(trigger.entity).despawn());
+        let fresh = app.world_mut().spawn_empty().id();
+        let mid_read = app.world_mut().spawn_empty().id();
+        let mut dialog = CloseTabDialog::default();
+        let request = |app: &mut App, dialog: &mut CloseTabDialog, entity, current_index, enabled| {
+            dialog.request(&mut app.world_mut().commands(), entity, current_index, &TabCloseConfirm { enabled });
+            app.world_mut().flush();
+        };
+
+        request(&mut app, &mut dialog, fresh, 0, true);
+        assert!(app.world().get_entity(fresh).is_err());
+        assert_eq!(dialog.target, None);
+
+        request(&mut app, &mut dialog, mid_read, 5, true);
+        assert!(app.world().get_entity(mid_read).is_ok());
+        assert_eq!(dialog.target, Some(CloseTarget::Tab(mid_read)));
+
+        dialog.target = None;
+        request(&mut app, &mut dialog, mid_read, 5, false);
+        assert!(app.world().get_entity(mid_read).is_err());
+        assert_eq!(dialog.target, None);
+    }
+
+    #[test]
+    fn close_others_request_asks_only_when_some_have_progress() {
+        let mut app = App::new();
+        app.add_observer(|trigger: On<TabCloseOthers>, mut commands: Commands| commands.entity(trigger.entity).despawn());
+        let keep = app.world_mut().spawn_empty().id();
+        let mut dialog = CloseTabDialog::default();
+        let request = |app: &mut App, dialog: &mut CloseTabDialog, in_progress, enabled| {
+            dialog.request_others(&mut app.world_mut().commands(), keep, in_progress, &TabCloseConfirm { enabled });
+            app.world_mut().flush();
+        };
+
+        request(&mut app, &mut dialog, 3, true);
+        assert!(app.world().get_entity(keep).is_ok());
+        assert_eq!(dialog.target, Some(CloseTarget::Others { keep, in_progress: 3 }));
+
+        dialog.target = None;
+        request(&mut app, &mut dialog, 0, true);
+        assert!(app.world().get_entity(keep).is_err());
+        assert_eq!(dialog.target, None);
+    }
+}
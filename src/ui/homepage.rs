@@ -4,21 +4,57 @@
 //! and a unique marker component. Each tile type has its own system that queries only
 //! what it needs.
 
+use std::time::Duration;
 use bevy::prelude::*;
 use bevy_egui::{EguiContexts, egui};
 
+use crate::break_reminder::{BreakReminder, BREAK_INTERVAL_MINS_MAX, BREAK_INTERVAL_MINS_MIN};
+use crate::calibration::{CalibrationHistory, CalibrationWizard};
 use crate::fonts::FontsStore;
-use crate::reader::{FONT_SIZE_MIN, FONT_SIZE_MAX, WPM_MIN, WPM_MAX, WPM_STEP};
-use crate::tabs::{ActiveTab, ApplyDefaultsToAll, DefaultTabSettings, HomepageTab};
+use crate::input::{ActionCategory, CheatSheet, KeyBindings, WordSkip, WORD_SKIP_MAX, WORD_SKIP_MIN};
+#[cfg(feature = "metronome")]
+use crate::metronome::Metronome;
+use crate::orp::{
+    NumberDisplay, OrpConfig, PacingPulse, ReduceMotion, ReticleStyle, WordAutoFit, WordFade, WordPreview, AUTO_FIT_MIN_SCALE_MAX, AUTO_FIT_MIN_SCALE_MIN,
+    ORP_PIVOT_FRACTION_MAX, ORP_PIVOT_FRACTION_MIN,
+    PACING_PULSE_MAX_DEPTH, PACING_PULSE_RATE_MAX, PACING_PULSE_RATE_MIN,
+    RETICLE_HEIGHT_RATIO_MAX, RETICLE_HEIGHT_RATIO_MIN, RETICLE_WIDTH_RATIO_MAX, RETICLE_WIDTH_RATIO_MIN,
+    WORD_FADE_MS_MAX, WORD_FADE_MS_MIN, WORD_PREVIEW_COUNT_MAX, WORD_PREVIEW_COUNT_MIN,
+};
+use crate::playback::{ResumeRewind, RESUME_REWIND_MAX};
+use crate::reader::{
+    TimingConfig, FONT_SIZE_MIN, FONT_SIZE_MAX, LONG_WORD_THRESHOLD_MAX, LONG_WORD_THRESHOLD_MIN, MIN_WORD_MS_MAX,
+    PAUSE_MULTIPLIER_MAX, PAUSE_MULTIPLIER_MIN, STOP_WORD_MULTIPLIER_MAX, STOP_WORD_MULTIPLIER_MIN, WARM_UP_START_FRACTION_MAX, WARM_UP_START_FRACTION_MIN,
+    WARM_UP_WORDS_MAX, WARM_UP_WORDS_MIN,
+    WPM_MIN, WPM_MAX, WPM_STEP,
+};
+#[cfg(not(target_arch = "wasm32"))]
+use crate::persistence::OpenDataDir;
+use crate::persistence::{ClearAllData, ClearOrphanCaches, MeasureStorage, ProgramState, StorageStatus, StorageUsage};
+use crate::stats::{local_date, DailyGoal, GoalUnit, ReadingStats, SessionRecord};
+use crate::tabs::{ActiveTab, ApplyDefaultsToAll, DefaultTabSettings, HomepageTab, TabCloseConfirm, TabTitleWidth, TAB_TITLE_WIDTH_MAX, TAB_TITLE_WIDTH_MIN};
+use crate::theme::{ReaderBackground, Theme, READER_DIM_MAX};
+#[cfg(all(feature = "update_check", not(target_arch = "wasm32")))]
+use crate::update_check::{AvailableUpdate, UpdateCheck};
+use super::labels::{format_bytes, format_count, format_duration};
 
+const CLEAR_ALL_CONFIRMATION: &str = "DELETE";
 const TILE_ROUNDING: u8 = 6;
 const TILE_INNER_MARGIN: i8 = 12;
 const COLOR_ABOUT: egui::Color32 = egui::Color32::from_rgb(45, 55, 72);
 const COLOR_FONT: egui::Color32 = egui::Color32::from_rgb(56, 78, 56);
 const COLOR_SHORTCUTS: egui::Color32 = egui::Color32::from_rgb(78, 56, 72);
-#[allow(dead_code)]
 const COLOR_STATS: egui::Color32 = egui::Color32::from_rgb(56, 68, 82);
 const COLOR_TIPS: egui::Color32 = egui::Color32::from_rgb(72, 62, 48);
+const COLOR_DISPLAY: egui::Color32 = egui::Color32::from_rgb(48, 66, 74);
+const COLOR_TIMING: egui::Color32 = egui::Color32::from_rgb(70, 64, 52);
+const COLOR_STORAGE: egui::Color32 = egui::Color32::from_rgb(62, 58, 80);
+const COLOR_WARNING_TEXT: egui::Color32 = egui::Color32::from_rgb(235, 190, 110);
+const COLOR_GOAL_MET: egui::Color32 = egui::Color32::from_rgb(110, 190, 110);
+const COLOR_STAT_VALUE: egui::Color32 = egui::Color32::from_rgb(140, 200, 200);
+const SPARKLINE_HEIGHT: f32 = 32.0;
+#[cfg(all(feature = "update_check", not(target_arch = "wasm32")))]
+const COLOR_UPDATE_TEXT: egui::Color32 = egui::Color32::from_rgb(150, 220, 150);
 const COLOR_TILE_TEXT: egui::Color32 = egui::Color32::from_rgb(187, 197, 214);
 const WEBSITE_PLACEHOLDER_URL: &str = "https://arrekin.com/?utm_source=arre-mind-reader";
 
@@ -51,6 +87,7 @@ pub struct TileVisuals {
 pub struct HomepageTile;
 impl HomepageTile {
     /// Run condition: returns true when the homepage tab is active.
+    #[allow(clippy::type_complexity)]
     pub fn is_active(
         query: Option<Single<(), (With<HomepageTab>, With<ActiveTab>)>>,
     ) -> bool {
@@ -63,28 +100,46 @@ impl HomepageTile {
         // centered automatically when the window is resized.
         commands.spawn((
             AboutTile,
-            TilePosition(Vec2::new(0.0, -94.0)),
-            TileSize(Vec2::new(380.0, 380.0)),
+            TilePosition(Vec2::new(0.0, -60.0)),
+            TileSize(Vec2::new(380.0, 450.0)),
             TileVisuals { title: "About", color: COLOR_ABOUT },
         ));
+        commands.spawn((
+            StatsTile,
+            TilePosition(Vec2::new(400.0, -262.0)),
+            TileSize(Vec2::new(260.0, 210.0)),
+            TileVisuals { title: "Reading Stats", color: COLOR_STATS },
+        ));
         commands.spawn((
             FontSettingsTile,
-            TilePosition(Vec2::new(400.0, -94.0)),
+            TilePosition(Vec2::new(400.0, -20.0)),
             TileSize(Vec2::new(260.0, 220.0)),
             TileVisuals { title: "Default Tab Settings", color: COLOR_FONT },
         ));
+        commands.spawn((
+            DisplaySettingsTile,
+            TilePosition(Vec2::new(400.0, 220.0)),
+            TileSize(Vec2::new(260.0, 220.0)),
+            TileVisuals { title: "Display Settings", color: COLOR_DISPLAY },
+        ));
+        commands.spawn((
+            TimingTile,
+            TilePosition(Vec2::new(0.0, 275.0)),
+            TileSize(Vec2::new(380.0, 150.0)),
+            TileVisuals { title: "Timing", color: COLOR_TIMING },
+        ));
         commands.spawn((
             ShortcutsTile,
-            TilePosition(Vec2::new(-400.0, -200.0)),
-            TileSize(Vec2::new(200.0, 120.0)),
+            TilePosition(Vec2::new(-400.0, -225.0)),
+            TileSize(Vec2::new(240.0, 210.0)),
             TileVisuals { title: "Keyboard Shortcuts", color: COLOR_SHORTCUTS },
         ));
-        // commands.spawn((
-        //     StatsTile,
-        //     TilePosition(Vec2::new(0.0, 164.0)),
-        //     TileSize(Vec2::new(220.0, 180.0)),
-        //     TileVisuals { title: "Reading Stats", color: COLOR_STATS },
-        // ));
+        commands.spawn((
+            StorageTile,
+            TilePosition(Vec2::new(-400.0, 215.0)),
+            TileSize(Vec2::new(300.0, 200.0)),
+            TileVisuals { title: "Storage", color: COLOR_STORAGE },
+        ));
         commands.spawn((
             TipsTile,
             TilePosition(Vec2::new(-400.0, 0.)),
@@ -111,6 +166,12 @@ pub struct AboutTile;
 impl AboutTile {
     pub fn update(
         mut contexts: EguiContexts,
+        mut wizard: ResMut<CalibrationWizard>,
+        history: Res<CalibrationHistory>,
+        #[cfg(all(feature = "update_check", not(target_arch = "wasm32")))]
+        mut update_check: ResMut<UpdateCheck>,
+        #[cfg(all(feature = "update_check", not(target_arch = "wasm32")))]
+        available_update: Option<Res<AvailableUpdate>>,
         tile: Single<(&TilePosition, &TileSize, &TileVisuals), With<AboutTile>>,
     ) {
         let Ok(ctx) = contexts.ctx_mut() else { return };
@@ -156,6 +217,15 @@ impl AboutTile {
             ui.label("1. Click + New and open a text");
             ui.label("2. Start around 250-350 WPM");
             ui.label("3. Increase by +50 WPM when comprehension stays solid");
+            ui.add_space(6.0);
+            ui.horizontal(|ui| {
+                if ui.button("🎯 Calibrate my WPM").clicked() {
+                    wizard.open();
+                }
+                if let Some(age) = history.describe_age() {
+                    ui.label(egui::RichText::new(format!("calibrated {}", age)).small().italics());
+                }
+            });
             ui.add_space(10.0);
             ui.label(
                 egui::RichText::new("\"Telepathy was hard, so I built RSVP. It's close enough.\" ~ Arrekin")
@@ -164,6 +234,13 @@ impl AboutTile {
                     .color(egui::Color32::from_rgb(223, 223, 105)),
             );
             ui.add_space(10.0);
+            #[cfg(all(feature = "update_check", not(target_arch = "wasm32")))]
+            if let Some(update) = &available_update {
+                ui.horizontal(|ui| {
+                    ui.label(egui::RichText::new(format!("⬆ {} is available", update.name)).color(COLOR_UPDATE_TEXT));
+                    ui.hyperlink_to("Release page", &update.url);
+                });
+            }
             ui.separator();
             ui.add_space(6.0);
             ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
@@ -172,6 +249,9 @@ impl AboutTile {
                     egui::RichText::new(format!("| v{}", env!("CARGO_PKG_VERSION")))
                         .color(egui::Color32::from_rgb(170, 182, 198)),
                 );
+                #[cfg(all(feature = "update_check", not(target_arch = "wasm32")))]
+                ui.checkbox(&mut update_check.enabled, "Check for updates")
+                    .on_hover_text("Looks up the latest GitHub release once a day. Nothing is downloaded.");
             });
         });
     }
@@ -186,6 +266,8 @@ impl FontSettingsTile {
         mut contexts: EguiContexts,
         fonts: Res<FontsStore>,
         mut defaults: ResMut<DefaultTabSettings>,
+        mut close_confirm: ResMut<TabCloseConfirm>,
+        mut title_width: ResMut<TabTitleWidth>,
         tile: Single<(&TilePosition, &TileSize, &TileVisuals), With<FontSettingsTile>>,
     ) {
         let Ok(ctx) = contexts.ctx_mut() else { return };
@@ -217,9 +299,25 @@ impl FontSettingsTile {
 
             ui.add_space(8.0);
             ui.label("WPM:");
-            ui.add(egui::Slider::new(&mut defaults.wpm, WPM_MIN..=WPM_MAX)
-                .step_by(WPM_STEP as f64)
-                .suffix(" wpm"));
+            ui.horizontal(|ui| {
+                ui.add(egui::Slider::new(&mut defaults.wpm, WPM_MIN..=WPM_MAX)
+                    .step_by(WPM_STEP as f64)
+                    .show_value(false));
+                ui.add(egui::DragValue::new(&mut defaults.wpm)
+                    .range(WPM_MIN..=WPM_MAX)
+                    .update_while_editing(false)
+                    .suffix(" wpm"));
+            });
+
+            ui.add_space(8.0);
+            ui.checkbox(&mut close_confirm.enabled, "Confirm closing a tab mid-read")
+                .on_hover_text("Ask before closing a tab that is past its first word");
+
+            ui.add_space(8.0);
+            ui.label("Tab title width:");
+            ui.add(egui::Slider::new(&mut title_width.max_chars, TAB_TITLE_WIDTH_MIN..=TAB_TITLE_WIDTH_MAX)
+                .suffix(" chars"))
+                .on_hover_text("Longer tab names are shortened in the middle");
 
             ui.add_space(12.0);
             ui.separator();
@@ -231,22 +329,257 @@ impl FontSettingsTile {
     }
 }
 
+#[derive(Component)]
+#[require(HomepageTile)]
+pub struct DisplaySettingsTile;
+impl DisplaySettingsTile {
+    #[allow(clippy::too_many_arguments)]
+    pub fn update(
+        mut contexts: EguiContexts,
+        mut pacing_pulse: ResMut<PacingPulse>,
+        mut reduce_motion: ResMut<ReduceMotion>,
+        mut auto_fit: ResMut<WordAutoFit>,
+        mut word_fade: ResMut<WordFade>,
+        mut word_preview: ResMut<WordPreview>,
+        mut number_display: ResMut<NumberDisplay>,
+        mut orp_config: ResMut<OrpConfig>,
+        mut reticle_style: ResMut<ReticleStyle>,
+        mut theme: ResMut<Theme>,
+        mut reader_background: ResMut<ReaderBackground>,
+        tile: Single<(&TilePosition, &TileSize, &TileVisuals), With<DisplaySettingsTile>>,
+    ) {
+        let Ok(ctx) = contexts.ctx_mut() else { return };
+        let (position, size, visuals) = tile.into_inner();
+        // Edited on copies so the reader display only refreshes on real changes.
+        let mut orp = orp_config.clone();
+        let mut reticle = reticle_style.clone();
+        let mut selected_theme = *theme;
+        let mut background = reader_background.clone();
+        let mut selected_number_display = *number_display;
+        tile_frame(ctx, "display_settings", position, size, visuals, |ui| {
+            egui::ScrollArea::vertical().show(ui, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label("Theme");
+                    for option in Theme::ALL {
+                        ui.selectable_value(&mut selected_theme, option, option.label());
+                    }
+                });
+                ui.horizontal(|ui| {
+                    ui.checkbox(&mut background.is_custom, "Reader background");
+                    ui.add_enabled_ui(background.is_custom, |ui| {
+                        egui::color_picker::color_edit_button_rgb(ui, &mut background.color);
+                    });
+                }).response.on_hover_text("Off: the theme's background. The word text switches between light and dark to stay legible.");
+                ui.add(egui::Slider::new(&mut background.dim, 0.0..=READER_DIM_MAX)
+                    .custom_formatter(|dim, _| format!("{:.0}%", dim * 100.0))
+                    .text("dim"));
+                ui.add_space(8.0);
+                ui.checkbox(&mut reduce_motion.enabled, "Reduce motion")
+                    .on_hover_text("Keeps the reticles still. The pacing pulse stays off while this is on.");
+                ui.add_enabled_ui(!reduce_motion.enabled, |ui| {
+                    ui.checkbox(&mut pacing_pulse.enabled, "Pacing pulse");
+                    ui.add_enabled_ui(pacing_pulse.enabled, |ui| {
+                        ui.add(egui::Slider::new(&mut pacing_pulse.rate_multiplier, PACING_PULSE_RATE_MIN..=PACING_PULSE_RATE_MAX)
+                            .suffix("× word rate"));
+                        ui.add(egui::Slider::new(&mut pacing_pulse.depth, 0.0..=PACING_PULSE_MAX_DEPTH)
+                            .text("depth"));
+                    });
+                });
+                ui.add_space(4.0);
+                ui.label(
+                    egui::RichText::new("⚠ The pulse flickers the reticles. Leave it off if you are sensitive to flashing light.")
+                        .small()
+                        .color(COLOR_WARNING_TEXT),
+                );
+                ui.add_space(8.0);
+                ui.checkbox(&mut auto_fit.enabled, "Shrink words wider than the window");
+                ui.add_enabled_ui(auto_fit.enabled, |ui| {
+                    ui.add(egui::Slider::new(&mut auto_fit.min_scale, AUTO_FIT_MIN_SCALE_MIN..=AUTO_FIT_MIN_SCALE_MAX)
+                        .text("min scale"));
+                });
+                ui.add_space(8.0);
+                ui.horizontal(|ui| {
+                    ui.checkbox(&mut word_fade.enabled, "Fade words in over");
+                    ui.add_enabled(word_fade.enabled, egui::DragValue::new(&mut word_fade.duration_ms)
+                        .range(WORD_FADE_MS_MIN..=WORD_FADE_MS_MAX)
+                        .suffix(" ms"));
+                }).response.on_hover_text("Softens word swaps while playing. Never takes more than half of a word's time.");
+                ui.add_space(8.0);
+                ui.horizontal(|ui| {
+                    ui.checkbox(&mut word_preview.enabled, "Preview the next");
+                    ui.add_enabled(word_preview.enabled, egui::DragValue::new(&mut word_preview.word_count)
+                        .range(WORD_PREVIEW_COUNT_MIN..=WORD_PREVIEW_COUNT_MAX)
+                        .suffix(" words"));
+                }).response.on_hover_text("Shows the upcoming words faintly under the reader to train peripheral pickup. Hidden while paused.");
+                ui.add_space(8.0);
+                ui.horizontal(|ui| {
+                    ui.label("Long numbers");
+                    for option in NumberDisplay::ALL {
+                        ui.selectable_value(&mut selected_number_display, option, option.label());
+                    }
+                }).response.on_hover_text("How numbers of five or more digits are shown. The text itself is not changed.");
+                ui.add_space(8.0);
+                ui.checkbox(&mut orp.use_pivot_fraction, "Custom fixation point")
+                    .on_hover_text("Off: the highlighted letter is picked by word length");
+                ui.add_enabled_ui(orp.use_pivot_fraction, |ui| {
+                    ui.add(egui::Slider::new(&mut orp.pivot_fraction, ORP_PIVOT_FRACTION_MIN..=ORP_PIVOT_FRACTION_MAX)
+                        .custom_formatter(|fraction, _| format!("{:.0}%", fraction * 100.0))
+                        .text("into the word"));
+                });
+                ui.add_space(8.0);
+                ui.horizontal(|ui| {
+                    ui.checkbox(&mut reticle.visible, "Reticles");
+                    ui.add_enabled_ui(reticle.visible, |ui| {
+                        egui::color_picker::color_edit_button_rgb(ui, &mut reticle.color);
+                    });
+                });
+                ui.add_enabled_ui(reticle.visible, |ui| {
+                    ui.add(egui::Slider::new(&mut reticle.width_ratio, RETICLE_WIDTH_RATIO_MIN..=RETICLE_WIDTH_RATIO_MAX)
+                        .text("width"));
+                    ui.add(egui::Slider::new(&mut reticle.height_ratio, RETICLE_HEIGHT_RATIO_MIN..=RETICLE_HEIGHT_RATIO_MAX)
+                        .text("height"));
+                });
+            });
+        });
+        if orp != *orp_config {
+            *orp_config = orp;
+        }
+        if reticle != *reticle_style {
+            *reticle_style = reticle;
+        }
+        if selected_theme != *theme {
+            *theme = selected_theme;
+        }
+        if background != *reader_background {
+            *reader_background = background;
+        }
+        if selected_number_display != *number_display {
+            *number_display = selected_number_display;
+        }
+    }
+}
+
+/// Pause multipliers applied on top of the base per-word delay.
+#[derive(Component)]
+#[require(HomepageTile)]
+pub struct TimingTile;
+impl TimingTile {
+    pub fn update(
+        mut contexts: EguiContexts,
+        mut timing: ResMut<TimingConfig>,
+        mut resume_rewind: ResMut<ResumeRewind>,
+        mut break_reminder: ResMut<BreakReminder>,
+        #[cfg(feature = "metronome")]
+        mut metronome: ResMut<Metronome>,
+        tile: Single<(&TilePosition, &TileSize, &TileVisuals), With<TimingTile>>,
+    ) {
+        let Ok(ctx) = contexts.ctx_mut() else { return };
+        let (position, size, visuals) = tile.into_inner();
+        tile_frame(ctx, "timing", position, size, visuals, |ui| {
+            egui::ScrollArea::vertical().show(ui, |ui| {
+                ui.horizontal(|ui| {
+                    ui.add(egui::DragValue::new(&mut timing.long_word_threshold)
+                        .range(LONG_WORD_THRESHOLD_MIN..=LONG_WORD_THRESHOLD_MAX)
+                        .prefix("> ")
+                        .suffix(" chars"));
+                    ui.add(pause_slider(&mut timing.long_word_multiplier, "long word"));
+                });
+                ui.add(pause_slider(&mut timing.comma_multiplier, "comma"));
+                ui.add(pause_slider(&mut timing.line_end_multiplier, "line break"))
+                    .on_hover_text("Pause after each line in tabs created with \"Preserve line breaks\"");
+                ui.add(pause_slider(&mut timing.sentence_end_multiplier, "sentence end"));
+                ui.add(pause_slider(&mut timing.paragraph_end_multiplier, "paragraph end"));
+                ui.checkbox(&mut timing.rare_word_enabled, "Slow down on rare words")
+                    .on_hover_text("Words longer than 6 letters that are not common English words");
+                ui.add_enabled(timing.rare_word_enabled, pause_slider(&mut timing.rare_word_multiplier, "rare word"));
+                ui.checkbox(&mut timing.stop_word_enabled, "Speed up stop words")
+                    .on_hover_text("Short function words like \"the\" and \"of\", when no pause applies to them");
+                ui.add_enabled(timing.stop_word_enabled, egui::Slider::new(&mut timing.stop_word_multiplier, STOP_WORD_MULTIPLIER_MIN..=STOP_WORD_MULTIPLIER_MAX)
+                    .step_by(0.05)
+                    .suffix("×")
+                    .text("stop word"));
+                ui.horizontal(|ui| {
+                    ui.label("Minimum per word:");
+                    ui.add(egui::DragValue::new(&mut timing.min_word_ms)
+                        .range(0..=MIN_WORD_MS_MAX)
+                        .suffix(" ms"));
+                });
+                ui.label(
+                    egui::RichText::new("When several pauses apply to a word, the longest one is used.")
+                        .small()
+                        .color(COLOR_TILE_TEXT),
+                );
+                ui.add_space(8.0);
+                ui.checkbox(&mut timing.warm_up_enabled, "Warm-up on Play")
+                    .on_hover_text("Start slower and ramp up to the tab's WPM");
+                ui.add_enabled_ui(timing.warm_up_enabled, |ui| {
+                    ui.horizontal(|ui| {
+                        ui.add(egui::Slider::new(&mut timing.warm_up_start_fraction, WARM_UP_START_FRACTION_MIN..=WARM_UP_START_FRACTION_MAX)
+                            .custom_formatter(|fraction, _| format!("{:.0}%", fraction * 100.0))
+                            .text("start speed over"));
+                        ui.add(egui::DragValue::new(&mut timing.warm_up_words)
+                            .range(WARM_UP_WORDS_MIN..=WARM_UP_WORDS_MAX)
+                            .suffix(" words"));
+                    });
+                });
+                ui.horizontal(|ui| {
+                    ui.label("Rewind on resume:");
+                    ui.add(egui::DragValue::new(&mut resume_rewind.0)
+                        .range(0..=RESUME_REWIND_MAX)
+                        .suffix(" words"));
+                }).response.on_hover_text("Step back this many words when Play follows a pause");
+                ui.add_space(8.0);
+                ui.horizontal(|ui| {
+                    ui.checkbox(&mut break_reminder.enabled, "Break reminder every");
+                    ui.add_enabled(break_reminder.enabled, egui::DragValue::new(&mut break_reminder.interval_mins)
+                        .range(BREAK_INTERVAL_MINS_MIN..=BREAK_INTERVAL_MINS_MAX)
+                        .suffix(" min"));
+                }).response.on_hover_text("Pause and suggest a break after this much reading. Stopping for 5 minutes counts as a break.");
+                #[cfg(feature = "metronome")]
+                {
+                    ui.add_space(8.0);
+                    ui.horizontal(|ui| {
+                        ui.checkbox(&mut metronome.enabled, "Click on each word");
+                        ui.add_enabled(metronome.enabled, egui::Slider::new(&mut metronome.volume, 0.0..=1.0)
+                            .custom_formatter(|volume, _| format!("{:.0}%", volume * 100.0))
+                            .text("volume"));
+                    });
+                    ui.add_enabled(metronome.enabled, egui::Checkbox::new(&mut metronome.accent_sentence_ends, "Lower tone at sentence ends"));
+                }
+            });
+        });
+    }
+}
+
 #[derive(Component)]
 #[require(HomepageTile)]
 pub struct ShortcutsTile;
 impl ShortcutsTile {
     pub fn update(
         mut contexts: EguiContexts,
+        key_bindings: Res<KeyBindings>,
+        mut word_skip: ResMut<WordSkip>,
         tile: Single<(&TilePosition, &TileSize, &TileVisuals), With<ShortcutsTile>>,
     ) {
         let Ok(ctx) = contexts.ctx_mut() else { return };
         let (position, size, visuals) = tile.into_inner();
-        let wpm_adjust_description = format!("Adjust WPM ±{}", WPM_STEP);
         tile_frame(ctx, "shortcuts", position, size, visuals, |ui| {
-            Self::shortcut_row(ui, "Space", "Play / Pause");
-            Self::shortcut_row(ui, "← / →", "Skip 5 words");
-            Self::shortcut_row(ui, "↑ / ↓", &wpm_adjust_description);
-            Self::shortcut_row(ui, "R", "Restart");
+            egui::ScrollArea::vertical().show(ui, |ui| {
+                for category in [ActionCategory::Playback, ActionCategory::Navigation] {
+                    for (chords, description) in key_bindings.describe_category(category, &word_skip) {
+                        Self::shortcut_row(ui, &chords, &description);
+                    }
+                }
+                ui.add_space(4.0);
+                ui.horizontal(|ui| {
+                    ui.label("Skip by");
+                    ui.add(egui::DragValue::new(&mut word_skip.0)
+                        .range(WORD_SKIP_MIN..=WORD_SKIP_MAX)
+                        .suffix(" words"));
+                });
+                ui.add_space(4.0);
+                ui.weak(format!("Hold {} for all shortcuts", CheatSheet::keys_label()));
+            });
         });
     }
 
@@ -259,23 +592,54 @@ impl ShortcutsTile {
     }
 }
 
+/// Lifetime totals and per-session WPM from `ReadingStats`, and the `DailyGoal`.
 #[derive(Component)]
 #[require(HomepageTile)]
-#[allow(dead_code)]
 pub struct StatsTile;
-#[allow(dead_code)]
 impl StatsTile {
     pub fn update(
         mut contexts: EguiContexts,
+        stats: Res<ReadingStats>,
+        mut goal: ResMut<DailyGoal>,
         tile: Single<(&TilePosition, &TileSize, &TileVisuals), With<StatsTile>>,
     ) {
         let Ok(ctx) = contexts.ctx_mut() else { return };
         let (position, size, visuals) = tile.into_inner();
         tile_frame(ctx, "stats", position, size, visuals, |ui| {
-            Self::stat_row(ui, "Total words read", "12,847");
-            Self::stat_row(ui, "Sessions", "23");
-            Self::stat_row(ui, "Avg WPM", "342");
-            Self::stat_row(ui, "Books finished", "2");
+            Self::stat_row(ui, "Total words read", &format_count(stats.words_read));
+            Self::stat_row(ui, "Reading time", &format_duration(Duration::from_secs_f64(stats.reading_secs)));
+            Self::stat_row(ui, "Sessions", &format_count(stats.sessions as u64));
+            let average_wpm = if stats.average_wpm > 0.0 { format!("{:.0}", stats.average_wpm) } else { "–".to_string() };
+            Self::stat_row(ui, "Avg WPM", &average_wpm);
+            Self::wpm_sparkline(ui, &stats.history);
+
+            ui.add_space(4.0);
+            ui.horizontal(|ui| {
+                ui.checkbox(&mut goal.enabled, "Daily goal");
+                ui.add_enabled_ui(goal.enabled, |ui| {
+                    ui.add(egui::DragValue::new(goal.target_mut()).range(1..=100_000));
+                    egui::ComboBox::from_id_salt("daily_goal_unit")
+                        .selected_text(goal.unit.label())
+                        .show_ui(ui, |ui| {
+                            for unit in GoalUnit::ALL {
+                                ui.selectable_value(&mut goal.unit, unit, unit.label());
+                            }
+                        });
+                });
+            });
+            if goal.enabled {
+                let today = local_date();
+                let (done, target) = (goal.done(&today), goal.target());
+                let readout = format!("{}/{} {} today", format_count(done), format_count(target), goal.unit.label());
+                let mut bar = egui::ProgressBar::new((done as f32 / target.max(1) as f32).min(1.0)).text(readout);
+                if goal.is_met(&today) {
+                    bar = bar.fill(COLOR_GOAL_MET);
+                }
+                ui.add(bar);
+                if goal.is_met(&today) {
+                    ui.label(egui::RichText::new("🎉 Goal met. Increase the WPM. Repeat.").color(COLOR_GOAL_MET));
+                }
+            }
         });
     }
 
@@ -284,11 +648,42 @@ impl StatsTile {
             ui.label(label);
             ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
                 ui.strong(egui::RichText::new(value)
-                    .color(egui::Color32::from_rgb(140, 200, 200)));
+                    .color(COLOR_STAT_VALUE));
             });
         });
     }
 
+    /// WPM of each recorded session, oldest on the left, scaled between the
+    /// slowest and fastest one. Hovering shows the nearest session.
+    fn wpm_sparkline(ui: &mut egui::Ui, history: &[SessionRecord]) {
+        let (rect, response) = ui.allocate_exact_size(egui::vec2(ui.available_width(), SPARKLINE_HEIGHT), egui::Sense::hover());
+        let painter = ui.painter_at(rect);
+        if history.len() < 2 {
+            painter.text(rect.center(), egui::Align2::CENTER_CENTER, "WPM trend after two sessions", egui::FontId::proportional(11.0), COLOR_TILE_TEXT.linear_multiply(0.6));
+            return;
+        }
+        let (min_wpm, max_wpm) = history.iter()
+            .fold((f64::MAX, f64::MIN), |(min, max), record| (min.min(record.wpm), max.max(record.wpm)));
+        let range = (max_wpm - min_wpm).max(1.0);
+        let step = rect.width() / (history.len() - 1) as f32;
+        let points: Vec<egui::Pos2> = history.iter().enumerate()
+            .map(|(index, record)| egui::pos2(
+                rect.left() + index as f32 * step,
+                rect.bottom() - ((record.wpm - min_wpm) / range) as f32 * rect.height(),
+            ))
+            .collect();
+        painter.add(egui::Shape::line(points.clone(), egui::Stroke::new(1.5, COLOR_STAT_VALUE)));
+        if let Some(&last) = points.last() {
+            painter.circle_filled(last, 2.5, COLOR_STAT_VALUE);
+        }
+        if let Some(pointer) = response.hover_pos() {
+            let index = (((pointer.x - rect.left()) / step).round() as usize).min(history.len() - 1);
+            let record = &history[index];
+            painter.circle_stroke(points[index], 3.5, egui::Stroke::new(1.0, egui::Color32::WHITE));
+            response.on_hover_text(format!("{}: {:.0} WPM, {} words", record.date, record.wpm, format_count(record.words)));
+        }
+    }
+
 }
 
 #[derive(Component)]
@@ -317,8 +712,93 @@ impl TipsTile {
     }
 }
 
+#[derive(Component)]
+#[require(HomepageTile)]
+pub struct StorageTile;
+impl StorageTile {
+    pub fn update(
+        mut commands: Commands,
+        mut contexts: EguiContexts,
+        storage_status: Res<StorageStatus>,
+        storage_usage: Res<StorageUsage>,
+        mut confirmation: Local<Option<String>>,
+        tile: Single<(&TilePosition, &TileSize, &TileVisuals), With<StorageTile>>,
+    ) {
+        let Ok(ctx) = contexts.ctx_mut() else { return };
+        if storage_usage.usage.is_none() && !storage_usage.is_measuring() {
+            commands.trigger(MeasureStorage);
+        }
+        let (position, size, visuals) = tile.into_inner();
+        tile_frame(ctx, "storage", position, size, visuals, |ui| {
+            ui.horizontal(|ui| {
+                ui.label("Location:");
+                #[cfg(not(target_arch = "wasm32"))]
+                if ui.small_button("📂 Open").clicked() {
+                    commands.trigger(OpenDataDir);
+                }
+            });
+            ui.label(egui::RichText::new(ProgramState::storage_location()).small().monospace());
+            ui.add_space(4.0);
+
+            match &storage_usage.usage {
+                Some(usage) => {
+                    ui.label(format!("Word caches: {} ({})", usage.file_count, format_bytes(usage.total_bytes)));
+                    ui.horizontal(|ui| {
+                        ui.label(format!("Orphans: {}", usage.orphan_count));
+                        if ui.add_enabled(usage.orphan_count > 0, egui::Button::new("Clear").small()).clicked() {
+                            commands.trigger(ClearOrphanCaches);
+                        }
+                    });
+                }
+                None => {
+                    ui.label("Word caches: measuring…");
+                }
+            }
+            let last_save = storage_status.describe_last_save().unwrap_or_else(|| "not yet".to_string());
+            ui.label(format!("Last saved: {}", last_save));
+            ui.add_space(4.0);
+
+            let mut is_closing_confirmation = false;
+            match confirmation.as_mut() {
+                None => {
+                    if ui.button("Clear all data…").clicked() {
+                        *confirmation = Some(String::new());
+                    }
+                }
+                Some(typed) => {
+                    ui.horizontal(|ui| {
+                        ui.add(egui::TextEdit::singleline(typed)
+                            .desired_width(70.0)
+                            .hint_text(CLEAR_ALL_CONFIRMATION));
+                        let is_confirmed = typed.trim() == CLEAR_ALL_CONFIRMATION;
+                        if ui.add_enabled(is_confirmed, egui::Button::new("Delete everything"))
+                            .on_hover_text("Closes all tabs and deletes saved tabs, settings and caches")
+                            .clicked()
+                        {
+                            commands.trigger(ClearAllData);
+                            is_closing_confirmation = true;
+                        } else if ui.small_button("Cancel").clicked() {
+                            is_closing_confirmation = true;
+                        }
+                    });
+                }
+            }
+            if is_closing_confirmation {
+                *confirmation = None;
+            }
+        });
+    }
+}
+
 // ── Shared frame helper ─────────────────────────────────────────────────────
 
+fn pause_slider<'a>(value: &'a mut f32, text: &str) -> egui::Slider<'a> {
+    egui::Slider::new(value, PAUSE_MULTIPLIER_MIN..=PAUSE_MULTIPLIER_MAX)
+        .step_by(0.1)
+        .suffix("×")
+        .text(text)
+}
+
 /// Renders the shared chrome for a homepage tile: positioned `egui::Area` with
 /// colored background, rounded corners, title heading, and separator.
 fn tile_frame(
@@ -0,0 +1,178 @@
+//! Label formatting helpers shared by UI components.
+
+use std::borrow::Cow;
+use std::time::Duration;
+
+use bevy_egui::egui;
+
+use crate::tabs::{TabTitleWidth, TAB_TITLE_WIDTH_MAX, TAB_TITLE_WIDTH_MIN};
+
+const ELLIPSIS: char = '…';
+const BYTE_UNITS: [&str; 4] = ["B", "KB", "MB", "GB"];
+
+/// Shortens `name` to at most `max_chars` characters by replacing its middle
+/// with an ellipsis. Both the start and the end are kept, since the end of a
+/// title often holds a volume or part number. Operates on `char`s, so a UTF-8
+/// codepoint is never split.
+pub fn truncate_middle(name: &str, max_chars: usize) -> Cow<'_, str> {
+    let char_count = name.chars().count();
+    if char_count <= max_chars {
+        return Cow::Borrowed(name);
+    }
+    let kept = max_chars.saturating_sub(1);
+    let head_count = kept.div_ceil(2);
+    let tail_count = kept - head_count;
+
+    let head = name.chars().take(head_count);
+    let tail = name.chars().skip(char_count - tail_count);
+    Cow::Owned(head.chain(std::iter::once(ELLIPSIS)).chain(tail).collect())
+}
+
+/// Tab name shortened to the `TabTitleWidth` setting with `truncate_middle`.
+pub fn tab_title<'a>(name: &'a str, width: &TabTitleWidth) -> Cow<'a, str> {
+    truncate_middle(name, width.max_chars.clamp(TAB_TITLE_WIDTH_MIN, TAB_TITLE_WIDTH_MAX))
+}
+
+/// Shows a tab name in bold, shortened like `tab_title`, with the full name
+/// on hover when shortened.
+pub fn strong_tab_title(ui: &mut egui::Ui, name: &str, width: &TabTitleWidth) {
+    let title = tab_title(name, width);
+    let is_truncated = title != name;
+    let response = ui.strong(title);
+    if is_truncated {
+        response.on_hover_text(name);
+    }
+}
+
+/// Formats a byte count with a binary-scaled unit, e.g. "1.4 MB".
+pub fn format_bytes(bytes: u64) -> String {
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < BYTE_UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{} {}", bytes, BYTE_UNITS[0])
+    } else {
+        format!("{:.1} {}", value, BYTE_UNITS[unit])
+    }
+}
+
+/// Formats a count with thousands separators, e.g. "12,847".
+pub fn format_count(count: u64) -> String {
+    let digits = count.to_string();
+    let mut formatted = String::with_capacity(digits.len() + digits.len() / 3);
+    for (index, digit) in digits.chars().enumerate() {
+        if index > 0 && (digits.len() - index).is_multiple_of(3) {
+            formatted.push(',');
+        }
+        formatted.push(digit);
+    }
+    formatted
+}
+
+/// Formats a long span coarsely, e.g. "45s", "12m", "3h 05m".
+pub fn format_duration(duration: Duration) -> String {
+    let secs = duration.as_secs();
+    match (secs / 3600, secs / 60 % 60) {
+        (0, 0) => format!("{}s", secs),
+        (0, minutes) => format!("{}m", minutes),
+        (hours, minutes) => format!("{}h {:02}m", hours, minutes),
+    }
+}
+
+/// Formats a span as minutes and seconds, e.g. "07:05" or "125:00".
+pub fn format_clock(duration: Duration) -> String {
+    let secs = duration.as_secs();
+    format!("{:02}:{:02}", secs / 60, secs % 60)
+}
+
+/// Formats an estimate of time left, e.g. "~45s", "~4m 12s", "~1h 05m".
+pub fn format_time_left(duration: Duration) -> String {
+    let secs = duration.as_secs();
+    match (secs / 3600, secs / 60 % 60) {
+        (0, 0) => format!("~{}s", secs),
+        (0, minutes) => format!("~{}m {:02}s", minutes, secs % 60),
+        (hours, minutes) => format!("~{}h {:02}m", hours, minutes),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn truncate_middle_keeps_short_names_unchanged() {
+        assert!(matches!(truncate_middle("Dune", 10), Cow::Borrowed("Dune")));
+    }
+
+    #[test]
+    fn truncate_middle_keeps_exact_limit_names_unchanged() {
+        assert_eq!(truncate_middle("abcdefghij", 10), "abcdefghij");
+        assert_eq!(truncate_middle("abcdefghijk", 10), "abcde…hijk");
+    }
+
+    #[test]
+    fn truncate_middle_keeps_start_and_end() {
+        let name = "The Count of Monte Cristo (Complete and Unabridged) Vol. 2";
+        let truncated = truncate_middle(name, 20);
+
+        assert_eq!(truncated.chars().count(), 20);
+        assert!(truncated.starts_with("The Count"));
+        assert!(truncated.ends_with("Vol. 2"));
+    }
+
+    #[test]
+    fn truncate_middle_never_splits_multi_byte_characters() {
+        let truncated = truncate_middle("ąćęłńóśźżąćęłńóśźż", 7);
+
+        assert_eq!(truncated, "ąćę…śźż");
+        assert_eq!(truncate_middle("👍👍👍👍👍", 3), "👍…👍");
+    }
+
+    #[test]
+    fn tab_title_clamps_the_width_setting() {
+        let name = "x".repeat(100);
+
+        assert_eq!(tab_title(&name, &TabTitleWidth { max_chars: 0 }).chars().count(), TAB_TITLE_WIDTH_MIN);
+        assert_eq!(tab_title(&name, &TabTitleWidth { max_chars: 1000 }).chars().count(), TAB_TITLE_WIDTH_MAX);
+        assert_eq!(tab_title(&name, &TabTitleWidth::default()).chars().count(), 28);
+    }
+
+    #[test]
+    fn format_bytes_scales_units() {
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(1536), "1.5 KB");
+        assert_eq!(format_bytes(3 * 1024 * 1024), "3.0 MB");
+        assert_eq!(format_bytes(5 * 1024 * 1024 * 1024 * 1024), "5120.0 GB");
+    }
+
+    #[test]
+    fn format_count_groups_thousands() {
+        assert_eq!(format_count(0), "0");
+        assert_eq!(format_count(999), "999");
+        assert_eq!(format_count(12_847), "12,847");
+        assert_eq!(format_count(1_000_000), "1,000,000");
+    }
+
+    #[test]
+    fn format_clock_counts_minutes_past_the_hour() {
+        assert_eq!(format_clock(Duration::from_millis(425_900)), "07:05");
+        assert_eq!(format_clock(Duration::from_secs(125 * 60)), "125:00");
+    }
+
+    #[test]
+    fn format_time_left_keeps_seconds_below_an_hour() {
+        assert_eq!(format_time_left(Duration::from_secs(45)), "~45s");
+        assert_eq!(format_time_left(Duration::from_secs(4 * 60 + 12)), "~4m 12s");
+        assert_eq!(format_time_left(Duration::from_secs(3600 + 5 * 60 + 59)), "~1h 05m");
+    }
+
+    #[test]
+    fn format_duration_drops_detail_as_it_grows() {
+        assert_eq!(format_duration(Duration::from_secs(45)), "45s");
+        assert_eq!(format_duration(Duration::from_secs(12 * 60 + 30)), "12m");
+        assert_eq!(format_duration(Duration::from_secs(3 * 3600 + 5 * 60)), "3h 05m");
+    }
+}
@@ -1,17 +1,39 @@
 //! UI systems using bevy_egui.
 //!
-//! Provides tab bar, playback controls, settings panel, homepage tiles, and the new tab dialog.
+//! Provides tab bar, playback controls, table of contents, settings panel, homepage tiles, the new tab dialog, the close-tab confirmation,
+//! the calibration wizard, the break reminder, toasts, the paused sentence context, the upcoming word preview, the search bar, the vocabulary window,
+//! and the shortcut cheat sheet overlay.
 //! UI components emit events/commands rather than directly mutating state.
 
 mod tab_bar;
 mod controls;
+mod cheat_sheet;
 mod dialogs;
 mod homepage;
+mod labels;
+mod search;
+mod sentence_context;
+mod toasts;
+mod toc;
+mod vocabulary;
+mod word_preview;
 
 use bevy::prelude::*;
 use bevy_egui::EguiPrimaryContextPass;
 
-pub use dialogs::{NewTabDialog, PendingFileLoad};
+use crate::break_reminder::BreakReminder;
+use crate::calibration::CalibrationWizard;
+use crate::input::CheatSheet;
+use crate::orp::WordPreview;
+use crate::reader::ReadingState;
+
+pub use dialogs::{CloseTabDialog, NewTabDialog, PasteDraft, PendingFileLoad, ReparseDialog, StorageWarningDialog};
+#[cfg(not(target_arch = "wasm32"))]
+pub use dialogs::{NewTabSettings, RawFileLoad};
+pub use search::OpenSearchBar;
+pub use toasts::{Toast, ToastKind};
+pub use toc::ToggleTocPanel;
+pub use vocabulary::ToggleVocabularyPanel;
 
 pub struct UiPlugin;
 impl Plugin for UiPlugin {
@@ -19,21 +41,56 @@ impl Plugin for UiPlugin {
         app
             .init_resource::<NewTabDialog>()
             .init_resource::<PendingFileLoad>()
+            .init_resource::<PasteDraft>()
+            .init_resource::<StorageWarningDialog>()
+            .init_resource::<ReparseDialog>()
+            .init_resource::<CloseTabDialog>()
+            .init_resource::<dialogs::SessionSummaryDialog>()
+            .init_resource::<toasts::Toasts>()
             .init_resource::<controls::MarqueeSeed>()
+            .init_resource::<toc::TocPanel>()
+            .init_resource::<search::SearchBar>()
+            .init_resource::<vocabulary::VocabularyPanel>()
             .add_systems(Startup, homepage::HomepageTile::spawn)
-            .add_systems(Update, dialogs::PendingFileLoad::poll)
+            .add_systems(Update, (
+                dialogs::PendingFileLoad::poll,
+                dialogs::PasteDraft::sync,
+                dialogs::StorageWarningDialog::open_on_first_failure,
+            ))
             .add_systems(EguiPrimaryContextPass, (
-                (tab_bar::tab_bar_system, controls::controls_system),
+                (tab_bar::tab_bar_system, controls::controls_system, toc::TocPanel::update),
+                sentence_context::sentence_context_system.run_if(in_state(ReadingState::Paused)),
+                word_preview::word_preview_system.run_if(WordPreview::is_enabled.and(not(in_state(ReadingState::Paused)))),
+                search::SearchBar::update.run_if(search::SearchBar::is_open),
+                vocabulary::VocabularyPanel::update.run_if(vocabulary::VocabularyPanel::is_open),
                 dialogs::NewTabDialog::update.run_if(dialogs::NewTabDialog::is_open),
+                dialogs::calibration_wizard_system.run_if(CalibrationWizard::is_open),
+                dialogs::break_reminder_system.run_if(BreakReminder::is_open),
+                dialogs::StorageWarningDialog::update.run_if(dialogs::StorageWarningDialog::is_open),
+                dialogs::ReparseDialog::update.run_if(dialogs::ReparseDialog::is_open),
+                dialogs::CloseTabDialog::update.run_if(dialogs::CloseTabDialog::is_open),
+                dialogs::SessionSummaryDialog::update.run_if(dialogs::SessionSummaryDialog::is_open),
                 (
                     homepage::HomepageTile::background,
                     homepage::AboutTile::update,
                     homepage::FontSettingsTile::update,
+                    homepage::DisplaySettingsTile::update,
+                    homepage::TimingTile::update,
                     homepage::ShortcutsTile::update,
-                    // homepage::StatsTile::update,
+                    homepage::StatsTile::update,
+                    homepage::StorageTile::update,
                     homepage::TipsTile::update,
                 ).run_if(homepage::HomepageTile::is_active),
+                toasts::Toasts::show.run_if(toasts::Toasts::is_any),
+                cheat_sheet::cheat_sheet_system.run_if(CheatSheet::is_visible),
             ).chain())
+            .add_observer(Toast::on_trigger)
+            .add_observer(ToggleTocPanel::on_trigger)
+            .add_observer(OpenSearchBar::on_trigger)
+            .add_observer(ToggleVocabularyPanel::on_trigger)
+            .add_observer(dialogs::SessionSummaryDialog::open_on_finish)
             ;
+        #[cfg(not(target_arch = "wasm32"))]
+        app.add_systems(Update, dialogs::StorageWarningDialog::poll_folder_pick);
     }
 }
@@ -0,0 +1,127 @@
+//! In-text search bar.
+//!
+//! Finds a phrase in the active tab's words (`Content::find`) and seeks to the
+//! matches one at a time. Enter jumps to the next match, Shift+Enter to the
+//! previous one, and Escape closes the bar.
+
+use bevy::prelude::*;
+use bevy_egui::{EguiContexts, egui};
+
+use crate::reader::ContentNavigate;
+use crate::tabs::{ActiveTab, Content, ReaderTab};
+
+const SEARCH_FIELD_WIDTH: f32 = 220.0;
+/// Offset from the top right corner, clearing the tab bar.
+const SEARCH_BAR_OFFSET: [f32; 2] = [-12.0, 40.0];
+
+/// Search bar state. Matches are recomputed when the query or the searched text changes.
+#[derive(Resource, Default)]
+pub struct SearchBar {
+    open: bool,
+    query: String,
+    /// Focuses the field on the next frame.
+    wants_focus: bool,
+    /// What `matches` were found for: the query, content cache id and word count.
+    searched: Option<(String, String, usize)>,
+    matches: Vec<usize>,
+}
+impl SearchBar {
+    pub fn is_open(search_bar: Res<SearchBar>) -> bool {
+        search_bar.open
+    }
+
+    fn refresh(&mut self, content: &Content) {
+        let searched = (self.query.clone(), content.content_cache_id.clone(), content.words.len());
+        if self.searched.as_ref() != Some(&searched) {
+            self.matches = content.find(&self.query);
+            self.searched = Some(searched);
+        }
+    }
+
+    /// First match after `index`, wrapping around to the first one.
+    fn next_match(&self, index: usize) -> Option<usize> {
+        self.matches.iter().copied().find(|&start| start > index).or(self.matches.first().copied())
+    }
+    /// Last match before `index`, wrapping around to the last one.
+    fn previous_match(&self, index: usize) -> Option<usize> {
+        self.matches.iter().copied().rev().find(|&start| start < index).or(self.matches.last().copied())
+    }
+
+    #[allow(clippy::type_complexity)]
+    pub fn update(
+        mut commands: Commands,
+        mut contexts: EguiContexts,
+        mut search_bar: ResMut<SearchBar>,
+        active_reader: Option<Single<&Content, (With<ActiveTab>, With<ReaderTab>)>>,
+    ) {
+        let Ok(ctx) = contexts.ctx_mut() else { return };
+        let Some(content) = active_reader else { return };
+        let content = content.into_inner();
+        let search_bar = search_bar.as_mut();
+        let mut target = None;
+
+        egui::Window::new("Search")
+            .title_bar(false)
+            .resizable(false)
+            .anchor(egui::Align2::RIGHT_TOP, SEARCH_BAR_OFFSET)
+            .show(ctx, |ui| {
+                ui.horizontal(|ui| {
+                    let field = ui.add(egui::TextEdit::singleline(&mut search_bar.query)
+                        .hint_text("Search…")
+                        .desired_width(SEARCH_FIELD_WIDTH))
+                        .on_hover_text("Searches the words as the reader shows them, without the original formatting");
+                    if std::mem::take(&mut search_bar.wants_focus) {
+                        field.request_focus();
+                    }
+                    search_bar.refresh(content);
+
+                    let (is_enter, is_shift, is_escape) = ui.input(|input| {
+                        (input.key_pressed(egui::Key::Enter), input.modifiers.shift, input.key_pressed(egui::Key::Escape))
+                    });
+                    if field.lost_focus() && is_escape {
+                        search_bar.open = false;
+                    } else if field.lost_focus() && is_enter {
+                        target = if is_shift {
+                            search_bar.previous_match(content.current_index)
+                        } else {
+                            search_bar.next_match(content.current_index)
+                        };
+                        field.request_focus();
+                    }
+
+                    let has_matches = !search_bar.matches.is_empty();
+                    if ui.add_enabled(has_matches, egui::Button::new("⏶")).on_hover_text("Previous match").clicked() {
+                        target = search_bar.previous_match(content.current_index);
+                    }
+                    if ui.add_enabled(has_matches, egui::Button::new("⏷")).on_hover_text("Next match").clicked() {
+                        target = search_bar.next_match(content.current_index);
+                    }
+                    let count = search_bar.matches.len();
+                    let status = match search_bar.matches.binary_search(&content.current_index) {
+                        _ if search_bar.query.trim().is_empty() => String::new(),
+                        _ if count == 0 => "No matches".to_string(),
+                        Ok(position) => format!("{} of {}", position + 1, count),
+                        Err(_) => format!("{} matches", count),
+                    };
+                    ui.label(status);
+                    if ui.small_button("✕").clicked() {
+                        search_bar.open = false;
+                    }
+                });
+            });
+
+        if let Some(index) = target {
+            commands.trigger(ContentNavigate::Seek(index));
+        }
+    }
+}
+
+/// Opens the search bar and focuses its field.
+#[derive(Event)]
+pub struct OpenSearchBar;
+impl OpenSearchBar {
+    pub fn on_trigger(_trigger: On<OpenSearchBar>, mut search_bar: ResMut<SearchBar>) {
+        search_bar.open = true;
+        search_bar.wants_focus = true;
+    }
+}
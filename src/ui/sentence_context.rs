@@ -0,0 +1,66 @@
+//! Sentence context shown under the reader display while paused.
+//!
+//! Lays out the sentence around the current position as one wrapped line of
+//! text, with the words of the current flash in the tab's highlight color.
+
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+use bevy_egui::{EguiContexts, egui};
+
+use crate::orp::NumberDisplay;
+use crate::tabs::{ActiveTab, Content, ReaderTab, TabChunkSize, TabFontSettings};
+use crate::theme::AppliedTheme;
+
+/// Longest context shown for sentences without punctuation.
+const SENTENCE_CONTEXT_MAX_WORDS: usize = 60;
+const SENTENCE_CONTEXT_FONT_SIZE: f32 = 18.0;
+/// Gap below the fixation letter, as a multiple of the tab's font size.
+/// Clears the bottom reticle.
+const SENTENCE_CONTEXT_OFFSET_RATIO: f32 = 1.6;
+const SENTENCE_CONTEXT_WIDTH_RATIO: f32 = 0.6;
+
+#[allow(clippy::type_complexity)]
+pub fn sentence_context_system(
+    mut contexts: EguiContexts,
+    number_display: Res<NumberDisplay>,
+    applied_theme: Res<AppliedTheme>,
+    active_reader: Option<Single<(&Content, &TabChunkSize, &TabFontSettings), (With<ActiveTab>, With<ReaderTab>)>>,
+    window: Single<&Window, With<PrimaryWindow>>,
+) {
+    let Ok(ctx) = contexts.ctx_mut() else { return };
+    let Some(active_reader) = active_reader else { return };
+    let (content, chunk_size, font_settings) = active_reader.into_inner();
+    if !content.has_words() { return; }
+
+    let sentence = content.current_sentence(SENTENCE_CONTEXT_MAX_WORDS);
+    let flash = content.current_index..content.current_index + chunk_size.0.max(1);
+    let [red, green, blue] = font_settings.highlight_color;
+    let [highlight_red, highlight_green, highlight_blue, _] = Color::srgb(red, green, blue).to_srgba().to_u8_array();
+    let highlight = egui::Color32::from_rgb(highlight_red, highlight_green, highlight_blue);
+    let font_id = egui::FontId::proportional(SENTENCE_CONTEXT_FONT_SIZE);
+    // Follows the reader background, which may differ from the egui theme.
+    let [text_red, text_green, text_blue, _] = applied_theme.text_color().to_srgba().to_u8_array();
+    let text_color = egui::Color32::from_rgb(text_red, text_green, text_blue);
+
+    let mut job = egui::text::LayoutJob {
+        halign: egui::Align::Center,
+        ..default()
+    };
+    job.wrap.max_width = window.width() * SENTENCE_CONTEXT_WIDTH_RATIO;
+    for index in sentence.clone() {
+        let Some(word) = content.words.get(index) else { continue };
+        let color = if flash.contains(&index) { highlight } else { text_color };
+        let separator = if index == sentence.start { "" } else { " " };
+        job.append(separator, 0.0, egui::TextFormat::simple(font_id.clone(), text_color));
+        job.append(&number_display.apply(word.text), 0.0, egui::TextFormat::simple(font_id.clone(), color));
+    }
+
+    let offset = font_settings.font_size * SENTENCE_CONTEXT_OFFSET_RATIO;
+    egui::Area::new(egui::Id::new("sentence_context"))
+        .pivot(egui::Align2::CENTER_TOP)
+        .fixed_pos(egui::pos2(window.width() * 0.5, window.height() * 0.5 + offset))
+        .interactable(false)
+        .show(ctx, |ui| {
+            ui.label(job);
+        });
+}
@@ -1,49 +1,156 @@
 //! Tab bar UI component.
 //!
-//! Renders the tab strip and emits TabSelect/TabClose events.
+//! Renders the tab strip and emits TabSelect/TabClose/TabRename events.
+//! Double-clicking a reader tab's title edits it in place; right-clicking it
+//! opens a menu with the other tab actions. The strip scrolls sideways when the
+//! tabs overflow the window, bringing a newly active tab into view.
 
 use bevy::prelude::*;
 use bevy_egui::{EguiContexts, egui};
 
-use crate::tabs::{ActiveTab, HomepageTab, TabClose, TabMarker, TabOrder, TabSelect};
-use super::NewTabDialog;
+use crate::export::TabExportText;
+use crate::playback::PlaybackCommand;
+use crate::tabs::{
+    ActiveTab, Content, EphemeralTab, HomepageTab, ReadTime, TabBookMeta, TabCloseConfirm, TabDuplicate, TabMarker, TabOrder,
+    TabRename, TabSelect, TabTextStats, TabTitleWidth, TabWpm,
+};
+use super::{CloseTabDialog, NewTabDialog};
+use super::labels::{format_clock, format_count, format_duration, tab_title};
 
+const RENAME_FIELD_WIDTH: f32 = 160.0;
+/// Space kept free for the "+ New" button to the right of the tab strip.
+const NEW_TAB_BUTTON_WIDTH: f32 = 64.0;
+
+#[allow(clippy::collapsible_if, clippy::too_many_arguments, clippy::type_complexity)]
 pub fn tab_bar_system(
     mut commands: Commands,
     mut contexts: EguiContexts,
     mut dialog: ResMut<NewTabDialog>,
+    mut close_dialog: ResMut<CloseTabDialog>,
+    close_confirm: Res<TabCloseConfirm>,
+    title_width: Res<TabTitleWidth>,
+    mut renaming: Local<Option<(Entity, String)>>,
+    mut is_rename_started: Local<bool>,
+    mut scrolled_to: Local<Option<Entity>>,
     tab_order: Res<TabOrder>,
-    tabs: Query<(&Name, Has<HomepageTab>, Has<ActiveTab>), With<TabMarker>>,
+    tabs: Query<(&Name, Has<HomepageTab>, Has<ActiveTab>, Has<EphemeralTab>, Option<&TabTextStats>, Option<&TabWpm>, Option<&ReadTime>, Option<&TabBookMeta>, Option<&Content>), With<TabMarker>>,
 ) {
     let Ok(ctx) = contexts.ctx_mut() else { return };
     
     egui::TopBottomPanel::top("tabs").show(ctx, |ui| {
         ui.horizontal(|ui| {
-            for &entity in tab_order.entities().iter() {
-                let Ok((name, is_homepage, is_active)) = tabs.get(entity) else { continue };
+            // Leaves room for the "+ New" button, which stays outside the scrolled strip.
+            let strip_width = (ui.available_width() - NEW_TAB_BUTTON_WIDTH).max(0.0);
+            egui::ScrollArea::horizontal()
+                .max_width(strip_width)
+                .show(ui, |ui| ui.horizontal(|ui| {
+                    for &entity in tab_order.entities().iter() {
+                        let Ok((name, is_homepage, is_active, is_ephemeral, stats, wpm, read_time, book_meta, content)) = tabs.get(entity) else { continue };
+                        let current_index = content.map_or(0, |content| content.current_index);
                 
-                let label = if is_active {
-                    egui::RichText::new(name.as_str()).strong()
-                } else {
-                    egui::RichText::new(name.as_str())
-                };
+                        let title = tab_title(name.as_str(), &title_width);
+                        let is_truncated = title != name.as_str();
+                        let label = if is_active {
+                            egui::RichText::new(title).strong()
+                        } else {
+                            egui::RichText::new(title)
+                        };
                 
-                ui.horizontal(|ui| {
-                    if ui.selectable_label(is_active, label).clicked() {
-                        commands.trigger(TabSelect::from(entity));
-                    }
-                    if !is_homepage {
-                        if ui.small_button("×").clicked() {
-                            commands.trigger(TabClose::from(entity));
-                        }
+                        ui.horizontal(|ui| {
+                            if is_ephemeral {
+                                ui.label("👻").on_hover_text("Ephemeral: not saved, gone after exit");
+                            }
+                            if let Some((renamed, edited)) = &mut *renaming && *renamed == entity {
+                                let field = ui.add(egui::TextEdit::singleline(edited).desired_width(RENAME_FIELD_WIDTH));
+                                if std::mem::take(&mut *is_rename_started) {
+                                    field.request_focus();
+                                }
+                                if field.lost_focus() {
+                                    if !ui.input(|input| input.key_pressed(egui::Key::Escape)) {
+                                        commands.trigger(TabRename { entity, name: std::mem::take(edited) });
+                                    }
+                                    *renaming = None;
+                                }
+                                return;
+                            }
+                            let mut response = ui.selectable_label(is_active, label);
+                            if is_active && *scrolled_to != Some(entity) {
+                                response.scroll_to_me(None);
+                                *scrolled_to = Some(entity);
+                            }
+                            let mut hover_lines = Vec::new();
+                            if is_truncated {
+                                hover_lines.push(name.to_string());
+                            }
+                            if let Some(description) = book_meta.and_then(|book_meta| book_meta.0.describe()) {
+                                hover_lines.push(description);
+                            }
+                            match (stats, wpm) {
+                                (Some(stats), Some(wpm)) => hover_lines.push(format!(
+                                    "{} words, ~{} at {} WPM",
+                                    format_count(stats.0.word_count as u64),
+                                    format_duration(stats.0.estimated_reading_time(wpm.0)),
+                                    wpm.0,
+                                )),
+                                (Some(stats), None) => hover_lines.push(format!("{} words", format_count(stats.0.word_count as u64))),
+                                _ => {}
+                            }
+                            if let Some(read_time) = read_time {
+                                hover_lines.push(format!("Read for {}", format_clock(read_time.0)));
+                            }
+                            if !hover_lines.is_empty() {
+                                response = response.on_hover_text(hover_lines.join("\n"));
+                            }
+                            if !is_homepage {
+                                response.context_menu(|ui| {
+                                    if ui.button("Rename").clicked() {
+                                        *renaming = Some((entity, name.to_string()));
+                                        *is_rename_started = true;
+                                    }
+                                    if ui.button("Duplicate").clicked() {
+                                        commands.trigger(TabDuplicate::from(entity));
+                                    }
+                                    if ui.button("Jump to start").clicked() {
+                                        commands.trigger(TabSelect::from(entity));
+                                        commands.trigger(PlaybackCommand::Restart);
+                                    }
+                                    if ui.button("Export as .txt…").clicked() {
+                                        commands.trigger(TabExportText::from(entity));
+                                    }
+                                    ui.separator();
+                                    if ui.button("Close").clicked() {
+                                        close_dialog.request(&mut commands, entity, current_index, &close_confirm);
+                                    }
+                                    if ui.button("Close others").clicked() {
+                                        let in_progress = tab_order.entities().iter()
+                                            .filter(|&&other| other != entity)
+                                            .filter_map(|&other| tabs.get(other).ok().and_then(|(.., content)| content))
+                                            .filter(|content| content.current_index > 0)
+                                            .count();
+                                        close_dialog.request_others(&mut commands, entity, in_progress, &close_confirm);
+                                    }
+                                });
+                            }
+                            if !is_homepage && response.double_clicked() {
+                                *renaming = Some((entity, name.to_string()));
+                                *is_rename_started = true;
+                            } else if response.clicked() {
+                                commands.trigger(TabSelect::from(entity));
+                            }
+                            if !is_homepage {
+                                if ui.small_button("×").clicked() {
+                                    close_dialog.request(&mut commands, entity, current_index, &close_confirm);
+                                }
+                            }
+                        });
+                        ui.separator();
                     }
-                });
-                ui.separator();
-            }
+                }));
             
             if ui.button("+ New").clicked() {
+                // The paste field keeps its text: it is the `PasteDraft`.
                 dialog.open = true;
-                dialog.text_input.clear();
+                dialog.url_error = None;
             }
         });
     });
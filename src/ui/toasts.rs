@@ -0,0 +1,89 @@
+//! Short-lived notifications in the bottom-right corner.
+//!
+//! Any system can `commands.trigger(Toast::new(..))`; the observer queues it and
+//! `Toasts::show` draws the queue until each entry expires.
+
+use bevy::prelude::*;
+use bevy_egui::{EguiContexts, egui};
+
+const TOAST_DURATION_SECS: f32 = 4.0;
+const TOAST_SPACING: f32 = 6.0;
+/// Keeps toasts above the controls panel.
+const TOAST_BOTTOM_OFFSET: f32 = -48.0;
+const COLOR_INFO: egui::Color32 = egui::Color32::from_rgb(45, 55, 72);
+const COLOR_WARNING: egui::Color32 = egui::Color32::from_rgb(110, 80, 30);
+const COLOR_ERROR: egui::Color32 = egui::Color32::from_rgb(120, 40, 40);
+
+#[derive(Clone, Copy)]
+pub enum ToastKind {
+    Info,
+    Warning,
+    Error,
+}
+impl ToastKind {
+    fn color(self) -> egui::Color32 {
+        match self {
+            ToastKind::Info => COLOR_INFO,
+            ToastKind::Warning => COLOR_WARNING,
+            ToastKind::Error => COLOR_ERROR,
+        }
+    }
+}
+
+/// Request to show a notification.
+#[derive(Event)]
+pub struct Toast {
+    pub kind: ToastKind,
+    pub text: String,
+}
+impl Toast {
+    pub fn new(kind: ToastKind, text: impl Into<String>) -> Self {
+        Self { kind, text: text.into() }
+    }
+
+    pub fn on_trigger(
+        trigger: On<Toast>,
+        time: Res<Time>,
+        mut toasts: ResMut<Toasts>,
+    ) {
+        let expires_at = time.elapsed_secs() + TOAST_DURATION_SECS;
+        toasts.0.push((trigger.kind, trigger.text.clone(), expires_at));
+    }
+}
+
+/// Visible toasts with their expiry time (in `Time::elapsed_secs`).
+#[derive(Resource, Default)]
+pub struct Toasts(Vec<(ToastKind, String, f32)>);
+impl Toasts {
+    pub fn is_any(toasts: Res<Toasts>) -> bool {
+        !toasts.0.is_empty()
+    }
+
+    pub fn show(
+        mut contexts: EguiContexts,
+        time: Res<Time>,
+        mut toasts: ResMut<Toasts>,
+    ) {
+        let now = time.elapsed_secs();
+        toasts.0.retain(|(_, _, expires_at)| *expires_at > now);
+        let Ok(ctx) = contexts.ctx_mut() else { return };
+
+        egui::Area::new(egui::Id::new("toasts"))
+            .order(egui::Order::Foreground)
+            .anchor(egui::Align2::RIGHT_BOTTOM, [-12.0, TOAST_BOTTOM_OFFSET])
+            .interactable(false)
+            .show(ctx, |ui| {
+                ui.spacing_mut().item_spacing.y = TOAST_SPACING;
+                for (kind, text, _) in toasts.0.iter() {
+                    egui::Frame::NONE
+                        .fill(kind.color())
+                        .corner_radius(egui::CornerRadius::same(6))
+                        .inner_margin(egui::Margin::symmetric(12, 8))
+                        .show(ui, |ui| {
+                            ui.label(egui::RichText::new(text).color(egui::Color32::WHITE));
+                        });
+                }
+            });
+        ctx.request_repaint();
+    }
+}
@@ -0,0 +1,119 @@
+//! Table of contents and bookmarks side panel.
+//!
+//! Lists the sections (EPUB chapters, PDF pages, Markdown headings) and bookmarks of
+//! the active reader tab, and seeks to one on click. Bookmarks can be added, renamed
+//! and removed here. Shown only when the active tab has sections or bookmarks.
+
+use bevy::prelude::*;
+use bevy_egui::{EguiContexts, egui};
+
+use crate::bookmarks::{BookmarkAdd, BookmarkRemove, BookmarkRename, Bookmarks};
+use crate::reader::ContentNavigate;
+use crate::tabs::{ActiveTab, Content, ReaderTab};
+
+const PANEL_DEFAULT_WIDTH: f32 = 220.0;
+
+/// Whether the side panel is shown, plus its text field state.
+#[derive(Resource)]
+pub struct TocPanel {
+    pub open: bool,
+    /// Label typed for the next bookmark.
+    new_bookmark_label: String,
+    /// Bookmark being renamed: its position and the edited label.
+    renaming: Option<(usize, String)>,
+}
+impl Default for TocPanel {
+    fn default() -> Self {
+        Self { open: true, new_bookmark_label: String::new(), renaming: None }
+    }
+}
+impl TocPanel {
+    #[allow(clippy::type_complexity)]
+    pub fn update(
+        mut commands: Commands,
+        mut contexts: EguiContexts,
+        mut toc_panel: ResMut<TocPanel>,
+        active_reader: Option<Single<(Entity, &Content, &Bookmarks), (With<ActiveTab>, With<ReaderTab>)>>,
+    ) {
+        if !toc_panel.open { return; }
+        let Some(active_reader) = active_reader else { return };
+        let (entity, content, bookmarks) = active_reader.into_inner();
+        if content.sections.is_empty() && bookmarks.0.is_empty() { return; }
+        let Ok(ctx) = contexts.ctx_mut() else { return };
+
+        let toc_panel = &mut *toc_panel;
+        let current_section = content.current_section();
+        egui::SidePanel::right("toc")
+            .resizable(true)
+            .default_width(PANEL_DEFAULT_WIDTH)
+            .show(ctx, |ui| {
+                ui.with_layout(egui::Layout::right_to_left(egui::Align::Min), |ui| {
+                    if ui.small_button("✕").on_hover_text("Hide (T)").clicked() {
+                        toc_panel.open = false;
+                    }
+                });
+                egui::ScrollArea::vertical().show(ui, |ui| {
+                    if !content.sections.is_empty() {
+                        egui::CollapsingHeader::new("Contents").default_open(true).show(ui, |ui| {
+                            for (index, section) in content.sections.iter().enumerate() {
+                                if ui.selectable_label(current_section == Some(index), &section.title).clicked() {
+                                    commands.trigger(ContentNavigate::Seek(section.start_index));
+                                }
+                            }
+                        });
+                    }
+
+                    egui::CollapsingHeader::new("Bookmarks").default_open(true).show(ui, |ui| {
+                        ui.horizontal(|ui| {
+                            let field = ui.add(egui::TextEdit::singleline(&mut toc_panel.new_bookmark_label)
+                                .hint_text("Label (optional)")
+                                .desired_width(120.0));
+                            let is_submitted = field.lost_focus() && ui.input(|input| input.key_pressed(egui::Key::Enter));
+                            if ui.small_button("Add").on_hover_text("Bookmark the current word").clicked() || is_submitted {
+                                let label = std::mem::take(&mut toc_panel.new_bookmark_label);
+                                commands.trigger(BookmarkAdd { entity, label });
+                            }
+                        });
+                        for (position, bookmark) in bookmarks.0.iter().enumerate() {
+                            ui.horizontal(|ui| {
+                                match &mut toc_panel.renaming {
+                                    Some((renamed, label)) if *renamed == position => {
+                                        let field = ui.add(egui::TextEdit::singleline(label).desired_width(120.0));
+                                        field.request_focus();
+                                        if field.lost_focus() {
+                                            if ui.input(|input| input.key_pressed(egui::Key::Enter)) {
+                                                commands.trigger(BookmarkRename { entity, position, label: std::mem::take(label) });
+                                            }
+                                            toc_panel.renaming = None;
+                                        }
+                                    }
+                                    _ => {
+                                        let response = ui.selectable_label(false, &bookmark.label)
+                                            .on_hover_text(format!("Word {} — double-click to rename", bookmark.index + 1));
+                                        if response.double_clicked() {
+                                            toc_panel.renaming = Some((position, bookmark.label.clone()));
+                                        } else if response.clicked() {
+                                            commands.trigger(ContentNavigate::Seek(bookmark.index));
+                                        }
+                                    }
+                                }
+                                if ui.small_button("✕").on_hover_text("Remove bookmark").clicked() {
+                                    commands.trigger(BookmarkRemove { entity, position });
+                                    toc_panel.renaming = None;
+                                }
+                            });
+                        }
+                    });
+                });
+            });
+    }
+}
+
+/// Shows or hides the side panel.
+#[derive(Event)]
+pub struct ToggleTocPanel;
+impl ToggleTocPanel {
+    pub fn on_trigger(_trigger: On<ToggleTocPanel>, mut toc_panel: ResMut<TocPanel>) {
+        toc_panel.open = !toc_panel.open;
+    }
+}
@@ -0,0 +1,81 @@
+//! Vocabulary window.
+//!
+//! Lists the captured words with the tab each came from, and exports them as
+//! `.txt` or `.csv`. Toggled by the `ToggleVocabularyPanel` event.
+
+use bevy::prelude::*;
+use bevy_egui::{EguiContexts, egui};
+
+use crate::export::SaveFile;
+use crate::vocabulary::{Vocabulary, VocabularyRemove};
+
+const LIST_MAX_HEIGHT: f32 = 320.0;
+
+/// Whether the vocabulary window is shown.
+#[derive(Resource, Default)]
+pub struct VocabularyPanel {
+    open: bool,
+}
+impl VocabularyPanel {
+    pub fn is_open(panel: Res<VocabularyPanel>) -> bool {
+        panel.open
+    }
+
+    pub fn update(
+        mut commands: Commands,
+        mut contexts: EguiContexts,
+        mut panel: ResMut<VocabularyPanel>,
+        vocabulary: Res<Vocabulary>,
+    ) {
+        let Ok(ctx) = contexts.ctx_mut() else { return };
+        egui::Window::new(format!("Vocabulary ({})", vocabulary.0.len()))
+            .id(egui::Id::new("vocabulary"))
+            .open(&mut panel.open)
+            .resizable(true)
+            .default_width(260.0)
+            .show(ctx, |ui| {
+                if vocabulary.0.is_empty() {
+                    ui.label("Press V while reading to save the current word here.");
+                    return;
+                }
+                egui::ScrollArea::vertical().max_height(LIST_MAX_HEIGHT).show(ui, |ui| {
+                    for (position, entry) in vocabulary.0.iter().enumerate() {
+                        ui.horizontal(|ui| {
+                            if ui.small_button("✕").on_hover_text("Remove").clicked() {
+                                commands.trigger(VocabularyRemove { position });
+                            }
+                            ui.label(&entry.word).on_hover_text(&entry.source);
+                        });
+                    }
+                });
+                ui.separator();
+                ui.horizontal(|ui| {
+                    if ui.button("Export .txt").clicked() {
+                        commands.trigger(SaveFile {
+                            file_name: "vocabulary.txt".to_string(),
+                            filter_name: "Text",
+                            extension: "txt",
+                            contents: vocabulary.to_txt().into_bytes(),
+                        });
+                    }
+                    if ui.button("Export .csv").clicked() {
+                        commands.trigger(SaveFile {
+                            file_name: "vocabulary.csv".to_string(),
+                            filter_name: "CSV",
+                            extension: "csv",
+                            contents: vocabulary.to_csv().into_bytes(),
+                        });
+                    }
+                });
+            });
+    }
+}
+
+/// Shows or hides the vocabulary window.
+#[derive(Event)]
+pub struct ToggleVocabularyPanel;
+impl ToggleVocabularyPanel {
+    pub fn on_trigger(_trigger: On<ToggleVocabularyPanel>, mut panel: ResMut<VocabularyPanel>) {
+        panel.open = !panel.open;
+    }
+}
@@ -0,0 +1,57 @@
+//! Preview strip of the upcoming words under the reader display.
+//!
+//! Paints the next few words faintly on one line below the fixation letter, so
+//! the eye can practice picking them up before they are flashed. Only the
+//! bounded slice from `Content::upcoming` is read each frame.
+
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+use bevy_egui::{EguiContexts, egui};
+
+use crate::orp::{NumberDisplay, WORD_PREVIEW_COUNT_MAX, WordPreview};
+use crate::tabs::{ActiveTab, Content, ReaderTab, TabChunkSize, TabFontSettings};
+use crate::theme::AppliedTheme;
+
+const WORD_PREVIEW_FONT_SIZE: f32 = 18.0;
+/// Gap below the fixation letter, as a multiple of the tab's font size.
+/// Clears the bottom reticle.
+const WORD_PREVIEW_OFFSET_RATIO: f32 = 1.6;
+const WORD_PREVIEW_WIDTH_RATIO: f32 = 0.8;
+const WORD_PREVIEW_ALPHA: f32 = 0.35;
+
+#[allow(clippy::type_complexity)]
+pub fn word_preview_system(
+    mut contexts: EguiContexts,
+    preview: Res<WordPreview>,
+    number_display: Res<NumberDisplay>,
+    applied_theme: Res<AppliedTheme>,
+    active_reader: Option<Single<(&Content, &TabChunkSize, &TabFontSettings), (With<ActiveTab>, With<ReaderTab>)>>,
+    window: Single<&Window, With<PrimaryWindow>>,
+) {
+    let Ok(ctx) = contexts.ctx_mut() else { return };
+    let Some(active_reader) = active_reader else { return };
+    let (content, chunk_size, font_settings) = active_reader.into_inner();
+
+    let upcoming = content.upcoming(chunk_size.0, preview.word_count.min(WORD_PREVIEW_COUNT_MAX));
+    if upcoming.is_empty() { return; }
+    let text = upcoming
+        .filter_map(|index| content.words.get(index))
+        .map(|word| number_display.apply(word.text))
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    // Follows the reader background, which may differ from the egui theme.
+    let [red, green, blue, _] = applied_theme.text_color().to_srgba().to_u8_array();
+    let color = egui::Color32::from_rgb(red, green, blue).gamma_multiply(WORD_PREVIEW_ALPHA);
+    let painter = ctx.layer_painter(egui::LayerId::new(egui::Order::Background, egui::Id::new("word_preview")));
+    let galley = painter.layout_no_wrap(text, egui::FontId::proportional(WORD_PREVIEW_FONT_SIZE), color);
+    let top = window.height() * 0.5 + font_settings.font_size * WORD_PREVIEW_OFFSET_RATIO;
+    let max_width = window.width() * WORD_PREVIEW_WIDTH_RATIO;
+    let clip = egui::Rect::from_center_size(
+        egui::pos2(window.width() * 0.5, top + galley.size().y * 0.5),
+        egui::vec2(max_width, galley.size().y),
+    );
+    // Left-aligned when it overflows, so the nearest words stay visible.
+    let left = if galley.size().x > max_width { clip.left() } else { window.width() * 0.5 - galley.size().x * 0.5 };
+    painter.with_clip_rect(clip).galley(egui::pos2(left, top), galley, color);
+}
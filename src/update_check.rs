@@ -0,0 +1,139 @@
+//! Opt-in check for newer releases on GitHub.
+//!
+//! At most once per day, fetches the latest release tag on the async task pool and
+//! compares it with the running version. A newer release is only announced on the
+//! About tile; nothing is downloaded. Network and parse failures are silent.
+//! Native only, compiled with the `update_check` feature.
+
+use bevy::prelude::*;
+use bevy::tasks::{block_on, poll_once, AsyncComputeTaskPool, Task};
+use serde::{Deserialize, Serialize};
+
+use crate::clock::unix_now_secs;
+
+pub struct UpdateCheckPlugin;
+impl Plugin for UpdateCheckPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<UpdateCheck>()
+            .init_resource::<PendingUpdateCheck>()
+            .add_systems(Update, (
+                UpdateCheck::start.run_if(UpdateCheck::is_due),
+                PendingUpdateCheck::poll,
+            ))
+            ;
+    }
+}
+
+const LATEST_RELEASE_URL: &str = "https://api.github.com/repos/Arrekin/arre-mind-reader/releases/latest";
+const CHECK_INTERVAL_SECS: u64 = 86_400;
+
+/// Update check settings. Serialized to disk as part of `ProgramState`.
+#[derive(Resource, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct UpdateCheck {
+    pub enabled: bool,
+    last_checked_secs: Option<u64>,
+}
+impl UpdateCheck {
+    fn is_due(update_check: Res<UpdateCheck>, pending: Res<PendingUpdateCheck>) -> bool {
+        update_check.enabled
+            && pending.0.is_none()
+            && update_check.last_checked_secs
+                .is_none_or(|last| unix_now_secs().saturating_sub(last) >= CHECK_INTERVAL_SECS)
+    }
+
+    /// Spawns the release fetch. The check time is recorded up front so a failing
+    /// network is retried the next day, not every frame.
+    fn start(
+        mut update_check: ResMut<UpdateCheck>,
+        mut pending: ResMut<PendingUpdateCheck>,
+    ) {
+        update_check.last_checked_secs = Some(unix_now_secs());
+        pending.0 = Some(AsyncComputeTaskPool::get().spawn(async { fetch_latest_release() }));
+    }
+}
+
+/// A release newer than the running version. Present only once one was found.
+#[derive(Resource)]
+pub struct AvailableUpdate {
+    pub name: String,
+    pub url: String,
+}
+
+/// The in-flight release fetch, if any.
+#[derive(Resource, Default)]
+pub struct PendingUpdateCheck(Option<Task<Option<AvailableUpdate>>>);
+impl PendingUpdateCheck {
+    fn poll(
+        mut commands: Commands,
+        mut pending: ResMut<PendingUpdateCheck>,
+    ) {
+        let Some(task) = &mut pending.0 else { return };
+        let Some(result) = block_on(poll_once(task)) else { return };
+        pending.0 = None;
+        if let Some(update) = result {
+            info!("Update available: {}", update.name);
+            commands.insert_resource(update);
+        }
+    }
+}
+
+/// Subset of the GitHub release JSON.
+#[derive(Deserialize)]
+struct Release {
+    tag_name: String,
+    name: Option<String>,
+    html_url: String,
+}
+
+fn fetch_latest_release() -> Option<AvailableUpdate> {
+    let mut request = ehttp::Request::get(LATEST_RELEASE_URL);
+    // GitHub rejects API requests without a User-Agent.
+    request.headers.insert("User-Agent", concat!("arre-mind-reader/", env!("CARGO_PKG_VERSION")));
+    request.headers.insert("Accept", "application/vnd.github+json");
+
+    let response = ehttp::fetch_blocking(&request).ok().filter(|response| response.ok)?;
+    let release: Release = serde_json::from_slice(&response.bytes).ok()?;
+    if !is_newer_release(&release.tag_name, env!("CARGO_PKG_VERSION")) { return None; }
+
+    Some(AvailableUpdate {
+        name: release.name.filter(|name| !name.trim().is_empty()).unwrap_or(release.tag_name),
+        url: release.html_url,
+    })
+}
+
+/// Whether release `tag` (e.g. "v1.2.0") is a higher semver than `current`.
+/// Tags that are not valid semver never count as newer.
+fn is_newer_release(tag: &str, current: &str) -> bool {
+    let tag = tag.trim().trim_start_matches(['v', 'V']);
+    match (semver::Version::parse(tag), semver::Version::parse(current)) {
+        (Ok(latest), Ok(current)) => latest > current,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn is_newer_release_compares_semver_not_strings() {
+        assert!(is_newer_release("v1.10.0", "1.9.0"));
+        assert!(is_newer_release("2.0.0", "1.0.0"));
+        assert!(!is_newer_release("v1.0.0", "1.0.0"));
+        assert!(!is_newer_release("v0.9.5", "1.0.0"));
+    }
+
+    #[test]
+    fn is_newer_release_ranks_prereleases_below_their_release() {
+        assert!(!is_newer_release("v1.0.0-beta.1", "1.0.0"));
+        assert!(is_newer_release("v1.1.0-rc.1", "1.0.0"));
+    }
+
+    #[test]
+    fn is_newer_release_ignores_unparseable_tags() {
+        assert!(!is_newer_release("latest", "1.0.0"));
+        assert!(!is_newer_release("v2", "1.0.0"));
+    }
+}
@@ -0,0 +1,141 @@
+//! Vocabulary list for study.
+//!
+//! `CaptureWord` saves the word shown in the active reader tab, without surrounding
+//! punctuation, to the global `Vocabulary`. Words are kept once, compared without
+//! case, in the order they were captured. The list is exported as plain text or CSV.
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::tabs::{ActiveTab, Content, ReaderTab};
+use crate::ui::{Toast, ToastKind};
+
+pub struct VocabularyPlugin;
+impl Plugin for VocabularyPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<Vocabulary>()
+            .add_observer(CaptureWord::on_trigger)
+            .add_observer(VocabularyRemove::on_trigger)
+            ;
+    }
+}
+
+/// Captured words, oldest first. Serialized to disk as part of `ProgramState`.
+#[derive(Resource, Clone, Default, Serialize, Deserialize)]
+pub struct Vocabulary(pub Vec<VocabularyEntry>);
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct VocabularyEntry {
+    pub word: String,
+    /// Name of the tab it was captured from.
+    pub source: String,
+}
+/// What `Vocabulary::add` did with a word.
+#[derive(Debug, PartialEq)]
+pub enum VocabularyAdd<'a> {
+    Added(&'a str),
+    /// The trimmed word, already listed in some case.
+    AlreadyListed(&'a str),
+    /// Nothing was left after trimming punctuation.
+    NoWord,
+}
+impl Vocabulary {
+    /// Adds `text` without surrounding punctuation, unless nothing is left or the
+    /// word is already listed.
+    pub fn add<'a>(&mut self, text: &'a str, source: &str) -> VocabularyAdd<'a> {
+        let word = text.trim_matches(|c: char| !c.is_alphanumeric());
+        if word.is_empty() { return VocabularyAdd::NoWord; }
+        let folded = word.to_lowercase();
+        if self.0.iter().any(|entry| entry.word.to_lowercase() == folded) { return VocabularyAdd::AlreadyListed(word); }
+        self.0.push(VocabularyEntry { word: word.to_string(), source: source.to_string() });
+        VocabularyAdd::Added(word)
+    }
+
+    /// One word per line.
+    pub fn to_txt(&self) -> String {
+        self.0.iter().map(|entry| format!("{}\n", entry.word)).collect()
+    }
+
+    /// `word,source` rows under a header, quoted where needed.
+    pub fn to_csv(&self) -> String {
+        let field = |value: &str| {
+            if value.contains([',', '"', '\n']) {
+                format!("\"{}\"", value.replace('"', "\"\""))
+            } else {
+                value.to_string()
+            }
+        };
+        std::iter::once("word,source\n".to_string())
+            .chain(self.0.iter().map(|entry| format!("{},{}\n", field(&entry.word), field(&entry.source))))
+            .collect()
+    }
+}
+
+/// Saves the current word of the active reader tab to the `Vocabulary`.
+#[derive(Event)]
+pub struct CaptureWord;
+impl CaptureWord {
+    #[allow(clippy::type_complexity)]
+    fn on_trigger(
+        _trigger: On<CaptureWord>,
+        mut commands: Commands,
+        mut vocabulary: ResMut<Vocabulary>,
+        active_reader: Option<Single<(&Content, &Name), (With<ActiveTab>, With<ReaderTab>)>>,
+    ) {
+        let Some(active_reader) = active_reader else { return };
+        let (content, name) = active_reader.into_inner();
+        let Some(word) = content.words.get(content.current_index) else { return };
+        let toast = match vocabulary.add(word.text, name.as_str()) {
+            VocabularyAdd::Added(added) => Toast::new(ToastKind::Info, format!("Saved \"{}\" to vocabulary", added)),
+            VocabularyAdd::AlreadyListed(listed) => Toast::new(ToastKind::Info, format!("\"{}\" is already in vocabulary", listed)),
+            VocabularyAdd::NoWord => Toast::new(ToastKind::Info, format!("\"{}\" has no letters or digits to save", word.text)),
+        };
+        commands.trigger(toast);
+    }
+}
+
+/// Deletes the entry at `position` in the `Vocabulary`.
+#[derive(Event)]
+pub struct VocabularyRemove {
+    pub position: usize,
+}
+impl VocabularyRemove {
+    fn on_trigger(trigger: On<VocabularyRemove>, mut vocabulary: ResMut<Vocabulary>) {
+        if trigger.position < vocabulary.0.len() {
+            vocabulary.0.remove(trigger.position);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn vocabulary_trims_and_skips_repeats_in_any_case() {
+        let mut vocabulary = Vocabulary::default();
+
+        assert_eq!(vocabulary.add("“Ephemeral,”", "Book"), VocabularyAdd::Added("Ephemeral"));
+        assert_eq!(vocabulary.add("ephemeral.", "Other"), VocabularyAdd::AlreadyListed("ephemeral"));
+        assert_eq!(vocabulary.add("—", "Book"), VocabularyAdd::NoWord);
+        assert_eq!(vocabulary.add("naïve", "Notes, vol. 2"), VocabularyAdd::Added("naïve"));
+
+        assert_eq!(vocabulary.to_txt(), "Ephemeral\nnaïve\n");
+        assert_eq!(vocabulary.to_csv(), "word,source\nEphemeral,Book\nnaïve,\"Notes, vol. 2\"\n");
+    }
+
+    #[test]
+    fn remove_addresses_entries_by_position() {
+        let mut app = App::new();
+        app.add_observer(VocabularyRemove::on_trigger);
+        let mut vocabulary = Vocabulary::default();
+        vocabulary.add("first", "Book");
+        vocabulary.add("second", "Book");
+        app.insert_resource(vocabulary);
+
+        app.world_mut().trigger(VocabularyRemove { position: 5 });
+        app.world_mut().trigger(VocabularyRemove { position: 0 });
+
+        assert_eq!(app.world().resource::<Vocabulary>().to_txt(), "second\n");
+    }
+}